@@ -1,8 +1,12 @@
-use applib::parser::{ParseResult, parse_save_data};
+use anyhow::Context;
+use applib::{
+    games::{GameTokens, parse_any_save_data},
+    parser::ParseResult,
+};
 use axum::{
     Json, Router,
     body::Bytes,
-    extract::DefaultBodyLimit,
+    extract::{DefaultBodyLimit, State},
     http::StatusCode,
     routing::{get, post},
 };
@@ -10,12 +14,16 @@ use opentelemetry::trace::TracerProvider as _;
 use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
 use std::io::IsTerminal;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::{net::TcpListener, signal};
 use tower_http::trace::{self, TraceLayer};
 use tracing::Level;
 use tracing_subscriber::{EnvFilter, fmt::format::FmtSpan, prelude::*};
 
+mod melt;
 mod screenshot;
+mod transcode;
 
 // Avoid musl's default allocator due to lackluster performance
 // https://nickb.dev/blog/default-musl-allocator-considered-harmful-to-performance
@@ -23,10 +31,13 @@ mod screenshot;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-#[tracing::instrument(name = "eu4.parse", skip(data), fields(request_bytes = data.len()))]
-async fn upload(data: Bytes) -> Result<Json<ParseResult>, StatusCode> {
+#[tracing::instrument(name = "save.parse", skip_all, fields(request_bytes = data.len()))]
+async fn upload(
+    State(tokens): State<Arc<GameTokens>>,
+    data: Bytes,
+) -> Result<Json<ParseResult>, StatusCode> {
     tracing::info!("received request (bytes: {})", data.len());
-    let result = tokio::task::block_in_place(|| parse_save_data(&data));
+    let result = tokio::task::block_in_place(|| parse_any_save_data(&data, &tokens));
 
     match result {
         Ok(parsed) => Ok(Json(parsed)),
@@ -100,10 +111,20 @@ async fn main() -> anyhow::Result<()> {
         Err(_) => 8080,
     };
 
+    // Binary saves of games other than EU4 need their token files
+    let tokens_dir = std::env::var_os("TOKENS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("assets/tokens"));
+    let tokens = GameTokens::from_dir(&tokens_dir)
+        .with_context(|| format!("unable to load token files: {}", tokens_dir.display()))?;
+
     let app = Router::new()
         .route("/", post(upload))
         .route("/healthz", get(health))
+        .route("/melt", post(melt::endpoint))
         .route("/screenshot", post(screenshot::endpoint))
+        .route("/transcode", post(transcode::endpoint))
+        .with_state(Arc::new(tokens))
         .layer(DefaultBodyLimit::max(50 * 1024 * 1024))
         .layer(
             TraceLayer::new_for_http()
@@ -0,0 +1,63 @@
+use applib::{
+    games::GameTokens,
+    melt::{MeltError, format_unknown_tokens, melt_save},
+};
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+fn error_response(err: MeltError) -> (StatusCode, String) {
+    match err {
+        MeltError::Decompress(e) => {
+            tracing::warn!(error = %e, "melt decompression error");
+            (StatusCode::BAD_REQUEST, format!("Invalid save file: {e}"))
+        }
+        e => {
+            tracing::warn!(error = %e, "melt error");
+            (StatusCode::BAD_REQUEST, format!("Unable to melt save: {e}"))
+        }
+    }
+}
+
+/// The body is buffered rather than streamed as detecting the game and
+/// locating a zip's entries both need the whole save. The request size is
+/// bounded by the router's body limit.
+#[tracing::instrument(
+    level = "info",
+    name = "melt.endpoint",
+    skip_all,
+    fields(request_bytes = body.len())
+)]
+pub async fn endpoint(State(tokens): State<Arc<GameTokens>>, body: Bytes) -> impl IntoResponse {
+    match tokio::task::block_in_place(|| melt_save(&body, &tokens)) {
+        Ok(melted) => {
+            tracing::info!(
+                game = melted.game.as_str(),
+                unknown_tokens = melted.unknown_tokens.len(),
+                "melted save"
+            );
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+            );
+            headers.insert("x-pdx-game", HeaderValue::from_static(melted.game.as_str()));
+            headers.insert(
+                "x-pdx-unknown-token-count",
+                HeaderValue::from(melted.unknown_tokens.len()),
+            );
+            if let Ok(value) = HeaderValue::from_str(&format_unknown_tokens(&melted.unknown_tokens))
+            {
+                headers.insert("x-pdx-unknown-tokens", value);
+            }
+
+            (StatusCode::OK, headers, Bytes::from(melted.data)).into_response()
+        }
+        Err(e) => error_response(e).into_response(),
+    }
+}
@@ -0,0 +1,43 @@
+use applib::transcode::transcode;
+use axum::{
+    body::Bytes,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::IntoResponse,
+};
+
+/// The body is buffered rather than streamed as a zip's central directory is
+/// at the end of the file, so no entry can be remuxed until the whole save has
+/// been received. The request size is bounded by the router's body limit.
+#[tracing::instrument(
+    level = "info",
+    name = "transcode.endpoint",
+    skip(body),
+    fields(request_bytes = body.len())
+)]
+pub async fn endpoint(body: Bytes) -> impl IntoResponse {
+    // Reuses the body's allocation when it is uniquely owned
+    match tokio::task::block_in_place(|| transcode(Vec::from(body))) {
+        Ok(transcoded) => {
+            tracing::info!(
+                reencoded = transcoded.reencoded,
+                response_bytes = transcoded.data.len(),
+                "transcoded save"
+            );
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(transcoded.content_type.mime()),
+            );
+            (StatusCode::OK, headers, Bytes::from(transcoded.data)).into_response()
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "transcode error");
+            (
+                StatusCode::BAD_REQUEST,
+                format!("Unable to transcode save: {e}"),
+            )
+                .into_response()
+        }
+    }
+}
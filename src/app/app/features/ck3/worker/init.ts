@@ -11,3 +11,23 @@ export async function parseCk3() {
   const meta = wasm.save.metadata();
   return { meta };
 }
+
+export function getPlayedCharacter() {
+  return wasm.save.get_played_character();
+}
+
+export function getDynastyTree() {
+  return wasm.save.get_dynasty_tree();
+}
+
+export function getHeldTitles() {
+  return wasm.save.get_held_titles();
+}
+
+export function getRealmSize() {
+  return wasm.save.get_realm_size();
+}
+
+export function getCharacterStats() {
+  return wasm.save.get_character_stats();
+}
@@ -7,6 +7,8 @@ import { CountrySelect } from "./CountrySelect";
 import { ToggleRow } from "../features/settings/ToggleRow";
 import { Dialog } from "@/components/Dialog";
 import { formatList } from "@/lib/format";
+import { Input } from "@/components/Input";
+import { getEu4Worker } from "../worker";
 
 type AiState = ReturnType<typeof useTagFilter>["ai"];
 const simpleFilter = (x: AiState) => {
@@ -82,6 +84,8 @@ export const CountryFilterButton = () => {
                 onChange={(x) => updateTagFilter({ includeSubjects: x })}
                 text="Include Subjects"
               />
+
+              <FilterExpressionInput />
             </div>
             <div className="flex justify-end">
               <Dialog.Close asChild>
@@ -117,3 +121,39 @@ const CountryFilterSelect = ({ action }: { action: "include" | "exclude" }) => {
     </CountrySelect>
   );
 };
+
+const FilterExpressionInput = () => {
+  const filter = useTagFilter();
+  const { updateTagFilter } = useEu4Actions();
+  const [draft, setDraft] = useState(filter.expression);
+  const [error, setError] = useState<string | undefined>(undefined);
+
+  const apply = async () => {
+    const invalid = await getEu4Worker().eu4ValidateTagFilterExpression(draft);
+    setError(invalid);
+    if (invalid === undefined) {
+      updateTagFilter({ expression: draft.trim() });
+    }
+  };
+
+  return (
+    <form
+      className="flex flex-col gap-1"
+      onSubmit={(e) => {
+        e.preventDefault();
+        void apply();
+      }}
+    >
+      <label htmlFor="country-filter-expression">Filter Expression:</label>
+      <Input
+        id="country-filter-expression"
+        className="px-2 py-1 font-mono"
+        placeholder="religion_group(christian) and development >= 200"
+        value={draft}
+        onChange={(e) => setDraft(e.target.value)}
+        onBlur={() => void apply()}
+      />
+      {error ? <p className="text-sm text-red-600 dark:text-red-400">{error}</p> : null}
+    </form>
+  );
+};
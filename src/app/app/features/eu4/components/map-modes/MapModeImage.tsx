@@ -1,10 +1,12 @@
 import React from "react";
 import political from "./images/mapmode_political.png";
 import religion from "./images/mapmode_religion.png";
+import culture from "../icons/icon_culture.png";
 import development from "./images/mapmode_development.png";
 import battles from "./images/mapmode_battles.png";
 import technology from "./images/mapmode_tech.png";
 import terrain from "./images/mapmode_terrain.png";
+import trade from "../icons/icon_merchant.png";
 import type { MapControls } from "../../types/map";
 
 function modeLookup(mode: MapControls["mode"]) {
@@ -13,6 +15,8 @@ function modeLookup(mode: MapControls["mode"]) {
       return political;
     case "religion":
       return religion;
+    case "culture":
+      return culture;
     case "development":
       return development;
     case "battles":
@@ -21,6 +25,8 @@ function modeLookup(mode: MapControls["mode"]) {
       return technology;
     case "terrain":
       return terrain;
+    case "trade":
+      return trade;
   }
 }
 
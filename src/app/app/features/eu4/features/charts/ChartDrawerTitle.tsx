@@ -77,6 +77,7 @@ export const ChartDrawerTitle = ({
             <Select.Item value="army-casualties">Army Casualties</Select.Item>
             <Select.Item value="navy-casualties">Navy Casualties</Select.Item>
             <Select.Item value="wars">Wars and Battles</Select.Item>
+            <Select.Item value="trade-nodes">Trade Nodes</Select.Item>
             <Select.Item value="income-table">Last Month's Income</Select.Item>
             <Select.Item value="expense-table">Last Month's Expenses</Select.Item>
             <Select.Item value="total-expense-table">Accumulated Expenses</Select.Item>
@@ -0,0 +1,135 @@
+import { useCallback, useEffect } from "react";
+import { createColumnHelper } from "@tanstack/react-table";
+import { useEu4Worker } from "@/features/eu4/worker";
+import type { TradeNode } from "../../worker/module";
+import { useTagFilter } from "../../store";
+import { Alert } from "@/components/Alert";
+import { Table } from "@/components/Table";
+import { DataTable } from "@/components/DataTable";
+import { useVisualizationDispatch } from "@/components/viz";
+import { Flag } from "../../components/avatars";
+import { formatFloat } from "@/lib/format";
+import { createCsv } from "@/lib/csv";
+
+function CountryPowers({ data, total }: { data: TradeNode["collectors"]; total: number }) {
+  return (
+    <div className="flex flex-col gap-1">
+      {data.map((x) => (
+        <Flag key={x.country.tag} tag={x.country.tag} name={x.country.name}>
+          <div className="flex items-center gap-2">
+            <Flag.Tooltip showName>
+              <Flag.Image size="xs" />
+            </Flag.Tooltip>
+            <span>{formatFloat((x.power / Math.max(total, 1)) * 100, 1)}%</span>
+          </div>
+        </Flag>
+      ))}
+    </div>
+  );
+}
+
+const columnHelper = createColumnHelper<TradeNode>();
+const columns = [
+  columnHelper.accessor("name", {
+    sortingFn: "text",
+    header: ({ column }) => <Table.ColumnHeader column={column} title="Node" />,
+  }),
+
+  columnHelper.accessor("localValue", {
+    sortingFn: "basic",
+    header: ({ column }) => <Table.ColumnHeader column={column} title="Local Value" />,
+    meta: { className: "text-right" },
+    cell: (info) => formatFloat(info.getValue(), 2),
+  }),
+
+  columnHelper.accessor("incomingValue", {
+    sortingFn: "basic",
+    header: ({ column }) => <Table.ColumnHeader column={column} title="Incoming" />,
+    meta: { className: "text-right" },
+    cell: (info) => formatFloat(info.getValue(), 2),
+  }),
+
+  columnHelper.accessor("outgoingValue", {
+    sortingFn: "basic",
+    header: ({ column }) => <Table.ColumnHeader column={column} title="Outgoing" />,
+    meta: { className: "text-right" },
+    cell: (info) => formatFloat(info.getValue(), 2),
+  }),
+
+  columnHelper.accessor("retention", {
+    sortingFn: "basic",
+    header: ({ column }) => <Table.ColumnHeader column={column} title="Retention" />,
+    meta: { className: "text-right" },
+    cell: (info) => `${formatFloat(info.getValue() * 100, 1)}%`,
+  }),
+
+  columnHelper.accessor("totalPower", {
+    sortingFn: "basic",
+    header: ({ column }) => <Table.ColumnHeader column={column} title="Total Power" />,
+    meta: { className: "text-right" },
+    cell: (info) => formatFloat(info.getValue(), 2),
+  }),
+
+  columnHelper.display({
+    id: "collectors",
+    header: () => <span>Collecting</span>,
+    cell: ({ row }) => (
+      <CountryPowers data={row.original.collectors} total={row.original.totalPower} />
+    ),
+  }),
+
+  columnHelper.display({
+    id: "steering",
+    header: () => <span>Steering</span>,
+    cell: ({ row }) => (
+      <CountryPowers data={row.original.steering} total={row.original.totalPower} />
+    ),
+  }),
+];
+
+export const TradeNodeTable = () => {
+  const filter = useTagFilter();
+  const visualizationDispatch = useVisualizationDispatch();
+
+  const { data = [], error } = useEu4Worker(
+    useCallback((worker) => worker.eu4GetTradeNodes(filter), [filter]),
+  );
+
+  useEffect(() => {
+    visualizationDispatch({
+      type: "update-csv-data",
+      getCsvData: async () => {
+        const dataCsv = data.map((x) => ({
+          ...x,
+          collectors: `"{${x.collectors.map((y) => y.country.tag).join(",")}}"`,
+          steering: `"{${x.steering.map((y) => y.country.tag).join(",")}}"`,
+          merchants: `"{${x.merchants.map((y) => `${y.country.tag}:${y.mode}`).join(",")}}"`,
+        }));
+
+        return createCsv(dataCsv, [
+          "name",
+          "localValue",
+          "incomingValue",
+          "outgoingValue",
+          "retention",
+          "totalPower",
+          "collectors",
+          "steering",
+          "merchants",
+        ]);
+      },
+    });
+  }, [data, visualizationDispatch]);
+
+  return (
+    <>
+      <Alert.Error msg={error} />
+      <DataTable
+        columns={columns}
+        data={data}
+        pagination={true}
+        initialSorting={[{ id: "totalPower", desc: true }]}
+      />
+    </>
+  );
+};
@@ -3,6 +3,7 @@ import type { VizModules } from "../../types/visualizations";
 import { AnnualIncome, AnnualScore, AnnualNationSize, AnnualInflation } from "./annual-ledger";
 import { HealthGrid } from "./HealthGrid";
 import { WarTable } from "./WarTable";
+import { TradeNodeTable } from "./TradeNodeTable";
 import { CountriesArmyCasualtiesTable } from "./casualties/CountriesArmyCasualtiesTable";
 import { CountriesNavyCasualtiesTable } from "./casualties/CountriesNavyCasualtiesTable";
 import { CountriesExpensesTable } from "./CountriesExpensesTable";
@@ -46,6 +47,8 @@ export const VizRenderer = ({ module }: VizRendererProps) => {
       return <CountriesNavyCasualtiesTable />;
     case "wars":
       return <WarTable />;
+    case "trade-nodes":
+      return <TradeNodeTable />;
     case "geographical-development":
       return <GeographicalDevelopmentTree />;
     case "owned-development-states":
@@ -79,6 +79,25 @@ const MapTipsTable = ({ tip }: MapTipContentsProps) => {
         </table>
       );
     }
+    case "culture": {
+      return (
+        <table>
+          <tbody>
+            {mapTagDescriptions(tip)}
+            {tip.primaryCulture ? (
+              <tr>
+                <td>{tip.owner.name}:</td>
+                <td className="pl-2">{tip.primaryCulture.name}</td>
+              </tr>
+            ) : null}
+            <tr>
+              <td>Province:</td>
+              <td className="pl-2">{tip.cultureInProvince.name}</td>
+            </tr>
+          </tbody>
+        </table>
+      );
+    }
     case "development": {
       return (
         <table>
@@ -52,7 +52,13 @@ function createTimelapsePayload({ store, interval }: { store: Eu4Store; interval
   const currentMapDate = selectDate(mapMode, state.save.meta, state.selectedDate);
 
   return {
-    kind: mapMode == "battles" || mapMode == "religion" ? mapMode : "political",
+    kind:
+      mapMode == "battles" ||
+      mapMode == "religion" ||
+      mapMode == "culture" ||
+      mapMode == "development"
+        ? mapMode
+        : "political",
     interval: interval,
     start: currentMapDate.enabledDays ?? 0,
   } as const;
@@ -26,6 +26,7 @@ export const emptyEu4CountryFilter: CountryMatcher = {
   include: [],
   exclude: [],
   includeSubjects: false,
+  expression: "",
 };
 
 export const initialEu4CountryFilter: CountryMatcher = {
@@ -409,7 +410,13 @@ export const useIsDatePickerEnabled = () => {
 };
 
 const dateEnabledMapMode = (mode: MapPayload["kind"]) => {
-  return mode === "political" || mode === "religion" || mode === "battles";
+  return (
+    mode === "political" ||
+    mode === "religion" ||
+    mode === "culture" ||
+    mode === "development" ||
+    mode === "battles"
+  );
 };
 
 export const selectDate = (mode: MapPayload["kind"], meta: EnhancedMeta, date: MapDate) => {
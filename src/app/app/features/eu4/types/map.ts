@@ -5,10 +5,12 @@ export type BorderFill = "None" | "Provinces" | "Countries";
 export const mapModes = [
   "political",
   "religion",
+  "culture",
   "development",
   "battles",
   "technology",
   "terrain",
+  "trade",
 ] as const;
 type MapMode = (typeof mapModes)[number];
 
@@ -11,6 +11,7 @@ export type VizModules =
   | "army-casualties"
   | "navy-casualties"
   | "wars"
+  | "trade-nodes"
   | "geographical-development"
   | "owned-development-states"
   | "provinces"
@@ -56,7 +56,10 @@ export function eu4MapColors(payload: MapPayload): MapColors {
     const secondary = arr.subarray(arr.length / 3, (arr.length * 2) / 3);
     const country = arr.subarray((arr.length * 2) / 3);
     return transfer({ primary, secondary, country }, [arr.buffer]);
-  } else if (payload.date != null && payload.kind == "religion") {
+  } else if (
+    payload.date != null &&
+    (payload.kind == "religion" || payload.kind == "culture" || payload.kind == "development")
+  ) {
     const primary = arr.subarray(0, arr.length / 3);
     const secondary = arr.subarray(arr.length / 3, (arr.length * 2) / 3);
     const country = arr.subarray((arr.length * 2) / 3);
@@ -102,7 +105,7 @@ export function mapTimelapseNext(): MapTimelapseItem | undefined {
 }
 
 export function mapTimelapse(payload: {
-  kind: "political" | "religion" | "battles";
+  kind: "political" | "religion" | "culture" | "development" | "battles";
   interval: "year" | "month" | "week" | "day";
   start: number | undefined;
 }) {
@@ -186,6 +189,12 @@ export function eu4GetCountryInstitutionPush(
   );
 }
 
+export function eu4GetInstitutionForecast(tag: string, institution: number, planned: number[]) {
+  return timeSync("institution spread forecast", () =>
+    wasm.save.get_institution_forecast(tag, institution, planned),
+  );
+}
+
 export function eu4GetCountryProvinceCulture(tag: string): CountryCulture[] {
   return wasm.save.get_country_province_culture(tag);
 }
@@ -264,6 +273,12 @@ export function eu4GetCountriesMana(filter: CountryMatcher) {
   return wasm.save.get_countries_mana(filter);
 }
 
+export function eu4GetTradeNodes(filter: CountryMatcher) {
+  return wasm.save.get_trade_nodes(filter);
+}
+
+export type TradeNode = ReturnType<typeof eu4GetTradeNodes>[number];
+
 export function eu4GetProvinces() {
   return wasm.save.get_provinces();
 }
@@ -381,6 +396,15 @@ export function eu4GetWarInfo(war: string) {
   };
 }
 
+export type WarScoreEstimate = ReturnType<typeof eu4GetWarScoreEstimate>;
+export function eu4GetWarScoreEstimate(war: string) {
+  const raw = wasm.save.get_war_score_estimate(war);
+  if (!raw) {
+    throw new Error(`Did not find war by the name of ${war}`);
+  }
+  return raw;
+}
+
 export type MonitorData = ReturnType<typeof eu4MonitoringData>;
 export function eu4MonitoringData() {
   const result = wasm.save.monitoring_data();
@@ -412,3 +436,7 @@ export function eu4GetMapTooltip(
 ): QuickTipPayload | null {
   return wasm.save.map_quick_tip(province, payload, date) ?? null;
 }
+
+export function eu4ValidateTagFilterExpression(expression: string): string | undefined {
+  return wasm.module.validate_tag_filter_expression(expression);
+}
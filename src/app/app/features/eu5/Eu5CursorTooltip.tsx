@@ -18,11 +18,17 @@ function formatHoverStat(stat: HoverStat): string | null {
     case "none":
       return null;
     case "control":
+    case "literacy":
+    case "satisfaction":
+    case "prosperity":
+    case "marketAccess":
+    case "marketAttraction":
       return `${formatFloat(stat.value.value * 100, 2)}%`;
     case "development":
     case "wealth":
     case "unrealizedTaxBase":
     case "stateEfficacy":
+    case "proximity":
       return formatFloat(stat.value.value, 2);
     case "population":
     case "rgoLevel":
@@ -32,6 +38,15 @@ function formatHoverStat(stat: HoverStat): string | null {
       return `${formatInt(stat.value.access * 100)}%`;
     case "religion":
       return stat.value.religion.name;
+    case "culture":
+      return stat.value.culture.name;
+    case "occupation":
+      if (stat.value.controller) {
+        return `Occupied by ${stat.value.controller.country.name}`;
+      }
+      return stat.value.occupiedLocations > 0
+        ? `${formatInt(stat.value.occupiedLocations)} occupied`
+        : "Not occupied";
   }
 
   const _exhaustive: never = stat;
@@ -14,6 +14,10 @@ import { MarketsInsight } from "./features/insights/Markets";
 import { PopulationInsight } from "./features/insights/Population";
 import { BuildingLevelsInsight } from "./features/insights/BuildingLevels";
 import { ReligionInsight } from "./features/insights/ReligionInsight";
+import { CultureInsight } from "./features/insights/CultureInsight";
+import { PopAttributeInsight } from "./features/insights/PopAttributeInsight";
+import { ProsperityInsight } from "./features/insights/ProsperityInsight";
+import { WarsInsight } from "./features/insights/WarsInsight";
 import { RgoInsight } from "./features/insights/Rgo";
 import { PoliticalInsight } from "./features/insights/Political";
 import { EntityProfileRoot } from "./features/profiles";
@@ -92,14 +96,26 @@ function PanelContentInner() {
     content = <WealthInsight />;
   } else if (currentMapMode === "unrealizedTaxBase") {
     content = <UnrealizedTaxBaseInsight />;
-  } else if (currentMapMode === "markets") {
+  } else if (currentMapMode === "markets" || currentMapMode === "marketAttraction") {
     content = <MarketsInsight />;
   } else if (currentMapMode === "population") {
     content = <PopulationInsight />;
+  } else if (currentMapMode === "literacy" || currentMapMode === "satisfaction") {
+    content = <PopAttributeInsight key={currentMapMode} metric={currentMapMode} />;
+  } else if (
+    currentMapMode === "prosperity" ||
+    currentMapMode === "proximity" ||
+    currentMapMode === "marketAccess"
+  ) {
+    content = <ProsperityInsight />;
   } else if (currentMapMode === "buildingLevels") {
     content = <BuildingLevelsInsight />;
   } else if (currentMapMode === "religion") {
     content = <ReligionInsight />;
+  } else if (currentMapMode === "culture") {
+    content = <CultureInsight />;
+  } else if (currentMapMode === "occupation") {
+    content = <WarsInsight />;
   } else if (currentMapMode === "rgoLevel") {
     content = <RgoInsight />;
   }
@@ -142,9 +158,17 @@ export const MAP_MODE_TITLES = {
   wealth: "Wealth",
   unrealizedTaxBase: "Tax Base Gap",
   markets: "Markets",
+  marketAccess: "Market Access",
+  marketAttraction: "Market Attraction",
+  prosperity: "Prosperity",
+  proximity: "Proximity",
   population: "Population",
+  literacy: "Literacy",
+  satisfaction: "Satisfaction",
   buildingLevels: "Building Levels",
   religion: "Religion",
+  culture: "Culture",
+  occupation: "Wars",
   rgoLevel: "RGO Level",
 } as const;
 
@@ -9,12 +9,20 @@ export const MAP_MODES: ModeConfig[] = [
   { value: "political", label: "Political" },
   { value: "control", label: "Control" },
   { value: "religion", label: "Religion" },
+  { value: "culture", label: "Culture" },
+  { value: "occupation", label: "Occupation" },
   { value: "markets", label: "Markets" },
+  { value: "marketAccess", label: "Market Access" },
+  { value: "marketAttraction", label: "Market Attraction" },
+  { value: "prosperity", label: "Prosperity" },
+  { value: "proximity", label: "Proximity" },
   { value: "development", label: "Development" },
   { value: "wealth", label: "Wealth" },
   { value: "unrealizedTaxBase", label: "Tax Base Gap" },
   { value: "rgoLevel", label: "RGO Level" },
   { value: "buildingLevels", label: "Building Levels" },
   { value: "population", label: "Population" },
+  { value: "literacy", label: "Literacy" },
+  { value: "satisfaction", label: "Satisfaction" },
   { value: "stateEfficacy", label: "State Efficacy" },
 ];
@@ -0,0 +1,225 @@
+import { useMemo } from "react";
+import { createColumnHelper } from "@tanstack/react-table";
+import { EChart } from "@/components/viz";
+import type { EChartsOption } from "@/components/viz";
+import { Eu5DataTable, SectionTitle, StatItem } from "../../components";
+import type { CountryCultureRow, CultureRow, CultureScopeSummary } from "@/wasm/wasm_eu5";
+import { formatFloat, formatInt } from "@/lib/format";
+import { escapeEChartsHtml } from "@/components/viz/EChart";
+import { chartTooltip, getEChartsTheme, seriesColor } from "@/components/viz/echartsTheme";
+import { InsightScopeHeader, InsightScopeHeaderSkeleton } from "../InsightScopeHeader";
+import { useEu5SelectionTrigger } from "../profiles/useEu5Trigger";
+import { CountryLink } from "../profiles/EntityLink";
+import {
+  Eu5InsightEmptyState,
+  Eu5InsightErrorState,
+  Eu5InsightLoadingState,
+} from "../Eu5InsightState";
+
+const CULTURE_CAP = 30;
+const BACK_LABEL = "Culture";
+
+function formatPercent(value: number, digits = 1) {
+  return `${formatFloat(value * 100, digits)}%`;
+}
+
+function CultureScopeHeader({ data }: { data?: CultureScopeSummary }) {
+  if (!data) return <InsightScopeHeaderSkeleton />;
+
+  return (
+    <InsightScopeHeader>
+      <StatItem
+        label={data.isEmpty ? "Countries" : "Entities"}
+        value={formatInt(data.countryCount)}
+      />
+      <StatItem label="Locations" value={formatInt(data.locationCount)} />
+      <StatItem label="Population" value={formatInt(data.totalPopulation)} />
+      <StatItem label="Cultures" value={formatInt(data.cultureCount)} />
+    </InsightScopeHeader>
+  );
+}
+
+export function CultureInsight() {
+  const insightQuery = useEu5SelectionTrigger((engine) => engine.trigger.getCultureInsight());
+
+  const cultures = insightQuery.data?.cultures ?? [];
+  const countries = insightQuery.data?.countries ?? [];
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <CultureScopeHeader data={insightQuery.data?.scope} />
+      {insightQuery.error ? (
+        <Eu5InsightErrorState error={insightQuery.error} />
+      ) : insightQuery.loading && !insightQuery.data ? (
+        <Eu5InsightLoadingState />
+      ) : (
+        <>
+          {cultures.length > 0 && (
+            <section>
+              <SectionTitle>Which cultures hold the most population?</SectionTitle>
+              <CultureShareChart cultures={cultures} />
+            </section>
+          )}
+
+          {countries.length > 0 && (
+            <section>
+              <SectionTitle>How culturally diverse are the countries?</SectionTitle>
+              <CountryCultureTable countries={countries} />
+            </section>
+          )}
+
+          {cultures.length === 0 && (
+            <Eu5InsightEmptyState title="No culture data in the selected scope." />
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+function cultureTooltip(row: CultureRow): string {
+  return [
+    `<strong>${escapeEChartsHtml(row.culture.name)}</strong>`,
+    `Population: ${formatInt(row.population)} (${formatPercent(row.populationShare)})`,
+    `Dominant in locations: ${formatInt(row.dominantLocationCount)}`,
+    `Dominant in countries: ${formatInt(row.dominantCountryCount)}`,
+  ].join("<br/>");
+}
+
+function CultureShareChart({ cultures }: { cultures: CultureRow[] }) {
+  const rows = useMemo(() => cultures.slice(0, CULTURE_CAP), [cultures]);
+
+  const option = useMemo((): EChartsOption => {
+    const { axisColor, gridLineColor, tickColor } = getEChartsTheme();
+
+    return {
+      dataset: {
+        source: rows.map((r) => ({ culture: r.culture.name, population: r.population })),
+        dimensions: ["culture", "population"],
+      },
+      grid: { left: 110, right: 24, top: 10, bottom: 24 },
+      xAxis: {
+        type: "value",
+        axisLabel: {
+          color: tickColor,
+          fontSize: 10,
+          formatter: (value: number) => formatInt(value),
+        },
+        axisLine: { lineStyle: { color: axisColor } },
+        splitLine: { lineStyle: { type: "dashed", color: gridLineColor, opacity: 0.5, width: 1 } },
+      },
+      yAxis: {
+        type: "category",
+        inverse: true,
+        axisLabel: { color: tickColor, fontSize: 11, fontWeight: 600, width: 100 },
+        axisLine: { lineStyle: { color: axisColor } },
+      },
+      tooltip: {
+        ...chartTooltip,
+        trigger: "axis",
+        axisPointer: { type: "shadow" },
+        formatter: (params) => {
+          const arr = Array.isArray(params) ? params : [params];
+          const idx = (arr[0] as { dataIndex?: number } | undefined)?.dataIndex;
+          if (idx == null) return "";
+          const row = rows[idx];
+          return row ? cultureTooltip(row) : "";
+        },
+      },
+      series: [
+        {
+          name: "Population",
+          type: "bar",
+          encode: { x: "population", y: "culture" },
+          itemStyle: {
+            color: (params: { dataIndex: number }) => {
+              return rows[params.dataIndex]?.colorHex ?? seriesColor(0);
+            },
+          },
+        },
+      ],
+    };
+  }, [rows]);
+
+  const height = rows.length * 24 + 34;
+  return <EChart option={option} style={{ height: `${height}px`, width: "100%" }} />;
+}
+
+const countryColumnHelper = createColumnHelper<CountryCultureRow>();
+
+function CultureMixBar({ row }: { row: CountryCultureRow }) {
+  const total = row.totalPopulation;
+  if (total === 0) return <span className="text-game-ink-500">—</span>;
+  return (
+    <div
+      className="flex h-2 w-24 overflow-hidden rounded-sm bg-game-panel-hover"
+      title={row.topCultures
+        .map((c) => `${c.culture.name}: ${formatPercent(c.population / total)}`)
+        .join(" · ")}
+    >
+      {row.topCultures.map((c) => (
+        <div
+          key={c.culture.key}
+          style={{ width: `${(c.population / total) * 100}%`, backgroundColor: c.colorHex }}
+        />
+      ))}
+    </div>
+  );
+}
+
+function CountryCultureTable({ countries }: { countries: CountryCultureRow[] }) {
+  const columns = useMemo(
+    () => [
+      countryColumnHelper.accessor((row) => row.country.country.name, {
+        id: "country",
+        sortingFn: "text",
+        meta: Eu5DataTable.meta({ headerLabel: "Country", variant: "pin" }),
+        cell: ({ row }) => (
+          <CountryLink country={row.original.country} aligned backLabel={BACK_LABEL} />
+        ),
+      }),
+      countryColumnHelper.accessor("totalPopulation", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Pop", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatInt(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      countryColumnHelper.accessor("cultureCount", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Cultures", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatInt(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      countryColumnHelper.accessor("dominantCultureShare", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Largest Culture" }),
+        cell: ({ row }) => {
+          const r = row.original;
+          const top = r.topCultures[0];
+          return (
+            <div className="flex flex-col gap-1">
+              <CultureMixBar row={r} />
+              <span className="text-[11px] text-game-ink-300">
+                {top ? `${top.culture.name} ` : ""}
+                {formatPercent(r.dominantCultureShare)}
+              </span>
+            </div>
+          );
+        },
+      }),
+    ],
+    [],
+  );
+
+  return (
+    <Eu5DataTable
+      className="w-full"
+      columns={columns}
+      data={countries}
+      initialSorting={[{ id: "totalPopulation", desc: true }]}
+      pagination
+    />
+  );
+}
@@ -0,0 +1,346 @@
+import { useMemo } from "react";
+import { createColumnHelper } from "@tanstack/react-table";
+import { EChart } from "@/components/viz";
+import type { EChartsOption } from "@/components/viz";
+import { Eu5DataTable, SectionTitle, StatItem } from "../../components";
+import type {
+  CountryPopTypeAttributeCell,
+  CountryRef,
+  PopAttributeInsightData,
+  PopAttributeLocation,
+  PopAttributeScopeSummary,
+  PopTypeAttributeRow,
+} from "@/wasm/wasm_eu5";
+import { formatFloat, formatInt } from "@/lib/format";
+import { chartTooltip, getEChartsTheme, seriesColor } from "@/components/viz/echartsTheme";
+import { InsightScopeHeader, InsightScopeHeaderSkeleton } from "../InsightScopeHeader";
+import { useEu5SelectionTrigger } from "../profiles/useEu5Trigger";
+import { LocationLink } from "../profiles/LocationLink";
+import { CountryLink } from "../profiles/EntityLink";
+import {
+  Eu5InsightEmptyState,
+  Eu5InsightErrorState,
+  Eu5InsightLoadingState,
+} from "../Eu5InsightState";
+
+type PopAttributeMetric = "literacy" | "satisfaction";
+
+function formatPercent(value: number, digits = 1) {
+  return `${formatFloat(value * 100, digits)}%`;
+}
+
+function PopAttributeScopeHeader({
+  data,
+  label,
+}: {
+  data?: PopAttributeScopeSummary;
+  label: string;
+}) {
+  if (!data) return <InsightScopeHeaderSkeleton />;
+
+  return (
+    <InsightScopeHeader>
+      <StatItem
+        label={data.isEmpty ? "Countries" : "Entities"}
+        value={formatInt(data.countryCount)}
+      />
+      <StatItem label="Locations" value={formatInt(data.locationCount)} />
+      <StatItem label="Population" value={formatInt(data.totalPopulation)} />
+      <StatItem label={`Avg ${label}`} value={formatPercent(data.avgValue)} />
+      {!data.isEmpty && (
+        <StatItem label="World Avg" value={formatPercent(data.baselineAvgValue)} />
+      )}
+    </InsightScopeHeader>
+  );
+}
+
+export function PopAttributeInsight({ metric }: { metric: PopAttributeMetric }) {
+  const insightQuery = useEu5SelectionTrigger(
+    (engine): Promise<PopAttributeInsightData> =>
+      metric === "literacy"
+        ? engine.trigger.getLiteracyInsight()
+        : engine.trigger.getSatisfactionInsight(),
+    [metric],
+  );
+
+  const data = insightQuery.data;
+  const label = data?.metricLabel ?? (metric === "literacy" ? "Literacy" : "Satisfaction");
+  const types = data?.types ?? [];
+  const countryCells = data?.countryCells ?? [];
+  const lowestLocations = data?.lowestLocations ?? [];
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <PopAttributeScopeHeader data={data?.scope} label={label} />
+      {insightQuery.error ? (
+        <Eu5InsightErrorState error={insightQuery.error} />
+      ) : insightQuery.loading && !data ? (
+        <Eu5InsightLoadingState />
+      ) : (
+        <>
+          {types.length > 0 && (
+            <section>
+              <SectionTitle>{`${label} by estate`}</SectionTitle>
+              <PopTypeChart types={types} label={label} showBaseline={!data?.scope.isEmpty} />
+            </section>
+          )}
+
+          {countryCells.length > 0 && (
+            <section>
+              <SectionTitle>
+                {`Which estates have the lowest ${label.toLowerCase()} where?`}
+              </SectionTitle>
+              <CountryPopTypeTable cells={countryCells} types={types} label={label} />
+            </section>
+          )}
+
+          {lowestLocations.length > 0 && (
+            <section>
+              <SectionTitle>{`Lowest ${label.toLowerCase()} locations`}</SectionTitle>
+              <LowestLocationsTable locations={lowestLocations} label={label} />
+            </section>
+          )}
+
+          {types.length === 0 && (
+            <Eu5InsightEmptyState title="No population in the selected scope." />
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+function PopTypeChart({
+  types,
+  label,
+  showBaseline,
+}: {
+  types: PopTypeAttributeRow[];
+  label: string;
+  showBaseline: boolean;
+}) {
+  const option = useMemo((): EChartsOption => {
+    const { axisColor, gridLineColor, tickColor } = getEChartsTheme();
+
+    return {
+      dataset: {
+        source: types.map((r) => ({
+          populationType: r.populationType,
+          value: r.avgValue * 100,
+          baseline: r.baselineAvgValue * 100,
+        })),
+        dimensions: ["populationType", "value", "baseline"],
+      },
+      grid: { left: 80, right: 24, top: showBaseline ? 30 : 10, bottom: 24 },
+      legend: showBaseline
+        ? { top: 0, textStyle: { color: tickColor, fontSize: 11 } }
+        : { show: false },
+      xAxis: {
+        type: "value",
+        min: 0,
+        max: 100,
+        axisLabel: { color: tickColor, fontSize: 10, formatter: "{value}%" },
+        axisLine: { lineStyle: { color: axisColor } },
+        splitLine: { lineStyle: { type: "dashed", color: gridLineColor, opacity: 0.5, width: 1 } },
+      },
+      yAxis: {
+        type: "category",
+        inverse: true,
+        axisLabel: { color: tickColor, fontSize: 11, fontWeight: 600 },
+        axisLine: { lineStyle: { color: axisColor } },
+      },
+      tooltip: {
+        ...chartTooltip,
+        trigger: "axis",
+        axisPointer: { type: "shadow" },
+        formatter: (params) => {
+          const arr = Array.isArray(params) ? params : [params];
+          const idx = (arr[0] as { dataIndex?: number } | undefined)?.dataIndex;
+          if (idx == null) return "";
+          const row = types[idx];
+          if (!row) return "";
+          const lines = [
+            `<strong>${row.populationType}</strong>`,
+            `Population: ${formatInt(row.population)}`,
+            `${label}: ${formatPercent(row.avgValue)}`,
+          ];
+          if (showBaseline) {
+            lines.push(`World: ${formatPercent(row.baselineAvgValue)}`);
+          }
+          return lines.join("<br/>");
+        },
+      },
+      series: [
+        {
+          name: "Selection",
+          type: "bar",
+          encode: { x: "value", y: "populationType" },
+          itemStyle: { color: seriesColor(0) },
+        },
+        ...(showBaseline
+          ? [
+              {
+                name: "World",
+                type: "bar" as const,
+                encode: { x: "baseline", y: "populationType" },
+                itemStyle: { color: seriesColor(1), opacity: 0.6 },
+              },
+            ]
+          : []),
+      ],
+    };
+  }, [types, label, showBaseline]);
+
+  const height = types.length * (showBaseline ? 32 : 24) + (showBaseline ? 54 : 34);
+  return <EChart option={option} style={{ height: `${height}px`, width: "100%" }} />;
+}
+
+type CountryPopTypeRow = {
+  country: CountryRef;
+  population: number;
+  cells: Map<string, CountryPopTypeAttributeCell>;
+};
+
+const countryColumnHelper = createColumnHelper<CountryPopTypeRow>();
+
+function CountryPopTypeTable({
+  cells,
+  types,
+  label,
+}: {
+  cells: CountryPopTypeAttributeCell[];
+  types: PopTypeAttributeRow[];
+  label: string;
+}) {
+  const rows = useMemo(() => {
+    const byCountry = new Map<string, CountryPopTypeRow>();
+    for (const cell of cells) {
+      const key = cell.country.country.key;
+      let row = byCountry.get(key);
+      if (!row) {
+        row = { country: cell.country, population: 0, cells: new Map() };
+        byCountry.set(key, row);
+      }
+      row.population += cell.population;
+      row.cells.set(cell.populationType, cell);
+    }
+    return [...byCountry.values()];
+  }, [cells]);
+
+  const columns = useMemo(
+    () => [
+      countryColumnHelper.accessor((row) => row.country.country.name, {
+        id: "country",
+        sortingFn: "text",
+        meta: Eu5DataTable.meta({ headerLabel: "Country", variant: "pin" }),
+        cell: ({ row }) => (
+          <CountryLink country={row.original.country} aligned backLabel={label} />
+        ),
+      }),
+      countryColumnHelper.accessor("population", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Pop", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatInt(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      ...types.map((type) =>
+        countryColumnHelper.accessor(
+          (row) => row.cells.get(type.populationType)?.avgValue ?? Number.NaN,
+          {
+            id: type.populationType,
+            sortingFn: "basic",
+            meta: Eu5DataTable.meta({ headerLabel: type.populationType, variant: "num" }),
+            cell: ({ row }) => {
+              const cell = row.original.cells.get(type.populationType);
+              if (!cell) return <span className="text-game-ink-500">—</span>;
+              return (
+                <Eu5DataTable.NumericCell
+                  title={`${formatInt(cell.population)} ${type.populationType}`}
+                >
+                  {formatPercent(cell.avgValue)}
+                </Eu5DataTable.NumericCell>
+              );
+            },
+          },
+        ),
+      ),
+    ],
+    [types, label],
+  );
+
+  return (
+    <Eu5DataTable
+      className="w-full"
+      columns={columns}
+      data={rows}
+      initialSorting={[{ id: "population", desc: true }]}
+    />
+  );
+}
+
+const locationColumnHelper = createColumnHelper<PopAttributeLocation>();
+
+function LowestLocationsTable({
+  locations,
+  label,
+}: {
+  locations: PopAttributeLocation[];
+  label: string;
+}) {
+  const columns = useMemo(
+    () => [
+      locationColumnHelper.accessor("location", {
+        id: "location",
+        sortingFn: (a, b) => a.original.location.name.localeCompare(b.original.location.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Location", variant: "pin" }),
+        cell: ({ row }) => <LocationLink location={row.original.location} backLabel={label} />,
+      }),
+      locationColumnHelper.accessor("owner", {
+        id: "owner",
+        sortingFn: (a, b) =>
+          a.original.owner.country.name.localeCompare(b.original.owner.country.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Owner" }),
+        cell: ({ row }) => <CountryLink country={row.original.owner} aligned backLabel={label} />,
+      }),
+      locationColumnHelper.accessor("population", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Pop", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatInt(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      locationColumnHelper.accessor("avgValue", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: label, variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatPercent(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      locationColumnHelper.accessor("lowestTypeValue", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Lowest Estate" }),
+        cell: ({ row }) => {
+          const r = row.original;
+          if (!r.lowestType) return <span className="text-game-ink-500">—</span>;
+          return (
+            <span className="text-[11px] text-game-ink-300">
+              {r.lowestType} {formatPercent(r.lowestTypeValue)}
+            </span>
+          );
+        },
+      }),
+    ],
+    [label],
+  );
+
+  return (
+    <Eu5DataTable
+      className="w-full"
+      columns={columns}
+      data={locations}
+      initialSorting={[{ id: "avgValue", desc: false }]}
+      pagination
+    />
+  );
+}
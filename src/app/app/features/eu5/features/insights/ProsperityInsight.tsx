@@ -0,0 +1,261 @@
+import { useMemo } from "react";
+import { createColumnHelper } from "@tanstack/react-table";
+import { EChart } from "@/components/viz";
+import type { EChartsOption } from "@/components/viz";
+import { Eu5DataTable, SectionTitle, StatItem } from "../../components";
+import type {
+  ProsperityBand,
+  ProsperityFactor,
+  ProsperityLocation,
+  ProsperityScopeSummary,
+} from "@/wasm/wasm_eu5";
+import { formatFloat, formatInt } from "@/lib/format";
+import { chartTooltip, getEChartsTheme, seriesColor } from "@/components/viz/echartsTheme";
+import { InsightScopeHeader, InsightScopeHeaderSkeleton } from "../InsightScopeHeader";
+import { useEu5SelectionTrigger } from "../profiles/useEu5Trigger";
+import { LocationLink } from "../profiles/LocationLink";
+import { CountryLink } from "../profiles/EntityLink";
+import {
+  Eu5InsightEmptyState,
+  Eu5InsightErrorState,
+  Eu5InsightLoadingState,
+} from "../Eu5InsightState";
+
+const BACK_LABEL = "Prosperity";
+
+function formatPercent(value: number, digits = 1) {
+  return `${formatFloat(value * 100, digits)}%`;
+}
+
+function ProsperityScopeHeader({ data }: { data?: ProsperityScopeSummary }) {
+  if (!data) return <InsightScopeHeaderSkeleton />;
+
+  return (
+    <InsightScopeHeader>
+      <StatItem
+        label={data.isEmpty ? "Countries" : "Entities"}
+        value={formatInt(data.countryCount)}
+      />
+      <StatItem label="Locations" value={formatInt(data.locationCount)} />
+      <StatItem label="Avg Prosperity" value={formatPercent(data.avgProsperity)} />
+      <StatItem label="Avg Proximity" value={formatFloat(data.avgProximity, 1)} />
+      <StatItem label="Avg Control" value={formatPercent(data.avgControl)} />
+      <StatItem label="Avg Market Access" value={formatPercent(data.avgMarketAccess)} />
+    </InsightScopeHeader>
+  );
+}
+
+export function ProsperityInsight() {
+  const insightQuery = useEu5SelectionTrigger((engine) => engine.trigger.getProsperityInsight());
+
+  const factors = insightQuery.data?.factors ?? [];
+  const bands = insightQuery.data?.bands ?? [];
+  const poorestLocations = insightQuery.data?.poorestLocations ?? [];
+  const locationCount = insightQuery.data?.scope.locationCount ?? 0;
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <ProsperityScopeHeader data={insightQuery.data?.scope} />
+      {insightQuery.error ? (
+        <Eu5InsightErrorState error={insightQuery.error} />
+      ) : insightQuery.loading && !insightQuery.data ? (
+        <Eu5InsightLoadingState />
+      ) : locationCount === 0 ? (
+        <Eu5InsightEmptyState title="No owned locations in the selected scope." />
+      ) : (
+        <>
+          <section>
+            <SectionTitle>What holds prosperity back?</SectionTitle>
+            <FactorTable factors={factors} />
+          </section>
+
+          <section>
+            <SectionTitle>Factors by prosperity band</SectionTitle>
+            <ProsperityBandChart bands={bands} />
+          </section>
+
+          {poorestLocations.length > 0 && (
+            <section>
+              <SectionTitle>Least prosperous locations</SectionTitle>
+              <PoorestLocationsTable locations={poorestLocations} />
+            </section>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+const factorColumnHelper = createColumnHelper<ProsperityFactor>();
+
+function FactorTable({ factors }: { factors: ProsperityFactor[] }) {
+  const columns = useMemo(
+    () => [
+      factorColumnHelper.accessor("factor", {
+        sortingFn: "text",
+        meta: Eu5DataTable.meta({ headerLabel: "Factor" }),
+      }),
+      factorColumnHelper.accessor("correlation", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Correlation", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatFloat(info.getValue(), 2)}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      factorColumnHelper.accessor("avgInPoorest", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Poorest 25%", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatPercent(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      factorColumnHelper.accessor("avgInRest", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Rest", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatPercent(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+    ],
+    [],
+  );
+
+  return (
+    <Eu5DataTable
+      className="w-full"
+      columns={columns}
+      data={factors}
+      initialSorting={[{ id: "correlation", desc: true }]}
+    />
+  );
+}
+
+function ProsperityBandChart({ bands }: { bands: ProsperityBand[] }) {
+  const option = useMemo((): EChartsOption => {
+    const { axisColor, gridLineColor, tickColor } = getEChartsTheme();
+    const series = [
+      { name: "Proximity", key: "avgProximity" },
+      { name: "Control", key: "avgControl" },
+      { name: "Market Access", key: "avgMarketAccess" },
+    ] as const;
+
+    return {
+      grid: { left: 48, right: 24, top: 30, bottom: 40 },
+      legend: { top: 0, textStyle: { color: tickColor, fontSize: 11 } },
+      xAxis: {
+        type: "category",
+        data: bands.map((b) => b.band),
+        name: "Prosperity",
+        nameLocation: "middle",
+        nameGap: 26,
+        axisLabel: { color: tickColor, fontSize: 10 },
+        axisLine: { lineStyle: { color: axisColor } },
+      },
+      yAxis: {
+        type: "value",
+        min: 0,
+        max: 1,
+        axisLabel: {
+          color: tickColor,
+          fontSize: 10,
+          formatter: (value: number) => formatPercent(value, 0),
+        },
+        axisLine: { lineStyle: { color: axisColor } },
+        splitLine: { lineStyle: { type: "dashed", color: gridLineColor, opacity: 0.5, width: 1 } },
+      },
+      tooltip: {
+        ...chartTooltip,
+        trigger: "axis",
+        axisPointer: { type: "shadow" },
+        formatter: (params) => {
+          const arr = Array.isArray(params) ? params : [params];
+          const idx = (arr[0] as { dataIndex?: number } | undefined)?.dataIndex;
+          if (idx == null) return "";
+          const band = bands[idx];
+          if (!band) return "";
+          return [
+            `<strong>Prosperity ${band.band}</strong>`,
+            `Locations: ${formatInt(band.locationCount)}`,
+            ...series.map((s) => `${s.name}: ${formatPercent(band[s.key])}`),
+          ].join("<br/>");
+        },
+      },
+      series: series.map((s, i) => ({
+        name: s.name,
+        type: "bar" as const,
+        data: bands.map((b) => (b.locationCount > 0 ? b[s.key] : null)),
+        itemStyle: { color: seriesColor(i) },
+      })),
+    };
+  }, [bands]);
+
+  return <EChart option={option} style={{ height: "260px", width: "100%" }} />;
+}
+
+const locationColumnHelper = createColumnHelper<ProsperityLocation>();
+
+function PoorestLocationsTable({ locations }: { locations: ProsperityLocation[] }) {
+  const columns = useMemo(
+    () => [
+      locationColumnHelper.accessor("location", {
+        id: "location",
+        sortingFn: (a, b) => a.original.location.name.localeCompare(b.original.location.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Location", variant: "pin" }),
+        cell: ({ row }) => <LocationLink location={row.original.location} backLabel={BACK_LABEL} />,
+      }),
+      locationColumnHelper.accessor("owner", {
+        id: "owner",
+        sortingFn: (a, b) =>
+          a.original.owner.country.name.localeCompare(b.original.owner.country.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Owner" }),
+        cell: ({ row }) => (
+          <CountryLink country={row.original.owner} aligned backLabel={BACK_LABEL} />
+        ),
+      }),
+      locationColumnHelper.accessor("prosperity", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Prosperity", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatPercent(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      locationColumnHelper.accessor("proximity", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Proximity", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatFloat(info.getValue(), 1)}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      locationColumnHelper.accessor("control", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Control", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatPercent(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      locationColumnHelper.accessor("marketAccess", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Access", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatPercent(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+      locationColumnHelper.accessor("limitingFactor", {
+        sortingFn: "text",
+        meta: Eu5DataTable.meta({ headerLabel: "Weakest Factor" }),
+        cell: (info) => <span className="text-[11px] text-game-ink-300">{info.getValue()}</span>,
+      }),
+    ],
+    [],
+  );
+
+  return (
+    <Eu5DataTable
+      className="w-full"
+      columns={columns}
+      data={locations}
+      initialSorting={[{ id: "prosperity", desc: false }]}
+      pagination
+    />
+  );
+}
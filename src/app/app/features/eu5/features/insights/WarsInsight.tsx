@@ -0,0 +1,219 @@
+import { useMemo, useState } from "react";
+import { createColumnHelper } from "@tanstack/react-table";
+import { Eu5DataTable, Eu5MapDataTable, SectionTitle, StatItem } from "../../components";
+import type { OccupiedLocation, WarBattle, WarSummary, WarsScopeSummary } from "@/wasm/wasm_eu5";
+import { formatFloat, formatInt } from "@/lib/format";
+import { InsightScopeHeader, InsightScopeHeaderSkeleton } from "../InsightScopeHeader";
+import { useEu5SelectionTrigger } from "../profiles/useEu5Trigger";
+import { LocationLink } from "../profiles/LocationLink";
+import { CountryLink } from "../profiles/EntityLink";
+import {
+  Eu5InsightEmptyState,
+  Eu5InsightErrorState,
+  Eu5InsightLoadingState,
+} from "../Eu5InsightState";
+
+const BACK_LABEL = "Wars";
+
+function WarsScopeHeader({ data }: { data?: WarsScopeSummary }) {
+  if (!data) return <InsightScopeHeaderSkeleton />;
+
+  return (
+    <InsightScopeHeader>
+      <StatItem label="Locations" value={formatInt(data.locationCount)} />
+      <StatItem label="Occupied" value={formatInt(data.occupiedLocationCount)} />
+      <StatItem label="Occupied Dev" value={formatFloat(data.occupiedDevelopment, 1)} />
+      <StatItem label="Wars" value={formatInt(data.warCount)} />
+      <StatItem label="Battles" value={formatInt(data.battleCount)} />
+    </InsightScopeHeader>
+  );
+}
+
+export function WarsInsight() {
+  const insightQuery = useEu5SelectionTrigger((engine) => engine.trigger.getWarsInsight());
+  const [selectedWarId, setSelectedWarId] = useState<number | null>(null);
+
+  const wars = insightQuery.data?.wars ?? [];
+  const occupiedLocations = insightQuery.data?.occupiedLocations ?? [];
+  const selectedWar = wars.find((war) => war.warId === selectedWarId) ?? wars[0];
+
+  return (
+    <div className="flex flex-col gap-4 p-4">
+      <WarsScopeHeader data={insightQuery.data?.scope} />
+      {insightQuery.error ? (
+        <Eu5InsightErrorState error={insightQuery.error} />
+      ) : insightQuery.loading && !insightQuery.data ? (
+        <Eu5InsightLoadingState />
+      ) : (
+        <>
+          {wars.length > 0 && (
+            <section>
+              <SectionTitle>Wars</SectionTitle>
+              <WarsTable
+                wars={wars}
+                selectedWarId={selectedWar?.warId}
+                onSelect={setSelectedWarId}
+              />
+            </section>
+          )}
+
+          {selectedWar && selectedWar.battles.length > 0 && (
+            <section>
+              <SectionTitle>{`Battles of war #${selectedWar.warId}`}</SectionTitle>
+              <BattlesTable battles={selectedWar.battles} />
+            </section>
+          )}
+
+          {occupiedLocations.length > 0 && (
+            <section>
+              <SectionTitle>Occupied locations</SectionTitle>
+              <OccupiedLocationsTable locations={occupiedLocations} />
+            </section>
+          )}
+
+          {wars.length === 0 && occupiedLocations.length === 0 && (
+            <Eu5InsightEmptyState title="No wars or occupations in the selected scope." />
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+const warColumnHelper = createColumnHelper<WarSummary>();
+
+function WarsTable({
+  wars,
+  selectedWarId,
+  onSelect,
+}: {
+  wars: WarSummary[];
+  selectedWarId: number | undefined;
+  onSelect: (warId: number) => void;
+}) {
+  const columns = useMemo(
+    () => [
+      warColumnHelper.accessor("warId", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "War", variant: "pin" }),
+        cell: (info) => {
+          const warId = info.getValue();
+          return (
+            <button
+              type="button"
+              className={warId === selectedWarId ? "font-semibold underline" : "hover:underline"}
+              onClick={() => onSelect(warId)}
+            >
+              {`#${warId}`}
+            </button>
+          );
+        },
+      }),
+      warColumnHelper.accessor("startDate", {
+        sortingFn: "text",
+        meta: Eu5DataTable.meta({ headerLabel: "Started" }),
+      }),
+      warColumnHelper.accessor("battleCount", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Battles", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatInt(info.getValue())}</Eu5DataTable.NumericCell>
+        ),
+      }),
+    ],
+    [selectedWarId, onSelect],
+  );
+
+  return (
+    <Eu5DataTable
+      className="w-full"
+      columns={columns}
+      data={wars}
+      initialSorting={[{ id: "battleCount", desc: true }]}
+      pagination
+    />
+  );
+}
+
+const battleColumnHelper = createColumnHelper<WarBattle>();
+
+function BattlesTable({ battles }: { battles: WarBattle[] }) {
+  const columns = useMemo(
+    () => [
+      battleColumnHelper.accessor("location", {
+        id: "location",
+        sortingFn: (a, b) => a.original.location.name.localeCompare(b.original.location.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Location", variant: "pin" }),
+        cell: ({ row }) => <LocationLink location={row.original.location} backLabel={BACK_LABEL} />,
+      }),
+      battleColumnHelper.accessor("date", {
+        sortingFn: "text",
+        meta: Eu5DataTable.meta({ headerLabel: "Date" }),
+      }),
+    ],
+    [],
+  );
+
+  return (
+    <Eu5MapDataTable
+      className="w-full"
+      columns={columns}
+      data={battles}
+      getRowHoverTarget={(row) => ({ kind: "location", locationIdx: row.location.key })}
+      initialSorting={[{ id: "date", desc: true }]}
+      pagination
+    />
+  );
+}
+
+const occupiedColumnHelper = createColumnHelper<OccupiedLocation>();
+
+function OccupiedLocationsTable({ locations }: { locations: OccupiedLocation[] }) {
+  const columns = useMemo(
+    () => [
+      occupiedColumnHelper.accessor("location", {
+        id: "location",
+        sortingFn: (a, b) => a.original.location.name.localeCompare(b.original.location.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Location", variant: "pin" }),
+        cell: ({ row }) => <LocationLink location={row.original.location} backLabel={BACK_LABEL} />,
+      }),
+      occupiedColumnHelper.accessor("owner", {
+        id: "owner",
+        sortingFn: (a, b) =>
+          a.original.owner.country.name.localeCompare(b.original.owner.country.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Owner" }),
+        cell: ({ row }) => (
+          <CountryLink country={row.original.owner} aligned backLabel={BACK_LABEL} />
+        ),
+      }),
+      occupiedColumnHelper.accessor("controller", {
+        id: "controller",
+        sortingFn: (a, b) =>
+          a.original.controller.country.name.localeCompare(b.original.controller.country.name),
+        meta: Eu5DataTable.meta({ headerLabel: "Controller" }),
+        cell: ({ row }) => (
+          <CountryLink country={row.original.controller} aligned backLabel={BACK_LABEL} />
+        ),
+      }),
+      occupiedColumnHelper.accessor("development", {
+        sortingFn: "basic",
+        meta: Eu5DataTable.meta({ headerLabel: "Dev", variant: "num" }),
+        cell: (info) => (
+          <Eu5DataTable.NumericCell>{formatFloat(info.getValue(), 1)}</Eu5DataTable.NumericCell>
+        ),
+      }),
+    ],
+    [],
+  );
+
+  return (
+    <Eu5MapDataTable
+      className="w-full"
+      columns={columns}
+      data={locations}
+      getRowHoverTarget={(row) => ({ kind: "location", locationIdx: row.location.key })}
+      initialSorting={[{ id: "development", desc: true }]}
+      pagination
+    />
+  );
+}
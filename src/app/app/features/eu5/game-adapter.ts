@@ -21,6 +21,10 @@ import type {
   PopulationInsightData,
   BuildingLevelsInsightData,
   ReligionInsightData,
+  CultureInsightData,
+  PopAttributeInsightData,
+  ProsperityInsightData,
+  WarsInsightData,
   RgoInsightData,
   ControlInsightData,
   PoliticalWorldScoreboard,
@@ -436,6 +440,21 @@ export function saveWorker(
     getReligionInsight: async (): Promise<ReligionInsightData> => {
       return await saveEngine.getReligionInsight();
     },
+    getCultureInsight: async (): Promise<CultureInsightData> => {
+      return await saveEngine.getCultureInsight();
+    },
+    getLiteracyInsight: async (): Promise<PopAttributeInsightData> => {
+      return await saveEngine.getLiteracyInsight();
+    },
+    getSatisfactionInsight: async (): Promise<PopAttributeInsightData> => {
+      return await saveEngine.getSatisfactionInsight();
+    },
+    getProsperityInsight: async (): Promise<ProsperityInsightData> => {
+      return await saveEngine.getProsperityInsight();
+    },
+    getWarsInsight: async (): Promise<WarsInsightData> => {
+      return await saveEngine.getWarsInsight();
+    },
     getRgoInsight: async (): Promise<RgoInsightData> => {
       return await saveEngine.getRgoInsight();
     },
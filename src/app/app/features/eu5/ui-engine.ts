@@ -28,6 +28,10 @@ import type {
   PopulationInsightData,
   BuildingLevelsInsightData,
   ReligionInsightData,
+  CultureInsightData,
+  PopAttributeInsightData,
+  ProsperityInsightData,
+  WarsInsightData,
   RgoInsightData,
   ControlInsightData,
   PoliticalWorldScoreboard,
@@ -88,6 +92,11 @@ export interface AppTriggers {
   getPopulationInsight(): Promise<PopulationInsightData>;
   getBuildingLevelsInsight(): Promise<BuildingLevelsInsightData>;
   getReligionInsight(): Promise<ReligionInsightData>;
+  getCultureInsight(): Promise<CultureInsightData>;
+  getLiteracyInsight(): Promise<PopAttributeInsightData>;
+  getSatisfactionInsight(): Promise<PopAttributeInsightData>;
+  getProsperityInsight(): Promise<ProsperityInsightData>;
+  getWarsInsight(): Promise<WarsInsightData>;
   getRgoInsight(): Promise<RgoInsightData>;
   getControlInsight(): Promise<ControlInsightData>;
   getPoliticalWorldScoreboard(): Promise<PoliticalWorldScoreboard>;
@@ -196,6 +205,11 @@ export class Eu5UIEngine implements AppEngine {
     getPopulationInsight: () => this.gameInstance.getPopulationInsight(),
     getBuildingLevelsInsight: () => this.gameInstance.getBuildingLevelsInsight(),
     getReligionInsight: () => this.gameInstance.getReligionInsight(),
+    getCultureInsight: () => this.gameInstance.getCultureInsight(),
+    getLiteracyInsight: () => this.gameInstance.getLiteracyInsight(),
+    getSatisfactionInsight: () => this.gameInstance.getSatisfactionInsight(),
+    getProsperityInsight: () => this.gameInstance.getProsperityInsight(),
+    getWarsInsight: () => this.gameInstance.getWarsInsight(),
     getRgoInsight: () => this.gameInstance.getRgoInsight(),
     getControlInsight: () => this.gameInstance.getControlInsight(),
     getPoliticalWorldScoreboard: () => this.gameInstance.getPoliticalWorldScoreboard(),
@@ -26,6 +26,10 @@ import type {
   PopulationInsightData,
   BuildingLevelsInsightData,
   ReligionInsightData,
+  CultureInsightData,
+  PopAttributeInsightData,
+  ProsperityInsightData,
+  WarsInsightData,
   RgoInsightData,
   ControlInsightData,
   PoliticalWorldScoreboard,
@@ -383,6 +387,21 @@ export const createGame = async (
     getReligionInsight: (): ReligionInsightData => {
       return app.get_religion_insight();
     },
+    getCultureInsight: (): CultureInsightData => {
+      return app.get_culture_insight();
+    },
+    getLiteracyInsight: (): PopAttributeInsightData => {
+      return app.get_literacy_insight();
+    },
+    getSatisfactionInsight: (): PopAttributeInsightData => {
+      return app.get_satisfaction_insight();
+    },
+    getProsperityInsight: (): ProsperityInsightData => {
+      return app.get_prosperity_insight();
+    },
+    getWarsInsight: (): WarsInsightData => {
+      return app.get_wars_insight();
+    },
     getRgoInsight: (): RgoInsightData => {
       return app.get_rgo_insight();
     },
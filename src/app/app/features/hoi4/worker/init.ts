@@ -16,3 +16,19 @@ export async function parseHoi4() {
 export async function countryDetails(tag: string) {
   return wasm.save.country_details(tag);
 }
+
+export async function countryDivisions(tag: string) {
+  return wasm.save.country_divisions(tag);
+}
+
+export async function countryEquipment(tag: string) {
+  return wasm.save.country_equipment(tag);
+}
+
+export async function wars() {
+  return wasm.save.wars();
+}
+
+export async function factions() {
+  return wasm.save.factions();
+}
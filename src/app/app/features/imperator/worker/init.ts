@@ -12,3 +12,19 @@ export async function parseImperator() {
   const meta: ImperatorMetadata = wasm.save.metadata();
   return { meta };
 }
+
+export function getCountries() {
+  return wasm.save.get_countries();
+}
+
+export function getProvinceDetails(provinceId: number) {
+  return wasm.save.get_province_details(provinceId);
+}
+
+export function getCountryCharacters(tag: string) {
+  return wasm.save.get_country_characters(tag);
+}
+
+export function getCountryFamilies(tag: string) {
+  return wasm.save.get_country_families(tag);
+}
@@ -39,3 +39,15 @@ export function get_country_stats(tag: string) {
 export function get_country_goods_prices(tag: string) {
   return wasm.save.get_country_goods_prices(tag);
 }
+
+export function get_country_states(tag: string) {
+  return wasm.save.get_country_states(tag);
+}
+
+export function get_country_interest_groups(tag: string) {
+  return wasm.save.get_country_interest_groups(tag);
+}
+
+export function get_country_diplomacy(tag: string) {
+  return wasm.save.get_country_diplomacy(tag);
+}
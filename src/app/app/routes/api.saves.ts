@@ -136,6 +136,12 @@ export const action = withCore(async ({ request, context }: Route.ActionArgs) =>
       throw new ValidationError(`unsupported patch: ${out.patch_shorthand}`);
     }
 
+    // The parse api recognizes saves of every game, but the saves table and
+    // the save pages are EU4 specific, so only EU4 saves can be stored.
+    if (out.kind !== "Parsed") {
+      throw new ValidationError(`only EU4 saves can be uploaded, received: ${out.kind}`);
+    }
+
     const newSave: NewSave = {
       id: saveId,
       userId: session.id,
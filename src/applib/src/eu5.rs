@@ -0,0 +1,84 @@
+use crate::parser::ParseFileError;
+use bumpalo_serde::ArenaDeserialize;
+use eu4game::shared::SaveCheckSummer;
+use eu5save::{
+    BasicTokenResolver, Eu5BinaryDeserialization, Eu5Error, Eu5ErrorKind, Eu5File, SaveContentKind,
+    SaveResolver, models::Gamestate,
+};
+use jomini::common::PdsDate;
+use serde::Serialize;
+use specta::Type;
+
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct Eu5PlayedCountry {
+    pub name: String,
+
+    /// The tag of the country, if it could be resolved from the country
+    /// database
+    pub tag: Option<String>,
+}
+
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct Eu5ParsedFile {
+    pub version: String,
+    pub date: String,
+    pub playthrough_id: String,
+    pub playthrough_name: String,
+    pub played_countries: Vec<Eu5PlayedCountry>,
+    pub is_ironman: bool,
+
+    /// Highwayhash of save data
+    pub hash: String,
+}
+
+/// Parses an EU5 save into the metadata needed to catalog it
+pub fn parse_eu5_save_data(
+    data: &[u8],
+    tokens: &BasicTokenResolver,
+) -> Result<Eu5ParsedFile, ParseFileError> {
+    let file = Eu5File::from_slice(data)
+        .map_err(Eu5ErrorKind::from)
+        .map_err(Eu5Error::from)?;
+
+    let resolver = SaveResolver::from_file(&file, tokens)?;
+    let arena = bumpalo::Bump::new();
+    let gamestate = file
+        .gamestate()
+        .map_err(Eu5ErrorKind::from)
+        .map_err(Eu5Error::from)?;
+    let save = match gamestate {
+        SaveContentKind::Text(mut text) => {
+            Gamestate::deserialize_in_arena(&mut text.deserializer(), &arena)
+        }
+        SaveContentKind::Binary(mut bin) => {
+            Gamestate::deserialize_in_arena(&mut bin.deserializer(&resolver), &arena)
+        }
+    }
+    .map_err(Eu5Error::from)?;
+
+    let played_countries = save
+        .played_countries
+        .iter()
+        .map(|player| Eu5PlayedCountry {
+            name: player.name.to_string(),
+            tag: save
+                .countries
+                .get_entry(player.country)
+                .map(|entry| entry.tag().to_string()),
+        })
+        .collect();
+
+    let mut hasher = SaveCheckSummer::new(true);
+    hasher.append(data);
+
+    let meta = save.metadata();
+    Ok(Eu5ParsedFile {
+        version: meta.version.to_string(),
+        date: meta.date.iso_8601().to_string(),
+        playthrough_id: meta.playthrough_id.to_string(),
+        playthrough_name: meta.playthrough_name.to_string(),
+        played_countries,
+        is_ironman: save.ironman,
+        hash: hasher.finish().unwrap_or_default(),
+    })
+}
@@ -0,0 +1,433 @@
+use crate::{
+    eu5::parse_eu5_save_data,
+    parser::{ParseFileError, ParseResult, parse_save_data},
+};
+use jomini::{binary::BasicTokenResolver, common::PdsDate};
+use serde::{Deserialize, Serialize, de::IgnoredAny};
+use specta::Type;
+use std::{io, path::Path};
+
+/// The games with saves that can be parsed server side
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SaveGame {
+    Eu4,
+    Eu5,
+    Ck3,
+    Hoi4,
+    Imperator,
+    Vic3,
+}
+
+impl SaveGame {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SaveGame::Eu4 => "eu4",
+            SaveGame::Eu5 => "eu5",
+            SaveGame::Ck3 => "ck3",
+            SaveGame::Hoi4 => "hoi4",
+            SaveGame::Imperator => "imperator",
+            SaveGame::Vic3 => "vic3",
+        }
+    }
+
+    /// Detects the game of a save from its header.
+    ///
+    /// EU4 and HOI4 have distinct headers, but EU5, CK3, Imperator, and Vic3
+    /// all share the same `SAV` envelope, so those saves return `None` and
+    /// need to be told apart by their metadata with
+    /// [`SaveGame::detect_from_metadata`]. Compressed data is assumed to be
+    /// EU4 as that is the only game uploaded compressed.
+    pub fn detect(data: &[u8]) -> Option<SaveGame> {
+        if data.starts_with(b"EU4txt")
+            || data.starts_with(b"EU4bin")
+            || data.starts_with(b"PK\x03\x04")
+            || data.starts_with(&[0x28, 0xb5, 0x2f, 0xfd])
+        {
+            Some(SaveGame::Eu4)
+        } else if data.starts_with(b"HOI4txt") || data.starts_with(b"HOI4bin") {
+            Some(SaveGame::Hoi4)
+        } else {
+            None
+        }
+    }
+
+    /// Detects the game of a `SAV` envelope save from the keys of its
+    /// metadata, without deserializing the gamestate.
+    ///
+    /// EU5 nests its metadata under `metadata`, while CK3 and Vic3 both use
+    /// `meta_data` but only Vic3 records a `game_date` within it. Imperator
+    /// writes its metadata at the top level. Binary metadata is resolved with
+    /// each game's tokens in turn, and a game is only detected when its own
+    /// tokens resolve the metadata as belonging to it.
+    pub fn detect_from_metadata(data: &[u8], tokens: &GameTokens) -> Option<SaveGame> {
+        use jomini::binary::TokenResolver;
+        use vic3save::{SaveMetadataKind, Vic3BinaryDeserialization, Vic3File};
+
+        let file = Vic3File::from_slice(data).ok()?;
+        if let SaveMetadataKind::Text(mut text) = file.meta().ok()? {
+            let probe: MetadataProbe = text.deserializer().deserialize().ok()?;
+            return probe.game();
+        }
+
+        let candidates = [
+            (SaveGame::Eu5, &tokens.eu5),
+            (SaveGame::Vic3, &tokens.vic3),
+            (SaveGame::Ck3, &tokens.ck3),
+            (SaveGame::Imperator, &tokens.imperator),
+        ];
+
+        candidates
+            .into_iter()
+            .filter(|(_, resolver)| !resolver.is_empty())
+            .find(|(game, resolver)| {
+                let Ok(SaveMetadataKind::Binary(mut bin)) = file.meta() else {
+                    return false;
+                };
+
+                bin.deserializer(*resolver)
+                    .deserialize::<MetadataProbe>()
+                    .ok()
+                    .and_then(|probe| probe.game())
+                    == Some(*game)
+            })
+            .map(|(game, _)| game)
+    }
+}
+
+/// The metadata keys that tell apart games that share the `SAV` envelope
+#[derive(Deserialize)]
+struct MetadataProbe {
+    metadata: Option<IgnoredAny>,
+    meta_data: Option<MetaDataProbe>,
+    version: Option<IgnoredAny>,
+}
+
+#[derive(Deserialize)]
+struct MetaDataProbe {
+    game_date: Option<IgnoredAny>,
+}
+
+impl MetadataProbe {
+    fn game(&self) -> Option<SaveGame> {
+        match (&self.metadata, &self.meta_data, &self.version) {
+            (Some(_), _, _) => Some(SaveGame::Eu5),
+            (None, Some(meta), _) if meta.game_date.is_some() => Some(SaveGame::Vic3),
+            (None, Some(_), _) => Some(SaveGame::Ck3),
+            (None, None, Some(_)) => Some(SaveGame::Imperator),
+            (None, None, None) => None,
+        }
+    }
+}
+
+/// Binary token resolvers for the games that don't embed their tokens
+#[derive(Debug)]
+pub struct GameTokens {
+    pub eu5: BasicTokenResolver,
+    pub ck3: BasicTokenResolver,
+    pub hoi4: BasicTokenResolver,
+    pub imperator: BasicTokenResolver,
+    pub vic3: BasicTokenResolver,
+}
+
+impl GameTokens {
+    /// Loads the `<game>.txt` token files found in the directory. A missing
+    /// file results in an empty resolver, so only plaintext saves of that
+    /// game will be parseable.
+    pub fn from_dir(dir: &Path) -> io::Result<GameTokens> {
+        let load = |name: &str| -> io::Result<BasicTokenResolver> {
+            let data = match std::fs::read(dir.join(name)) {
+                Ok(data) => data,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+                Err(e) => return Err(e),
+            };
+            BasicTokenResolver::from_text_lines(data.as_slice())
+        };
+
+        Ok(GameTokens {
+            eu5: load("eu5.txt")?,
+            ck3: load("ck3.txt")?,
+            hoi4: load("hoi4.txt")?,
+            imperator: load("imperator.txt")?,
+            vic3: load("vic3.txt")?,
+        })
+    }
+}
+
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct Ck3ParsedFile {
+    pub version: String,
+    pub date: String,
+    pub playthrough_id: Option<String>,
+    pub player_names: Vec<String>,
+    pub played_character: Option<u32>,
+    pub is_ironman: bool,
+}
+
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct Hoi4ParsedFile {
+    pub version: Option<String>,
+    pub date: String,
+    pub playthrough_id: Option<String>,
+    pub player_tag: Option<String>,
+    pub is_ironman: bool,
+}
+
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct ImperatorParsedFile {
+    pub version: String,
+    pub date: String,
+    pub playthrough_id: Option<String>,
+    pub player_names: Vec<String>,
+    pub is_ironman: bool,
+}
+
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct Vic3ParsedFile {
+    pub version: String,
+    pub date: String,
+    pub playthrough_id: Option<String>,
+    pub player_names: Vec<String>,
+    pub is_ironman: bool,
+}
+
+/// Parses a save of any supported game into a game tagged result.
+///
+/// The game is detected up front so that a save that fails to parse reports
+/// the error of its own game.
+pub fn parse_any_save_data(
+    data: &[u8],
+    tokens: &GameTokens,
+) -> Result<ParseResult, ParseFileError> {
+    match identify_save_game(data, tokens)? {
+        SaveGame::Eu4 => parse_save_data(data),
+        SaveGame::Eu5 => parse_eu5(data, tokens),
+        SaveGame::Ck3 => parse_ck3(data, tokens),
+        SaveGame::Hoi4 => parse_hoi4(data, tokens),
+        SaveGame::Imperator => parse_imperator(data, tokens),
+        SaveGame::Vic3 => parse_vic3(data, tokens),
+    }
+}
+
+/// Identifies the game of a save from its header, or its metadata when the
+/// header is shared between games. The gamestate is not deserialized, so
+/// tokens unknown to the resolver do not prevent identification.
+pub fn identify_save_game(data: &[u8], tokens: &GameTokens) -> Result<SaveGame, ParseFileError> {
+    SaveGame::detect(data)
+        .or_else(|| SaveGame::detect_from_metadata(data, tokens))
+        .ok_or(ParseFileError::UnknownGame)
+}
+
+fn parse_eu5(data: &[u8], tokens: &GameTokens) -> Result<ParseResult, ParseFileError> {
+    let save = parse_eu5_save_data(data, &tokens.eu5)?;
+    Ok(ParseResult::Eu5(Box::new(save)))
+}
+
+fn parse_vic3(data: &[u8], tokens: &GameTokens) -> Result<ParseResult, ParseFileError> {
+    use vic3save::{DeserializeVic3, Vic3Date, Vic3Error, Vic3File, savefile::Player};
+
+    #[derive(Deserialize)]
+    struct Vic3Summary {
+        meta_data: Vic3Metadata,
+        #[serde(default)]
+        previous_played: Vec<Player>,
+        ironman_manager: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    struct Vic3Metadata {
+        version: String,
+        game_date: Vic3Date,
+        playthrough_id: Option<String>,
+    }
+
+    let file = Vic3File::from_slice(data).map_err(Vic3Error::from)?;
+    let save: Vic3Summary = (&file).deserialize(&tokens.vic3)?;
+
+    Ok(ParseResult::Vic3(Box::new(Vic3ParsedFile {
+        version: save.meta_data.version,
+        date: save.meta_data.game_date.iso_8601().to_string(),
+        playthrough_id: save.meta_data.playthrough_id,
+        player_names: save.previous_played.into_iter().map(|x| x.name).collect(),
+        is_ironman: save.ironman_manager.is_some(),
+    })))
+}
+
+fn parse_ck3(data: &[u8], tokens: &GameTokens) -> Result<ParseResult, ParseFileError> {
+    use ck3save::{Ck3Error, Ck3File, DeserializeCk3, models::Metadata};
+    use jomini::common::Date;
+
+    #[derive(Deserialize)]
+    struct Ck3Summary {
+        meta_data: Metadata,
+        date: Date,
+        played_character: Option<Ck3PlayedCharacter>,
+        playthrough_id: Option<String>,
+        ironman_manager: Option<IgnoredAny>,
+
+        // Not read, but only CK3 saves contain living characters
+        #[expect(dead_code)]
+        living: IgnoredAny,
+    }
+
+    #[derive(Deserialize)]
+    struct Ck3PlayedCharacter {
+        name: Option<String>,
+        character: u32,
+    }
+
+    let file = Ck3File::from_slice(data).map_err(Ck3Error::from)?;
+    let save: Ck3Summary = (&file).deserialize(&tokens.ck3)?;
+    let player_names = save
+        .played_character
+        .as_ref()
+        .and_then(|x| x.name.clone())
+        .into_iter()
+        .collect();
+
+    Ok(ParseResult::Ck3(Box::new(Ck3ParsedFile {
+        version: save.meta_data.version,
+        date: save.date.iso_8601().to_string(),
+        playthrough_id: save.playthrough_id,
+        player_names,
+        played_character: save.played_character.map(|x| x.character),
+        is_ironman: save.ironman_manager.is_some(),
+    })))
+}
+
+fn parse_imperator(data: &[u8], tokens: &GameTokens) -> Result<ParseResult, ParseFileError> {
+    use imperator_save::{DeserializeImperator, ImperatorDate, ImperatorError, ImperatorFile};
+
+    #[derive(Deserialize)]
+    struct ImperatorSummary {
+        version: String,
+        date: ImperatorDate,
+        #[serde(default)]
+        played_country: Vec<ImperatorPlayedCountry>,
+        playthrough_id: Option<String>,
+        ironman_manager: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    struct ImperatorPlayedCountry {
+        name: Option<String>,
+    }
+
+    let file = ImperatorFile::from_slice(data).map_err(ImperatorError::from)?;
+    let save: ImperatorSummary = (&file).deserialize(&tokens.imperator)?;
+
+    Ok(ParseResult::Imperator(Box::new(ImperatorParsedFile {
+        version: save.version,
+        date: save.date.iso_8601().to_string(),
+        playthrough_id: save.playthrough_id,
+        player_names: save
+            .played_country
+            .into_iter()
+            .filter_map(|x| x.name)
+            .collect(),
+        is_ironman: save.ironman_manager.is_some(),
+    })))
+}
+
+fn parse_hoi4(data: &[u8], tokens: &GameTokens) -> Result<ParseResult, ParseFileError> {
+    use hoi4save::{Hoi4Date, Hoi4Error, Hoi4File, Hoi4SliceFileKind};
+
+    #[derive(Deserialize)]
+    struct Hoi4Summary {
+        player: Option<String>,
+        date: Hoi4Date,
+        version: Option<String>,
+        game_unique_id: Option<String>,
+        #[serde(default)]
+        ironman: bool,
+    }
+
+    let file = Hoi4File::from_slice(data)?;
+    let save: Hoi4Summary = match file.kind() {
+        Hoi4SliceFileKind::Text(x) => x.deserializer().deserialize().map_err(Hoi4Error::from)?,
+        Hoi4SliceFileKind::Binary(x) => x
+            .deserializer(&tokens.hoi4)
+            .deserialize()
+            .map_err(Hoi4Error::from)?,
+    };
+
+    Ok(ParseResult::Hoi4(Box::new(Hoi4ParsedFile {
+        version: save.version,
+        date: save.date.iso_8601().to_string(),
+        playthrough_id: save.game_unique_id,
+        player_tag: save.player,
+        is_ironman: save.ironman,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_game_from_header() {
+        assert_eq!(
+            SaveGame::detect(b"EU4txt\ndate=1444.11.11"),
+            Some(SaveGame::Eu4)
+        );
+        assert_eq!(SaveGame::detect(b"EU4bin\x00\x00"), Some(SaveGame::Eu4));
+        assert_eq!(SaveGame::detect(b"PK\x03\x04\x14\x00"), Some(SaveGame::Eu4));
+        assert_eq!(SaveGame::detect(b"HOI4txt\n"), Some(SaveGame::Hoi4));
+        assert_eq!(SaveGame::detect(b"SAV0103e3ad6b4c0000"), None);
+        assert_eq!(SaveGame::detect(b""), None);
+    }
+
+    fn text_save(meta: &str) -> Vec<u8> {
+        format!("SAV0100{:08x}{:08x}\n{}", 0, meta.len(), meta).into_bytes()
+    }
+
+    fn empty_tokens() -> GameTokens {
+        GameTokens::from_dir(Path::new("does-not-exist")).unwrap()
+    }
+
+    #[test]
+    fn test_detect_game_from_metadata() {
+        let tokens = empty_tokens();
+        let cases = [
+            (
+                "metadata={ date=1337.4.1 version=\"1.0.0\" }\n",
+                Some(SaveGame::Eu5),
+            ),
+            (
+                "meta_data={ version=\"1.5.9\" game_date=1887.1.1 }\n",
+                Some(SaveGame::Vic3),
+            ),
+            (
+                "meta_data={ version=\"1.12.5\" meta_player_name=\"William\" }\n",
+                Some(SaveGame::Ck3),
+            ),
+            (
+                "version=\"2.0.4\" date=450.10.1\n",
+                Some(SaveGame::Imperator),
+            ),
+            ("foo=bar\n", None),
+        ];
+
+        for (meta, expected) in cases {
+            let data = text_save(meta);
+            assert_eq!(
+                SaveGame::detect_from_metadata(&data, &tokens),
+                expected,
+                "{meta}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_reports_error_of_detected_game() {
+        let tokens = empty_tokens();
+
+        // Missing the version required of a Vic3 save
+        let data = text_save("meta_data={ game_date=1887.1.1 }\n");
+        let err = parse_any_save_data(&data, &tokens).unwrap_err();
+        assert!(matches!(err, ParseFileError::Vic3(_)), "{err}");
+
+        let data = text_save("foo=bar\n");
+        let err = parse_any_save_data(&data, &tokens).unwrap_err();
+        assert!(matches!(err, ParseFileError::UnknownGame), "{err}");
+    }
+}
@@ -0,0 +1,307 @@
+use crate::parser::{ParsedFile, SavePatch};
+use eu4game::achievements::weighted_factor;
+use serde::Serialize;
+use specta::Type;
+use std::collections::{BTreeMap, HashMap};
+
+/// A parsed save that is a candidate for the achievement leaderboards
+#[derive(Debug, Clone)]
+pub struct LeaderboardSave {
+    pub save_id: String,
+    pub file: ParsedFile,
+
+    /// Saves that are disqualified by a moderator are kept out of the
+    /// leaderboards, like the leaderboard_qualified column in the database
+    pub leaderboard_qualified: bool,
+}
+
+#[derive(Type, Debug, Serialize, Clone, PartialEq)]
+pub struct LeaderboardRank {
+    pub rank: usize,
+    pub save_id: String,
+    pub playthrough_id: String,
+    pub player_names: Vec<String>,
+    pub patch: SavePatch,
+
+    /// Days since the start date, unweighted
+    pub days: i32,
+
+    /// Days since the start date, weighted by patch
+    pub score_days: i32,
+}
+
+#[derive(Type, Debug, Serialize, Clone, PartialEq)]
+pub struct AchievementLeaderboard {
+    pub achievement_id: i32,
+    pub ranks: Vec<LeaderboardRank>,
+}
+
+#[derive(Type, Debug, Serialize, Clone, PartialEq)]
+pub struct PreviousRecord {
+    pub save_id: String,
+    pub score_days: i32,
+}
+
+/// A save that set a new best score for an achievement at the time it was
+/// uploaded
+#[derive(Type, Debug, Serialize, Clone, PartialEq)]
+pub struct RecordBroken {
+    pub achievement_id: i32,
+    pub save_id: String,
+    pub score_days: i32,
+
+    /// The record that was broken, or none if this was the first save with
+    /// the achievement
+    pub previous: Option<PreviousRecord>,
+}
+
+#[derive(Type, Debug, Serialize, Clone, PartialEq)]
+pub struct Leaderboards {
+    /// Leaderboards sorted by achievement id
+    pub boards: Vec<AchievementLeaderboard>,
+
+    /// Records broken in upload order
+    pub records: Vec<RecordBroken>,
+}
+
+/// Computes the achievement leaderboards with the current patch weights.
+///
+/// Saves are expected in upload order, which breaks ties between saves with
+/// the same score, earliest upload first.
+pub fn compute_leaderboards(saves: &[LeaderboardSave]) -> Leaderboards {
+    compute_leaderboards_with(saves, weighted_factor)
+}
+
+/// Computes the achievement leaderboards with custom patch weights so that
+/// the ranking impact of changing weights can be evaluated. Saves from a
+/// patch without a weight and saves that aren't leaderboard qualified are
+/// excluded.
+pub fn compute_leaderboards_with<F>(saves: &[LeaderboardSave], weight: F) -> Leaderboards
+where
+    F: Fn(u16, u16) -> Option<f64>,
+{
+    let scored = saves.iter().enumerate().filter_map(|(order, save)| {
+        if !save.leaderboard_qualified {
+            return None;
+        }
+
+        let patch = &save.file.patch;
+        let factor = weight(patch.first, patch.second)?;
+        let score_days = (f64::from(save.file.days) * factor).floor() as i32;
+        Some((order, save, score_days))
+    });
+
+    let mut records = Vec::new();
+    let mut best: HashMap<i32, (&str, i32)> = HashMap::new();
+
+    // The best scoring save of each playthrough, per achievement
+    let mut playthroughs: BTreeMap<i32, HashMap<&str, (usize, &LeaderboardSave, i32)>> =
+        BTreeMap::new();
+
+    for (order, save, score_days) in scored {
+        let achievements = save.file.achievements.as_deref().unwrap_or_default();
+        for &achievement_id in achievements {
+            let previous = best.get(&achievement_id).copied();
+            if previous.is_none_or(|(_, days)| score_days < days) {
+                best.insert(achievement_id, (save.save_id.as_str(), score_days));
+                records.push(RecordBroken {
+                    achievement_id,
+                    save_id: save.save_id.clone(),
+                    score_days,
+                    previous: previous.map(|(save_id, score_days)| PreviousRecord {
+                        save_id: String::from(save_id),
+                        score_days,
+                    }),
+                });
+            }
+
+            let board = playthroughs.entry(achievement_id).or_default();
+            let playthrough = save.file.playthrough_id.as_str();
+            let entry = board
+                .entry(playthrough)
+                .or_insert((order, save, score_days));
+            if score_days < entry.2 {
+                *entry = (order, save, score_days);
+            }
+        }
+    }
+
+    let boards = playthroughs
+        .into_iter()
+        .map(|(achievement_id, board)| {
+            let mut entries: Vec<_> = board.into_values().collect();
+            entries.sort_unstable_by_key(|(order, _, score_days)| (*score_days, *order));
+
+            let ranks = entries
+                .into_iter()
+                .enumerate()
+                .map(|(i, (_, save, score_days))| LeaderboardRank {
+                    rank: i + 1,
+                    save_id: save.save_id.clone(),
+                    playthrough_id: save.file.playthrough_id.clone(),
+                    player_names: save.file.player_names.clone(),
+                    patch: save.file.patch,
+                    days: save.file.days,
+                    score_days,
+                })
+                .collect();
+
+            AchievementLeaderboard {
+                achievement_id,
+                ranks,
+            }
+        })
+        .collect();
+
+    Leaderboards { boards, records }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eu4save::{Encoding, models::GameDifficulty};
+
+    fn save(
+        save_id: &str,
+        playthrough_id: &str,
+        minor: u16,
+        days: i32,
+        ids: &[i32],
+    ) -> LeaderboardSave {
+        LeaderboardSave {
+            save_id: String::from(save_id),
+            file: ParsedFile {
+                patch: SavePatch {
+                    first: 1,
+                    second: minor,
+                    third: 0,
+                    fourth: 0,
+                },
+                encoding: Encoding::BinaryZip,
+                playthrough_id: String::from(playthrough_id),
+                game_difficulty: GameDifficulty::Normal,
+                is_ironman: true,
+                is_multiplayer: false,
+                player_names: vec![String::from("player")],
+                player_tag: String::from("ENG"),
+                player_tag_name: String::from("England"),
+                player_start_tag: None,
+                player_start_tag_name: None,
+                date: String::new(),
+                days,
+                score_date: String::new(),
+                score_days: days,
+                achievements: Some(ids.to_vec()),
+                achievement_eligibility: Vec::new(),
+                patch_shorthand: format!("1.{}", minor),
+                hash: String::new(),
+            },
+            leaderboard_qualified: true,
+        }
+    }
+
+    fn flat(_major: u16, _minor: u16) -> Option<f64> {
+        Some(1.0)
+    }
+
+    #[test]
+    fn test_best_save_per_playthrough() {
+        let saves = [
+            save("a", "p1", 37, 1000, &[18]),
+            save("b", "p1", 37, 800, &[18]),
+            save("c", "p2", 37, 900, &[18]),
+        ];
+
+        let result = compute_leaderboards_with(&saves, flat);
+        assert_eq!(result.boards.len(), 1);
+        let ranks = &result.boards[0].ranks;
+        let ids: Vec<_> = ranks.iter().map(|x| x.save_id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c"]);
+        assert_eq!(ranks[0].rank, 1);
+        assert_eq!(ranks[1].rank, 2);
+    }
+
+    #[test]
+    fn test_ties_broken_by_upload_order() {
+        let saves = [
+            save("a", "p1", 37, 1000, &[18]),
+            save("b", "p2", 37, 1000, &[18]),
+        ];
+
+        let result = compute_leaderboards_with(&saves, flat);
+        let ids: Vec<_> = result.boards[0]
+            .ranks
+            .iter()
+            .map(|x| x.save_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
+
+        // A tie doesn't break the record
+        assert_eq!(result.records.len(), 1);
+    }
+
+    #[test]
+    fn test_records_broken() {
+        let saves = [
+            save("a", "p1", 37, 1000, &[18, 34]),
+            save("b", "p2", 37, 1200, &[18]),
+            save("c", "p3", 37, 900, &[18]),
+        ];
+
+        let result = compute_leaderboards_with(&saves, flat);
+        let records: Vec<_> = result
+            .records
+            .iter()
+            .map(|x| (x.achievement_id, x.save_id.as_str()))
+            .collect();
+        assert_eq!(records, vec![(18, "a"), (34, "a"), (18, "c")]);
+        assert_eq!(
+            result.records[2].previous,
+            Some(PreviousRecord {
+                save_id: String::from("a"),
+                score_days: 1000
+            })
+        );
+    }
+
+    #[test]
+    fn test_weights_change_rankings() {
+        let saves = [
+            save("old", "p1", 30, 1000, &[18]),
+            save("new", "p2", 37, 1050, &[18]),
+        ];
+
+        let result = compute_leaderboards_with(&saves, flat);
+        assert_eq!(result.boards[0].ranks[0].save_id, "old");
+
+        let penalize_old = |_major: u16, minor: u16| Some(if minor < 37 { 1.1 } else { 1.0 });
+        let result = compute_leaderboards_with(&saves, penalize_old);
+        assert_eq!(result.boards[0].ranks[0].save_id, "new");
+        assert_eq!(result.boards[0].ranks[1].score_days, 1100);
+    }
+
+    #[test]
+    fn test_unqualified_saves_excluded() {
+        let mut disqualified = save("a", "p1", 37, 800, &[18]);
+        disqualified.leaderboard_qualified = false;
+        let saves = [disqualified, save("b", "p2", 37, 1000, &[18])];
+
+        let result = compute_leaderboards_with(&saves, flat);
+        let ids: Vec<_> = result.boards[0]
+            .ranks
+            .iter()
+            .map(|x| x.save_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["b"]);
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].save_id, "b");
+    }
+
+    #[test]
+    fn test_unweighted_patches_excluded() {
+        let saves = [save("a", "p1", 28, 1000, &[18])];
+        let result = compute_leaderboards(&saves);
+        assert!(result.boards.is_empty());
+        assert!(result.records.is_empty());
+    }
+}
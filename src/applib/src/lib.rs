@@ -1,5 +1,10 @@
 use eu4save::PdsDate;
+pub mod eu5;
+pub mod games;
+pub mod leaderboard;
+pub mod melt;
 pub mod parser;
+pub mod transcode;
 
 pub fn eu4_days_to_date(days: i32) -> String {
     eu4save::eu4_start_date()
@@ -0,0 +1,148 @@
+use crate::{
+    games::{GameTokens, SaveGame, identify_save_game},
+    parser::ParseFileError,
+};
+use std::io::Cursor;
+
+#[derive(Debug)]
+pub struct MeltedSave {
+    pub game: SaveGame,
+    pub data: Vec<u8>,
+
+    /// Binary tokens that could not be resolved and were written out as
+    /// `__unknown_0x..` fields, sorted in ascending order
+    pub unknown_tokens: Vec<u16>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MeltError {
+    #[error("unable to decompress save: {0}")]
+    Decompress(#[from] std::io::Error),
+
+    #[error("{0}")]
+    Parse(#[from] ParseFileError),
+
+    #[error("unable to melt eu4 file: {0}")]
+    Eu4(#[from] eu4save::Eu4Error),
+
+    #[error("unable to melt eu5 file: {0}")]
+    Eu5(#[from] eu5save::Eu5Error),
+
+    #[error("unable to melt ck3 file: {0}")]
+    Ck3(#[from] ck3save::Ck3Error),
+
+    #[error("unable to melt hoi4 file: {0}")]
+    Hoi4(#[from] hoi4save::Hoi4Error),
+
+    #[error("unable to melt imperator file: {0}")]
+    Imperator(#[from] imperator_save::ImperatorError),
+
+    #[error("unable to melt vic3 file: {0}")]
+    Vic3(#[from] vic3save::Vic3Error),
+}
+
+/// Converts a binary save of any supported game into plaintext. Unknown
+/// tokens are reported rather than failing the melt.
+pub fn melt_save(data: &[u8], tokens: &GameTokens) -> Result<MeltedSave, MeltError> {
+    let inflated;
+    let data = if pdx_zstd::is_zstd_compressed(data) {
+        inflated = pdx_zstd::decode_all(data)?;
+        inflated.as_slice()
+    } else {
+        data
+    };
+
+    let game = identify_save_game(data, tokens)?;
+    let mut out = Cursor::new(Vec::new());
+    let unknown_tokens = match game {
+        SaveGame::Eu4 => {
+            use eu4save::{Eu4File, FailedResolveStrategy, MeltOptions};
+            let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Ignore);
+            let file = Eu4File::from_slice(data)?;
+            let resolver = eu4game::shared::embedded_resolver();
+            file.melt(options, &resolver, &mut out)?
+                .unknown_tokens()
+                .clone()
+        }
+        SaveGame::Eu5 => {
+            use eu5save::{
+                Eu5Error, Eu5ErrorKind, Eu5File, Eu5Melt, FailedResolveStrategy, MeltOptions,
+            };
+            let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Ignore);
+            let file = Eu5File::from_slice(data)
+                .map_err(Eu5ErrorKind::from)
+                .map_err(Eu5Error::from)?;
+            (&file)
+                .melt(options, &tokens.eu5, &mut out)?
+                .unknown_tokens()
+                .clone()
+        }
+        SaveGame::Ck3 => {
+            use ck3save::{Ck3Error, Ck3File, Ck3Melt, FailedResolveStrategy, MeltOptions};
+            let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Ignore);
+            let file = Ck3File::from_slice(data).map_err(Ck3Error::from)?;
+            (&file)
+                .melt(options, &tokens.ck3, &mut out)?
+                .unknown_tokens()
+                .clone()
+        }
+        SaveGame::Hoi4 => {
+            use hoi4save::{FailedResolveStrategy, Hoi4File, MeltOptions};
+            let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Ignore);
+            let file = Hoi4File::from_slice(data)?;
+            file.melt(options, &tokens.hoi4, &mut out)?
+                .unknown_tokens()
+                .clone()
+        }
+        SaveGame::Imperator => {
+            use imperator_save::{
+                FailedResolveStrategy, ImperatorError, ImperatorFile, ImperatorMelt, MeltOptions,
+            };
+            let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Ignore);
+            let file = ImperatorFile::from_slice(data).map_err(ImperatorError::from)?;
+            (&file)
+                .melt(options, &tokens.imperator, &mut out)?
+                .unknown_tokens()
+                .clone()
+        }
+        SaveGame::Vic3 => {
+            use vic3save::{FailedResolveStrategy, MeltOptions, Vic3Error, Vic3File, Vic3Melt};
+            let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Ignore);
+            let file = Vic3File::from_slice(data).map_err(Vic3Error::from)?;
+            (&file)
+                .melt(options, &tokens.vic3, &mut out)?
+                .unknown_tokens()
+                .clone()
+        }
+    };
+
+    let mut unknown_tokens = unknown_tokens.into_iter().collect::<Vec<_>>();
+    unknown_tokens.sort_unstable();
+
+    Ok(MeltedSave {
+        game,
+        data: out.into_inner(),
+        unknown_tokens,
+    })
+}
+
+/// Formats unknown tokens as a comma separated list of hex ids, suitable for
+/// a response header
+pub fn format_unknown_tokens(tokens: &[u16]) -> String {
+    tokens
+        .iter()
+        .map(|x| format!("0x{:x}", x))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_unknown_tokens() {
+        assert_eq!(format_unknown_tokens(&[]), "");
+        assert_eq!(format_unknown_tokens(&[0x1b, 0x337f]), "0x1b,0x337f");
+    }
+}
@@ -1,5 +1,9 @@
+use crate::{
+    eu5::Eu5ParsedFile,
+    games::{Ck3ParsedFile, Hoi4ParsedFile, ImperatorParsedFile, Vic3ParsedFile},
+};
 use eu4game::{
-    achievements::{AchievementHunter, WeightedScore},
+    achievements::{AchievementEligibility, AchievementHunter, EligibilityCheck, WeightedScore},
     shared::Eu4Parser,
 };
 use eu4save::{Encoding, PdsDate, eu4_start_date, models::GameDifficulty};
@@ -25,6 +29,19 @@ pub struct InvalidPatch {
 pub enum ParseResult {
     InvalidPatch(InvalidPatch),
     Parsed(Box<ParsedFile>),
+    Eu5(Box<Eu5ParsedFile>),
+    Ck3(Box<Ck3ParsedFile>),
+    Hoi4(Box<Hoi4ParsedFile>),
+    Imperator(Box<ImperatorParsedFile>),
+    Vic3(Box<Vic3ParsedFile>),
+}
+
+/// A requirement that a save must meet to be eligible for achievements
+#[derive(Type, Debug, Serialize, Clone)]
+pub struct AchievementGate {
+    pub check: EligibilityCheck,
+    pub passed: bool,
+    pub reason: String,
 }
 
 #[derive(Type, Debug, Serialize, Clone)]
@@ -45,6 +62,10 @@ pub struct ParsedFile {
     pub score_date: String,
     pub score_days: i32,
     pub achievements: Option<Vec<i32>>,
+
+    /// The gates checked to determine if the save is eligible for
+    /// achievements
+    pub achievement_eligibility: Vec<AchievementGate>,
     pub patch_shorthand: String,
 
     /// Highwayhash of save data
@@ -58,6 +79,24 @@ pub enum ParseFileError {
 
     #[error("unable to parse file: {0}")]
     Parse(#[from] eu4game::Eu4GameError),
+
+    #[error("unable to parse eu5 file: {0}")]
+    Eu5(#[from] eu5save::Eu5Error),
+
+    #[error("unable to parse ck3 file: {0}")]
+    Ck3(#[from] ck3save::Ck3Error),
+
+    #[error("unable to parse hoi4 file: {0}")]
+    Hoi4(#[from] hoi4save::Hoi4Error),
+
+    #[error("unable to parse imperator file: {0}")]
+    Imperator(#[from] imperator_save::ImperatorError),
+
+    #[error("unable to parse vic3 file: {0}")]
+    Vic3(#[from] vic3save::Vic3Error),
+
+    #[error("unable to detect the game of the save file")]
+    UnknownGame,
 }
 
 pub fn parse_save_data(data: &[u8]) -> Result<ParseResult, ParseFileError> {
@@ -99,6 +138,17 @@ pub fn parse_save_data(data: &[u8]) -> Result<ParseResult, ParseFileError> {
         .map(|x| save_game_query.localize_country(&x));
 
     let days = eu4_start_date().days_until(&meta.date);
+    let achievement_eligibility =
+        AchievementEligibility::check(encoding, &query, &game, &player_histories)
+            .gates
+            .into_iter()
+            .map(|x| AchievementGate {
+                check: x.check,
+                passed: x.passed,
+                reason: x.reason,
+            })
+            .collect();
+
     let achievements =
         AchievementHunter::create(encoding, &query, &game, &player_histories).map(|x| {
             x.achievements()
@@ -128,6 +178,7 @@ pub fn parse_save_data(data: &[u8]) -> Result<ParseResult, ParseFileError> {
         date: meta.date.iso_8601().to_string(),
         days,
         achievements,
+        achievement_eligibility,
         patch_shorthand,
         score_date: weighted_score.date,
         score_days: weighted_score.days,
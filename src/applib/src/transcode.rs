@@ -0,0 +1,245 @@
+use std::io::{Cursor, Read, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentType {
+    Zip,
+    Zstd,
+}
+
+impl ContentType {
+    pub fn mime(&self) -> &'static str {
+        match self {
+            ContentType::Zip => "application/zip",
+            ContentType::Zstd => "application/zstd",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Transcoded {
+    pub content_type: ContentType,
+    pub data: Vec<u8>,
+
+    /// False when the input was already zstd encoded and returned as is
+    pub reencoded: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TranscodeError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("zip error: {0}")]
+    Zip(#[from] rawzip::Error),
+
+    #[error("unsupported compression method for zip entry: {0}")]
+    UnsupportedCompression(String),
+}
+
+/// Re-encodes the data into a smaller, faster format.
+///
+/// - Remux ZIP archives with Zstd, preserving any data (like a save header)
+///   that precedes the archive. Every entry is kept, as games need more than
+///   the gamestate (eg: EU5 saves store a `string_lookup` entry).
+/// - Otherwise, return the data compressed as a Zstd stream.
+///
+/// Data that is already zstd encoded is returned unchanged.
+pub fn transcode(data: Vec<u8>) -> Result<Transcoded, TranscodeError> {
+    let locator = rawzip::ZipLocator::new().max_search_space(1024);
+    let zip = match locator.locate_in_slice(data) {
+        Ok(zip) => zip,
+        Err((data, _)) if pdx_zstd::is_zstd_compressed(&data) => {
+            return Ok(Transcoded {
+                content_type: ContentType::Zstd,
+                data,
+                reencoded: false,
+            });
+        }
+        Err((data, _)) => {
+            let out = Cursor::new(Vec::with_capacity(data.len() / 10));
+            let mut encoder = pdx_zstd::Encoder::new(out, 7)?;
+            encoder.write_all(&data)?;
+            return Ok(Transcoded {
+                content_type: ContentType::Zstd,
+                data: encoder.finish()?.into_inner(),
+                reencoded: true,
+            });
+        }
+    };
+
+    let mut is_encoded = true;
+    let mut compressed_size: u64 = 0;
+    let mut files = Vec::new();
+    let mut entries = zip.entries();
+    while let Some(entry) = entries.next_entry()? {
+        let file_path = entry.file_path();
+        compressed_size += entry.compressed_size_hint();
+        is_encoded &= entry.compression_method() == rawzip::CompressionMethod::ZSTD;
+        let file_name = String::from(file_path.try_normalize()?);
+        files.push((file_name, entry.compression_method(), entry.wayfinder()));
+    }
+
+    if is_encoded {
+        return Ok(Transcoded {
+            content_type: ContentType::Zip,
+            data: zip.get_ref().to_vec(),
+            reencoded: false,
+        });
+    }
+
+    let prelude = zip
+        .entries()
+        .filter_map(Result::ok)
+        .map(|x| x.local_header_offset())
+        .min()
+        .unwrap_or(zip.directory_offset());
+    let prelude = &zip.get_ref()[0..prelude as usize];
+
+    let out: Vec<u8> = Vec::with_capacity((compressed_size + prelude.len() as u64) as usize);
+    let mut writer = Cursor::new(out);
+    writer.write_all(prelude)?;
+    let mut out_zip = rawzip::ZipArchiveWriter::builder()
+        .with_offset(prelude.len() as u64)
+        .build(writer);
+
+    for (name, compression_method, wayfinder) in files {
+        let entry = zip.get_entry(wayfinder)?;
+        let reader: Box<dyn Read> = match compression_method {
+            rawzip::CompressionMethod::STORE => Box::new(entry.data()),
+            rawzip::CompressionMethod::DEFLATE => {
+                Box::new(flate2::read::DeflateDecoder::new(entry.data()))
+            }
+            method => {
+                return Err(TranscodeError::UnsupportedCompression(format!(
+                    "{:?}",
+                    method
+                )));
+            }
+        };
+
+        let (mut out_file, config) = out_zip
+            .new_file(&name)
+            .compression_method(rawzip::CompressionMethod::ZSTD)
+            .start()?;
+        let enc = pdx_zstd::Encoder::new(&mut out_file, 7)?;
+        let mut writer = config.wrap(enc);
+        let mut reader = entry.verifying_reader(reader);
+        std::io::copy(&mut reader, &mut writer)?;
+        let (zstd_writer, output) = writer.finish()?;
+        zstd_writer.finish()?;
+        out_file.finish(output)?;
+    }
+
+    Ok(Transcoded {
+        content_type: ContentType::Zip,
+        data: out_zip.finish()?.into_inner(),
+        reencoded: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored_zip(prelude: &[u8], files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = Cursor::new(Vec::new());
+        writer.write_all(prelude).unwrap();
+        let mut archive = rawzip::ZipArchiveWriter::builder()
+            .with_offset(prelude.len() as u64)
+            .build(writer);
+
+        for (name, data) in files {
+            let (mut out_file, config) = archive
+                .new_file(name)
+                .compression_method(rawzip::CompressionMethod::STORE)
+                .start()
+                .unwrap();
+            let mut writer = config.wrap(&mut out_file);
+            writer.write_all(data).unwrap();
+            let (_, output) = writer.finish().unwrap();
+            out_file.finish(output).unwrap();
+        }
+
+        archive.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_transcode_stored_zip() {
+        let prelude = b"SAV0103000000000000000000\n";
+        let data = stored_zip(
+            prelude,
+            &[("gamestate", b"date=1444.11.11"), ("extra", b"kept")],
+        );
+
+        let transcoded = transcode(data).unwrap();
+        assert!(transcoded.reencoded);
+        assert_eq!(transcoded.content_type, ContentType::Zip);
+        assert!(transcoded.data.starts_with(prelude));
+
+        let zip = rawzip::ZipArchive::from_slice(transcoded.data.as_slice()).unwrap();
+        let mut entries = zip.entries();
+        let entry = entries.next_entry().unwrap().unwrap();
+        assert_eq!(entry.file_path().as_ref(), b"gamestate");
+        assert_eq!(entry.compression_method(), rawzip::CompressionMethod::ZSTD);
+        let file = zip.get_entry(entry.wayfinder()).unwrap();
+        let actual = pdx_zstd::decode_all(file.data()).unwrap();
+        assert_eq!(actual.as_slice(), b"date=1444.11.11");
+
+        let entry = entries.next_entry().unwrap().unwrap();
+        assert_eq!(entry.file_path().as_ref(), b"extra");
+        let file = zip.get_entry(entry.wayfinder()).unwrap();
+        let actual = pdx_zstd::decode_all(file.data()).unwrap();
+        assert_eq!(actual.as_slice(), b"kept");
+        assert!(entries.next_entry().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_transcode_eu5_string_lookup() {
+        use eu5save::{Eu5File, JominiFileKind};
+
+        let prelude = format!("SAV0203{:08x}{:08x}\n", 0, 0);
+        let data = stored_zip(
+            prelude.as_bytes(),
+            &[
+                ("gamestate", b"metadata={ date=1337.4.1 }"),
+                ("string_lookup", b"\x01\x00\x03\x00abc"),
+            ],
+        );
+
+        let transcoded = transcode(data).unwrap();
+        assert!(transcoded.reencoded);
+
+        let file = Eu5File::from_slice(transcoded.data.as_slice()).unwrap();
+        assert_eq!(file.header().version(), 2);
+        let JominiFileKind::Zip(zip) = file.kind() else {
+            panic!("expected a zip save");
+        };
+
+        let mut lookup = Vec::new();
+        zip.read_entry("string_lookup")
+            .unwrap()
+            .read_to_end(&mut lookup)
+            .unwrap();
+        assert_eq!(lookup.as_slice(), b"\x01\x00\x03\x00abc");
+
+        let mut gamestate = Vec::new();
+        zip.read_entry("gamestate")
+            .unwrap()
+            .read_to_end(&mut gamestate)
+            .unwrap();
+        assert_eq!(gamestate.as_slice(), b"metadata={ date=1337.4.1 }");
+    }
+
+    #[test]
+    fn test_transcode_plaintext() {
+        let transcoded = transcode(b"EU4txt\ndate=1444.11.11".to_vec()).unwrap();
+        assert!(transcoded.reencoded);
+        assert_eq!(transcoded.content_type, ContentType::Zstd);
+        let actual = pdx_zstd::decode_all(&transcoded.data).unwrap();
+        assert_eq!(actual.as_slice(), b"EU4txt\ndate=1444.11.11");
+
+        let again = transcode(transcoded.data.clone()).unwrap();
+        assert!(!again.reencoded);
+        assert_eq!(again.data, transcoded.data);
+    }
+}
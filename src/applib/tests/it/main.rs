@@ -0,0 +1,66 @@
+use applib::eu5::{Eu5ParsedFile, parse_eu5_save_data};
+use eu4game::shared::SaveCheckSummer;
+use eu5save::BasicTokenResolver;
+use std::{io::Read, sync::LazyLock};
+
+mod utils;
+
+static TOKENS: LazyLock<BasicTokenResolver> = LazyLock::new(|| {
+    let file_data = std::fs::read("../eu5save/assets/eu5.txt").unwrap_or_default();
+    BasicTokenResolver::from_text_lines(file_data.as_slice()).unwrap()
+});
+
+fn read_save(filename: &str) -> Vec<u8> {
+    let mut data = Vec::new();
+    utils::request_file(filename)
+        .read_to_end(&mut data)
+        .unwrap();
+    data
+}
+
+fn assert_common(data: &[u8], parsed: &Eu5ParsedFile) {
+    assert!(!parsed.played_countries.is_empty());
+    for player in &parsed.played_countries {
+        assert!(!player.name.is_empty());
+        let tag = player.tag.as_deref().unwrap_or_default();
+        assert!(!tag.is_empty(), "unresolved tag for {}", player.name);
+    }
+
+    // The hash covers the entire file, not only the gamestate
+    let mut hasher = SaveCheckSummer::new(true);
+    hasher.append(data);
+    assert_eq!(Some(parsed.hash.as_str()), hasher.finish().as_deref());
+}
+
+#[test]
+fn test_parse_eu5_text_save() {
+    let data = read_save("debug-1.0.eu5");
+    let parsed = parse_eu5_save_data(&data, &TOKENS).unwrap();
+    assert_eq!(
+        parsed.playthrough_id,
+        "7f9ee4fa-1ab6-4c05-87f4-e11700302fdf"
+    );
+    assert!(!parsed.is_ironman);
+    assert_common(&data, &parsed);
+}
+
+#[test]
+fn test_parse_eu5_ironman_save() {
+    if TOKENS.is_empty() {
+        eprintln!("EU5 binary tokens not loaded, skipping ironman-1.0.eu5");
+        return;
+    }
+
+    let data = read_save("ironman-1.0.eu5");
+    let parsed = parse_eu5_save_data(&data, &TOKENS).unwrap();
+    assert_eq!(
+        parsed.playthrough_id,
+        "27a85fde-a436-485c-bf66-609af9fb64dc"
+    );
+    assert!(parsed.is_ironman);
+    assert_common(&data, &parsed);
+
+    let text = read_save("debug-1.0.eu5");
+    let text = parse_eu5_save_data(&text, &TOKENS).unwrap();
+    assert_ne!(parsed.hash, text.hash);
+}
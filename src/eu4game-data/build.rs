@@ -153,4 +153,33 @@ fn main() {
         let _ = writeln!(embedded_file, "}}");
     }
     let _ = writeln!(embedded_file, "}}");
+
+    // Checksums of unmodified installs of a patch, one per line. A patch
+    // without a checksums file has no known checksums.
+    let _ = writeln!(
+        embedded_file,
+        "pub fn known_checksums(minor_version: u16) -> &'static [&'static str] {{"
+    );
+    let _ = writeln!(embedded_file, "match minor_version {{");
+    for (major, minor) in &versions {
+        let version = format!("{}.{}", major, minor);
+        let p = Path::new("../../assets/game/eu4")
+            .join(&version)
+            .join("checksums.txt");
+        println!("cargo:rerun-if-changed={}", p.display());
+        let data = fs::read_to_string(&p).unwrap_or_default();
+        let checksums = data
+            .lines()
+            .map(str::trim)
+            .filter(|x| !x.is_empty() && !x.starts_with('#'))
+            .map(|x| format!("{:?}", x))
+            .collect::<Vec<_>>();
+
+        if !checksums.is_empty() {
+            let _ = writeln!(embedded_file, "{} => &[{}],", minor, checksums.join(", "));
+        }
+    }
+    let _ = writeln!(embedded_file, "_ => &[],");
+    let _ = writeln!(embedded_file, "}}");
+    let _ = writeln!(embedded_file, "}}");
 }
@@ -69,7 +69,7 @@ use crate::game::Game;
 pub use eu4game_data::{Achievement, Difficulty, achievements};
 use eu4save::{
     CountryTag, Encoding, Eu4Date, PdsDate, ProvinceId, eu4_start_date,
-    models::{Country, Eu4Save, Province, TaxManpowerModifier},
+    models::{Country, Eu4Save, GameDifficulty, Province, TaxManpowerModifier},
     query::{PlayerHistory, Query},
 };
 use serde::Serialize;
@@ -82,10 +82,35 @@ macro_rules! broken_invariant {
     };
 }
 
+/// Quantitative progress towards a condition, like the number of provinces
+/// in a region that are owned out of all the provinces in the region
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConditionProgress {
+    pub current: usize,
+    pub total: usize,
+
+    /// The provinces or tags that don't yet satisfy the condition
+    pub missing: Vec<String>,
+}
+
+impl ConditionProgress {
+    pub fn is_complete(&self) -> bool {
+        self.current >= self.total
+    }
+
+    /// Combine with the progress of another set of provinces or tags
+    pub fn merge(&mut self, other: ConditionProgress) {
+        self.current += other.current;
+        self.total += other.total;
+        self.missing.extend(other.missing);
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct AchievementCondition {
     completed: bool,
     description: String,
+    progress: Option<ConditionProgress>,
 }
 
 impl AchievementCondition {
@@ -93,6 +118,7 @@ impl AchievementCondition {
         AchievementCondition {
             completed,
             description: desc.into(),
+            progress: None,
         }
     }
 
@@ -100,6 +126,7 @@ impl AchievementCondition {
         AchievementCondition {
             completed: true,
             description: desc.into(),
+            progress: None,
         }
     }
 
@@ -107,8 +134,32 @@ impl AchievementCondition {
         AchievementCondition {
             completed: false,
             description: desc.into(),
+            progress: None,
+        }
+    }
+
+    /// A condition that is completed once all of the progress has been made.
+    /// The progress is appended to the description.
+    pub fn from_progress<V: Into<String>>(desc: V, progress: ConditionProgress) -> Self {
+        let desc = desc.into();
+        AchievementCondition {
+            completed: progress.is_complete(),
+            description: format!("{} ({}/{})", desc, progress.current, progress.total),
+            progress: Some(progress),
         }
     }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn progress(&self) -> Option<&ConditionProgress> {
+        self.progress.as_ref()
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -184,95 +235,239 @@ pub fn weighted_factor(major: u16, minor: u16) -> Option<f64> {
     }
 }
 
-fn owned_and_cored_by(prov: &Province, tag: CountryTag) -> bool {
-    let cored = prov.cores.contains(&tag);
-    let owned = prov.owner == Some(tag);
-    owned && cored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+pub enum EligibilityCheck {
+    Encoding,
+    Ironman,
+    Multiplayer,
+    Observer,
+    Checksum,
+    Console,
+    SingleHuman,
+    HistoricalDevelopment,
+    RandomNewWorld,
+    ProvinceCount,
+    FutureHistory,
+    StartingCountry,
 }
 
-impl<'a> AchievementHunter<'a> {
-    pub fn new(encoding: Encoding, query: &'a Query, game: &'a Game) -> Option<Self> {
-        let province_owners = query.province_owners();
-        let nation_events = query.nation_events(&province_owners);
-        let player_histories = query.player_histories(&nation_events);
-        AchievementHunter::create(encoding, query, game, &player_histories)
-    }
+#[derive(Debug, Clone, Serialize)]
+pub struct EligibilityGate {
+    pub check: EligibilityCheck,
+    pub passed: bool,
+    pub reason: String,
+}
 
-    pub fn create(
+/// Every gate a save must pass before achievements are detected, so that
+/// an ineligible save can explain why it is ineligible.
+#[derive(Debug, Clone, Serialize)]
+pub struct AchievementEligibility {
+    pub gates: Vec<EligibilityGate>,
+
+    /// Recorded so users can see it, but achievements are not restricted
+    /// by difficulty
+    pub difficulty: GameDifficulty,
+}
+
+impl AchievementEligibility {
+    pub fn check(
         encoding: Encoding,
-        query: &'a Query,
-        game: &'a Game,
+        query: &Query,
+        game: &Game,
         player_histories: &[PlayerHistory],
-    ) -> Option<Self> {
+    ) -> Self {
         let save = query.save();
-        let mut valid = !encoding.is_text()
-            && save.meta.is_ironman
-            && !save.meta.multiplayer
-            && save.meta.not_observer
-            && save.game.achievement_ok;
-
-        let mut current_humans: Vec<_> = query.countries().filter(|x| x.country.human).collect();
-
-        valid &= current_humans.len() == 1;
-        let patch = &query.save().meta.savegame_version;
-        valid &= query
-            .save()
-            .game
-            .gameplay_settings
-            .options
-            .tax_manpower_modifier
-            == TaxManpowerModifier::Historical;
+        let mut gates = Vec::new();
+        let mut gate = |check, passed, reason: String| {
+            gates.push(EligibilityGate {
+                check,
+                passed,
+                reason,
+            })
+        };
+
+        gate(
+            EligibilityCheck::Encoding,
+            !encoding.is_text(),
+            format!("save must not be plaintext (encoding: {:?})", encoding),
+        );
+
+        gate(
+            EligibilityCheck::Ironman,
+            save.meta.is_ironman,
+            String::from("ironman must be enabled"),
+        );
+
+        gate(
+            EligibilityCheck::Multiplayer,
+            !save.meta.multiplayer,
+            String::from("save must not be multiplayer"),
+        );
+
+        gate(
+            EligibilityCheck::Observer,
+            save.meta.not_observer,
+            String::from("player must not be in observer mode"),
+        );
 
-        valid &= !query.save().meta.is_random_new_world;
+        // The checksum changes when game files are modified. Patches without
+        // recorded checksums can't be verified, so they are let through.
+        let checksum = &save.meta.checksum;
+        let known_checksums = eu4game_data::known_checksums(save.meta.savegame_version.second);
+        if known_checksums.is_empty() {
+            gate(
+                EligibilityCheck::Checksum,
+                true,
+                format!(
+                    "checksum {} can't be verified, no checksums are known for this patch",
+                    checksum
+                ),
+            );
+        } else {
+            gate(
+                EligibilityCheck::Checksum,
+                known_checksums.contains(&checksum.as_str()),
+                format!(
+                    "checksum must match an unmodified game ({} found)",
+                    checksum
+                ),
+            );
+        }
+
+        // The game revokes achievements once the console is used. It is the
+        // only save flag that records console usage.
+        gate(
+            EligibilityCheck::Console,
+            save.game.achievement_ok,
+            String::from("console commands must not have been used"),
+        );
+
+        let humans = query.countries().filter(|x| x.country.human).count();
+        gate(
+            EligibilityCheck::SingleHuman,
+            humans == 1,
+            format!("exactly one human country ({} found)", humans),
+        );
+
+        let tax_manpower = &save.game.gameplay_settings.options.tax_manpower_modifier;
+        gate(
+            EligibilityCheck::HistoricalDevelopment,
+            *tax_manpower == TaxManpowerModifier::Historical,
+            format!(
+                "province development must be historical ({:?})",
+                tax_manpower
+            ),
+        );
+
+        gate(
+            EligibilityCheck::RandomNewWorld,
+            !save.meta.is_random_new_world,
+            String::from("random new world must be disabled"),
+        );
 
         // Protect against users "upgrading" a patch to a later version by comparing against the
         // known number of provinces in a standard game.
-        valid &= query.save().game.provinces.len() == game.total_provinces();
+        let provinces = save.game.provinces.len();
+        let expected_provinces = game.total_provinces();
+        gate(
+            EligibilityCheck::ProvinceCount,
+            provinces == expected_provinces,
+            format!(
+                "number of provinces must match the patch ({} found, {} expected)",
+                provinces, expected_provinces
+            ),
+        );
 
         // 1.30 introduced games that have history in the future on the start date. I suppose they
         //   should still be marked valid
         let eu4_start = eu4_start_date();
-        let date_gate = if query.save().meta.date == eu4_start {
+        let date_gate = if save.meta.date == eu4_start {
             eu4_start.add_days(1)
         } else {
-            query.save().meta.date
+            save.meta.date
         };
 
-        valid &= query
+        let country_dates = query
             .countries()
             .map(|x| x.country)
-            .flat_map(|x| x.history.events.iter().map(|(date, _event)| date))
-            .all(|x| x <= &date_gate);
-
-        valid &= query
-            .save()
+            .flat_map(|x| x.history.events.iter().map(|(date, _event)| date));
+        let war_dates = save
             .game
             .previous_wars
             .iter()
-            .flat_map(|x| x.history.events.iter().map(|(date, _event)| date))
-            .all(|x| x <= &date_gate);
+            .flat_map(|x| x.history.events.iter().map(|(date, _event)| date));
+        let future_history = country_dates.chain(war_dates).any(|x| x > &date_gate);
+        gate(
+            EligibilityCheck::FutureHistory,
+            !future_history,
+            format!(
+                "history must not be dated after the save date ({})",
+                save.meta.date.iso_8601()
+            ),
+        );
+
+        gate(
+            EligibilityCheck::StartingCountry,
+            query.starting_country(player_histories).is_some(),
+            String::from("player's starting country must be known"),
+        );
+
+        AchievementEligibility {
+            gates,
+            difficulty: save.game.gameplay_settings.options.difficulty,
+        }
+    }
+
+    pub fn is_eligible(&self) -> bool {
+        self.gates.iter().all(|x| x.passed)
+    }
+}
+
+fn owned_and_cored_by(prov: &Province, tag: CountryTag) -> bool {
+    let cored = prov.cores.contains(&tag);
+    let owned = prov.owner == Some(tag);
+    owned && cored
+}
+
+impl<'a> AchievementHunter<'a> {
+    pub fn new(encoding: Encoding, query: &'a Query, game: &'a Game) -> Option<Self> {
+        let province_owners = query.province_owners();
+        let nation_events = query.nation_events(&province_owners);
+        let player_histories = query.player_histories(&nation_events);
+        AchievementHunter::create(encoding, query, game, &player_histories)
+    }
 
-        if !valid {
+    pub fn create(
+        encoding: Encoding,
+        query: &'a Query,
+        game: &'a Game,
+        player_histories: &[PlayerHistory],
+    ) -> Option<Self> {
+        let eligibility = AchievementEligibility::check(encoding, query, game, player_histories);
+        if !eligibility.is_eligible() {
             return None;
         }
 
-        let human = current_humans.remove(0);
+        let save = query.save();
+        let patch = &save.meta.savegame_version;
+        let human = query.countries().find(|x| x.country.human)?;
+        let starting_country = query.starting_country(player_histories)?;
 
         let mut self_and_subjects: HashSet<_> = human.country.subjects.iter().cloned().collect();
         self_and_subjects.insert(human.tag);
 
-        query
-            .starting_country(player_histories)
-            .map(|starting_country| AchievementHunter {
-                query,
-                game,
-                patch: (patch.first, patch.second),
-                save,
-                tag: human.tag,
-                country: human.country,
-                starting_country,
-                self_and_subjects,
-            })
+        Some(AchievementHunter {
+            query,
+            game,
+            patch: (patch.first, patch.second),
+            save,
+            tag: human.tag,
+            country: human.country,
+            starting_country,
+            self_and_subjects,
+        })
     }
 
     fn normal_start_date(&self) -> AchievementCondition {
@@ -307,11 +502,6 @@ impl<'a> AchievementHunter<'a> {
         province.colony_size.is_some()
     }
 
-    fn owns_core_province_id(&self, id: ProvinceId) -> bool {
-        let province = self.save.game.provinces.get(&id);
-        province.is_some_and(|x| owned_and_cored_by(x, self.tag))
-    }
-
     fn owns_core_province_condition(&self, id: ProvinceId) -> AchievementCondition {
         let province = self.save.game.provinces.get(&id);
         let result = province.is_some_and(|prov| self.owns_core_province(prov));
@@ -327,11 +517,6 @@ impl<'a> AchievementHunter<'a> {
             .is_some_and(|x| self.self_and_subjects.contains(x))
     }
 
-    fn owns_or_non_sovereign_subject_of_id(&self, id: ProvinceId) -> bool {
-        let prov = self.save.game.provinces.get(&id);
-        prov.is_some_and(|x| self.owns_or_non_sovereign_subject_of_province(x))
-    }
-
     fn owns_or_non_sovereign_subject_of_id_condition(
         &self,
         id: ProvinceId,
@@ -350,51 +535,81 @@ impl<'a> AchievementHunter<'a> {
         AchievementCondition::new(result, desc)
     }
 
-    fn all_provs_in_area<T>(&self, area: &str, f: T) -> bool
+    fn all_provs_in_continent<T>(&self, continent: &str, f: T) -> bool
     where
-        T: Fn(&Province, ProvinceId) -> bool,
+        T: Fn(&Province) -> bool,
     {
-        match self.game.area_provinces(area) {
-            Some(mut prov_ids) => prov_ids.all(|prov_id| {
-                self.save
-                    .game
-                    .provinces
-                    .get(&prov_id)
-                    .is_some_and(|p| f(p, prov_id))
-            }),
+        match self.game.continent_provinces(continent) {
+            Some(mut prov_ids) => {
+                prov_ids.all(|prov_id| self.save.game.provinces.get(&prov_id).is_some_and(&f))
+            }
             None => {
-                broken_invariant!("{} area not recognized", area);
+                broken_invariant!("{} region not recognized", continent);
                 false
             }
         }
     }
 
-    fn all_provs_in_region<T>(&self, region: &str, f: T) -> bool
+    fn provinces_progress<T>(
+        &self,
+        prov_ids: impl Iterator<Item = ProvinceId>,
+        f: T,
+    ) -> ConditionProgress
     where
         T: Fn(&Province, ProvinceId) -> bool,
     {
-        match self.game.region_areas(region) {
-            Some(mut areas) => areas.all(|a| self.all_provs_in_area(a, &f)),
-            None => {
-                broken_invariant!("{} region not recognized", region);
-                false
+        let mut progress = ConditionProgress::default();
+        for prov_id in prov_ids {
+            progress.total += 1;
+            match self.save.game.provinces.get(&prov_id) {
+                Some(prov) if f(prov, prov_id) => progress.current += 1,
+                Some(prov) => progress
+                    .missing
+                    .push(format!("{} ({})", prov_id, prov.name)),
+                None => progress.missing.push(prov_id.to_string()),
             }
         }
+        progress
     }
 
-    fn all_provs_in_continent<T>(&self, continent: &str, f: T) -> bool
+    fn areas_progress<T>(&self, areas: &[&str], f: T) -> ConditionProgress
     where
-        T: Fn(&Province) -> bool,
+        T: Fn(&Province, ProvinceId) -> bool,
     {
-        match self.game.continent_provinces(continent) {
-            Some(mut prov_ids) => {
-                prov_ids.all(|prov_id| self.save.game.provinces.get(&prov_id).is_some_and(&f))
-            }
-            None => {
-                broken_invariant!("{} region not recognized", continent);
-                false
+        let mut progress = ConditionProgress::default();
+        for area in areas {
+            let Some(prov_ids) = self.game.area_provinces(area) else {
+                broken_invariant!("{} area not recognized", area);
+                progress.total += 1;
+                progress.missing.push(String::from(*area));
+                continue;
+            };
+
+            progress.merge(self.provinces_progress(prov_ids, &f));
+        }
+        progress
+    }
+
+    fn regions_progress<T>(&self, regions: &[&str], f: T) -> ConditionProgress
+    where
+        T: Fn(&Province, ProvinceId) -> bool,
+    {
+        let mut areas = Vec::new();
+        let mut unknown = Vec::new();
+        for region in regions {
+            match self.game.region_areas(region) {
+                Some(region_areas) => areas.extend(region_areas),
+                None => {
+                    broken_invariant!("{} region not recognized", region);
+                    unknown.push(String::from(*region));
+                }
             }
         }
+
+        let mut progress = self.areas_progress(&areas, f);
+        progress.total += unknown.len();
+        progress.missing.extend(unknown);
+        progress
     }
 
     fn is_wasteland_or_empty_province(&self, prov: &Province) -> bool {
@@ -574,30 +789,28 @@ impl<'a> AchievementHunter<'a> {
         let desc = "started as Kotte or Kandy";
         result.and(AchievementCondition::new(starter, desc));
 
-        let has_all_provinces = if result.completed() {
-            [
+        let desc = "India super region owned and cored by country and is buddhist";
+        let condition = if result.completed() {
+            let regions = [
                 "deccan_region",
                 "coromandel_region",
                 "bengal_region",
                 "hindusthan_region",
                 "west_india_region",
-            ]
-            .iter()
-            .all(|region| {
-                self.all_provs_in_region(region, |province, _| {
-                    province
-                        .religion
-                        .as_ref()
-                        .is_some_and(|religion| religion == "buddhism")
-                        && owned_and_cored_by(province, self.tag)
-                })
-            })
+            ];
+            let progress = self.regions_progress(&regions, |province, _| {
+                province
+                    .religion
+                    .as_ref()
+                    .is_some_and(|religion| religion == "buddhism")
+                    && owned_and_cored_by(province, self.tag)
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
 
-        let desc = "India super region owned and cored by country and is buddhist";
-        result.and(AchievementCondition::new(has_all_provinces, desc));
+        result.and(condition);
 
         result
     }
@@ -607,21 +820,27 @@ impl<'a> AchievementHunter<'a> {
         result.and(self.no_custom_nations());
         result.and(self.normal_start_date());
 
-        let same_religion = self
-            .country
-            .religion
-            .as_ref()
-            .is_some_and(|country_religion| {
-                self.save
-                    .game
-                    .provinces
-                    .values()
-                    .filter_map(|x| x.owner.and(x.religion.as_ref()))
-                    .all(|x| x == country_religion)
-            });
-
         let desc = "all controllable provinces are the same religion";
-        result.and(AchievementCondition::new(same_religion, desc));
+        let Some(country_religion) = self.country.religion.as_ref() else {
+            result.and(AchievementCondition::failed(desc));
+            return result;
+        };
+
+        let mut progress = ConditionProgress::default();
+        for (id, prov) in &self.save.game.provinces {
+            let Some(religion) = prov.owner.and(prov.religion.as_ref()) else {
+                continue;
+            };
+
+            progress.total += 1;
+            if religion == country_religion {
+                progress.current += 1;
+            } else {
+                progress.missing.push(format!("{} ({})", id, prov.name));
+            }
+        }
+
+        result.and(AchievementCondition::from_progress(desc, progress));
 
         result
     }
@@ -641,16 +860,22 @@ impl<'a> AchievementHunter<'a> {
             })
             .collect();
 
-        let owned = self
-            .save
-            .game
-            .provinces
-            .values()
-            .filter_map(|x| x.owner)
-            .all(|x| self_and_non_tributaries.contains(&x));
+        let mut progress = ConditionProgress::default();
+        let mut other_owners = HashSet::new();
+        for owner in self.save.game.provinces.values().filter_map(|x| x.owner) {
+            progress.total += 1;
+            if self_and_non_tributaries.contains(&owner) {
+                progress.current += 1;
+            } else {
+                other_owners.insert(owner);
+            }
+        }
+
+        progress.missing = other_owners.iter().map(|x| x.to_string()).collect();
+        progress.missing.sort_unstable();
 
         let desc = "only existing countries are self and non-tributary subjects";
-        result.and(AchievementCondition::new(owned, desc));
+        result.and(AchievementCondition::from_progress(desc, progress));
         result
     }
 
@@ -731,8 +956,9 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Byzantium";
         result.and(AchievementCondition::new(playing, desc));
 
-        let has_all_provinces = if result.completed() {
-            [
+        let desc = "owns and cored balkans and anatolia";
+        let condition = if result.completed() {
+            let areas = [
                 "morea_area",
                 "northern_greece_area",
                 "albania_area",
@@ -748,17 +974,15 @@ impl<'a> AchievementHunter<'a> {
                 "rum_area",
                 "cukurova_area",
                 "dulkadir_area",
-            ]
-            .iter()
-            .all(|area| self.all_provs_in_area(area, |prov, _| owned_and_cored_by(prov, self.tag)))
+            ];
+            let progress =
+                self.areas_progress(&areas, |prov, _| owned_and_cored_by(prov, self.tag));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
 
-        result.and(AchievementCondition::new(
-            has_all_provinces,
-            "owns and cored balkans and anatolia",
-        ));
+        result.and(condition);
         result
     }
 
@@ -776,23 +1000,22 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Hungary";
         result.and(AchievementCondition::new(playing, desc));
 
-        let has_all_provinces = if result.completed() {
-            [
+        let desc = "owns and cored austria";
+        let condition = if result.completed() {
+            let areas = [
                 "inner_austria_area",
                 "austria_proper_area",
                 "carinthia_area",
                 "tirol_area",
-            ]
-            .iter()
-            .all(|area| self.all_provs_in_area(area, |prov, _| owned_and_cored_by(prov, self.tag)))
+            ];
+            let progress =
+                self.areas_progress(&areas, |prov, _| owned_and_cored_by(prov, self.tag));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
 
-        result.and(AchievementCondition::new(
-            has_all_provinces,
-            "owns and cored austria",
-        ));
+        result.and(condition);
         result
     }
 
@@ -963,16 +1186,17 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Nevers";
         result.and(AchievementCondition::new(playing, desc));
 
-        let has_provinces = if result.completed() {
-            self.all_provs_in_region("france_region", |province, _| {
+        let desc = "owns and cored France region";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["france_region"], |province, _| {
                 owned_and_cored_by(province, self.tag)
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
 
-        let desc = "owns and cored France region";
-        result.and(AchievementCondition::new(has_provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -1016,24 +1240,21 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Teutonic Order or Livonian Order";
         result.and(AchievementCondition::new(playing, desc));
 
-        let has_all_provinces = if result.completed() {
-            ["russia_region", "ural_region", "crimea_region"]
-                .iter()
-                .all(|x| {
-                    self.all_provs_in_region(x, |province, _| {
-                        province
-                            .religion
-                            .as_ref()
-                            .is_some_and(|religion| religion == "catholic")
-                            && owned_and_cored_by(province, self.tag)
-                    })
-                })
+        let desc = "Own and core all provinces in the Pontic Steppe, Russia, and Ural regions and conver them to catholicism";
+        let condition = if result.completed() {
+            let regions = ["russia_region", "ural_region", "crimea_region"];
+            let progress = self.regions_progress(&regions, |province, _| {
+                province
+                    .religion
+                    .as_ref()
+                    .is_some_and(|religion| religion == "catholic")
+                    && owned_and_cored_by(province, self.tag)
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Own and core all provinces in the Pontic Steppe, Russia, and Ural regions and conver them to catholicism";
-        result.and(AchievementCondition::new(has_all_provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -1102,16 +1323,16 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Serbia";
         result.and(AchievementCondition::new(playing, desc));
 
-        let has_provinces = if result.completed() {
-            self.all_provs_in_region("balkan_region", |province, _| {
+        let desc = "Own and core all provinces in the balkans";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["balkan_region"], |province, _| {
                 owned_and_cored_by(province, self.tag)
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Own and core all provinces in the balkans";
-        result.and(AchievementCondition::new(has_provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -1133,16 +1354,16 @@ impl<'a> AchievementHunter<'a> {
             });
         result.and(AchievementCondition::new(irish, desc));
 
-        let has_provinces = if result.completed() {
-            self.all_provs_in_region("british_isles_region", |province, _| {
+        let desc = "owns and cored british_isles";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["british_isles_region"], |province, _| {
                 owned_and_cored_by(province, self.tag)
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "owns and cored british_isles";
-        result.and(AchievementCondition::new(has_provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -1383,15 +1604,16 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Lippe";
         result.and(AchievementCondition::new(starter, desc));
 
-        let has_provs = if result.completed() {
-            self.all_provs_in_region("british_isles_region", |province, _| {
+        let desc = "owns british isles";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["british_isles_region"], |province, _| {
                 province.owner == Some(self.tag)
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        result.and(AchievementCondition::new(has_provs, "owns british isles"));
+        result.and(condition);
         result
     }
 
@@ -1626,25 +1848,27 @@ impl<'a> AchievementHunter<'a> {
             "year is before 1550",
         ));
 
-        let owns_india = if result.completed() {
+        let desc = "conquered india";
+        let condition = if result.completed() {
             match self.game.superregion_regions("india_superregion") {
-                Some(mut regions) => regions.all(|region| {
-                    self.all_provs_in_region(region, |prov, _| {
+                Some(regions) => {
+                    let regions: Vec<_> = regions.collect();
+                    let progress = self.regions_progress(&regions, |prov, _| {
                         prov.owner
                             .as_ref()
                             .is_some_and(|x| self.self_and_subjects.contains(x))
-                    })
-                }),
+                    });
+                    AchievementCondition::from_progress(desc, progress)
+                }
                 None => {
                     broken_invariant!("india superregion not recognized");
-                    false
+                    AchievementCondition::failed(desc)
                 }
             }
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        result.and(AchievementCondition::new(owns_india, "conquered india"));
+        result.and(condition);
         result
     }
 
@@ -1719,26 +1943,22 @@ impl<'a> AchievementHunter<'a> {
             "has an empire government",
         ));
 
-        let has_all_provinces = if result.completed() {
-            [
+        let desc = "owns Hungary as Avaria";
+        let condition = if result.completed() {
+            let areas = [
                 "alfold_area",
                 "transdanubia_area",
                 "slovakia_area",
                 "transylvania_area",
                 "southern_transylvania_area",
-            ]
-            .iter()
-            .all(|area| {
-                self.all_provs_in_area(area, |prov, _| prov.owner == Some(self.starting_country))
-            })
+            ];
+            let progress =
+                self.areas_progress(&areas, |prov, _| prov.owner == Some(self.starting_country));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        result.and(AchievementCondition::new(
-            has_all_provinces,
-            "owns Hungary as Avaria",
-        ));
+        result.and(condition);
         result
     }
 
@@ -1752,22 +1972,19 @@ impl<'a> AchievementHunter<'a> {
         let desc = "started as Albania";
         result.and(AchievementCondition::new(starter, desc));
 
-        let has_all_provinces = if result.completed() {
-            ["iberia_region", "caucasia_region"].iter().all(|region| {
-                self.all_provs_in_region(region, |prov, _| {
-                    prov.owner
-                        .as_ref()
-                        .is_some_and(|x| self.self_and_subjects.contains(x))
-                })
-            })
+        let desc = "owns Iberia and the Caucasus";
+        let condition = if result.completed() {
+            let regions = ["iberia_region", "caucasia_region"];
+            let progress = self.regions_progress(&regions, |prov, _| {
+                prov.owner
+                    .as_ref()
+                    .is_some_and(|x| self.self_and_subjects.contains(x))
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        result.and(AchievementCondition::new(
-            has_all_provinces,
-            "owns Iberia and the Caucasus",
-        ));
+        result.and(condition);
         result
     }
 
@@ -1923,33 +2140,20 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Persia";
         result.and(AchievementCondition::new(playing, desc));
 
-        let has_all_provinces = if result.completed() {
-            ["anatolia_region", "egypt_region"].iter().all(|region| {
-                self.all_provs_in_region(region, |province, _| {
-                    (province.owner == Some(self.tag)) && province.cores.contains(&self.tag)
-                })
-            })
-        } else {
-            false
-        };
-
-        let has_all_provs_in_area = if result.completed() {
-            ["morea_area", "northern_greece_area", "macedonia_area"]
-                .iter()
-                .all(|region| {
-                    self.all_provs_in_area(region, |province, _| {
-                        (province.owner == Some(self.tag)) && province.cores.contains(&self.tag)
-                    })
-                })
+        let desc = "owns and cored the persian conquests";
+        let condition = if result.completed() {
+            let owned_and_cored = |province: &Province, _: ProvinceId| {
+                (province.owner == Some(self.tag)) && province.cores.contains(&self.tag)
+            };
+            let regions = ["anatolia_region", "egypt_region"];
+            let areas = ["morea_area", "northern_greece_area", "macedonia_area"];
+            let mut progress = self.regions_progress(&regions, owned_and_cored);
+            progress.merge(self.areas_progress(&areas, owned_and_cored));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "owns and cored the persian conquests";
-        result.and(AchievementCondition::new(
-            has_all_provinces && has_all_provs_in_area,
-            desc,
-        ));
+        result.and(condition);
 
         result
     }
@@ -2029,16 +2233,16 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Riga";
         result.and(AchievementCondition::new(playing, desc));
 
-        let owns_baltic = if result.completed() {
-            self.all_provs_in_region("baltic_region", |p, _| {
+        let desc = "Riga or a non-sovereign subject owns baltic region";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["baltic_region"], |p, _| {
                 self.owns_or_non_sovereign_subject_of_province(p)
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Riga or a non-sovereign subject owns baltic region";
-        result.and(AchievementCondition::new(owns_baltic, desc));
+        result.and(condition);
 
         result
     }
@@ -2066,15 +2270,16 @@ impl<'a> AchievementHunter<'a> {
             });
         result.and(AchievementCondition::new(romanian, desc));
 
-        let owns_balkan = if result.completed() {
-            self.all_provs_in_region("balkan_region", |p, _| {
+        let desc = "owns balkans";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["balkan_region"], |p, _| {
                 self.owns_or_non_sovereign_subject_of_province(p)
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        result.and(AchievementCondition::new(owns_balkan, "owns balkans"));
+        result.and(condition);
         result
     }
 
@@ -2136,29 +2341,30 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently a Malagasy country";
         result.and(AchievementCondition::new(starter, desc));
 
-        let provinces = if result.completed() {
-            [
+        let desc = "owns penguin lands";
+        let condition = if result.completed() {
+            let ids = [
                 1177, 1179, 833, 1180, 1084, 2727, 2736, 1086, 2735, 1087, 2734, 4869, 4868, 1085,
                 4858, 1246, 1109, 783, 782, 2869, 1095, 2025,
-            ]
-            .iter()
-            .all(|id| self.owns_or_non_sovereign_subject_of_id(ProvinceId::new(*id)))
-                && [
-                    "madagascar_highlands_area",
-                    "betsimasaraka_area",
-                    "sakalava_area",
-                    "southern_madagascar",
-                ]
-                .iter()
-                .all(|x| {
-                    self.all_provs_in_area(x, |province, _| owned_and_cored_by(province, self.tag))
-                })
+            ];
+            let mut progress = self
+                .provinces_progress(ids.into_iter().map(ProvinceId::new), |p, _| {
+                    self.owns_or_non_sovereign_subject_of_province(p)
+                });
+            let areas = [
+                "madagascar_highlands_area",
+                "betsimasaraka_area",
+                "sakalava_area",
+                "southern_madagascar",
+            ];
+            progress.merge(
+                self.areas_progress(&areas, |province, _| owned_and_cored_by(province, self.tag)),
+            );
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "owns penguin lands";
-        result.and(AchievementCondition::new(provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -2190,22 +2396,24 @@ impl<'a> AchievementHunter<'a> {
         let desc = "started as The Knights";
         result.and(AchievementCondition::new(starter, desc));
 
-        let provinces = if result.completed() {
-            [
+        let desc = "owns the Carribean and Mediteranean islands";
+        let condition = if result.completed() {
+            let ids = [
                 320, 321, 163, 164, 2348, 3003, 4700, 4698, 142, 2982, 124, 125, 4737, 4736, 2954,
                 126, 127, 1247, 4559, 4560, 333, 112, 4735, 2986,
-            ]
-            .iter()
-            .all(|id| self.owns_core_province_id(ProvinceId::from(*id)))
-                && self.all_provs_in_region("carribeans_region", |prov, _| {
-                    prov.owner == Some(self.tag)
-                })
+            ];
+            let mut progress = self
+                .provinces_progress(ids.into_iter().map(ProvinceId::from), |p, _| {
+                    owned_and_cored_by(p, self.tag)
+                });
+            progress.merge(self.regions_progress(&["carribeans_region"], |prov, _| {
+                prov.owner == Some(self.tag)
+            }));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "owns the Carribean and Mediteranean islands";
-        result.and(AchievementCondition::new(provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -2243,14 +2451,15 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently an Australian tribe";
         result.and(AchievementCondition::new(playing, desc));
 
-        let provinces = if result.completed() {
-            self.all_provs_in_region("australia_region", |p, _| p.owner == Some(self.tag))
+        let desc = "owns the Australia region";
+        let condition = if result.completed() {
+            let progress =
+                self.regions_progress(&["australia_region"], |p, _| p.owner == Some(self.tag));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "owns the Australia region";
-        result.and(AchievementCondition::new(provinces, desc));
+        result.and(condition);
 
         let desc = "subjugated Hungary";
         let hungary = "HUN".parse::<CountryTag>().unwrap();
@@ -2287,16 +2496,16 @@ impl<'a> AchievementHunter<'a> {
         let desc = "Jewish Community Aspect active";
         result.and(AchievementCondition::new(aspect_active, desc));
 
-        let province_religions = if result.completed() {
-            self.all_provs_in_region("egypt_region", |p, _| {
+        let desc = "Convert all of Egypt to Jewish";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["egypt_region"], |p, _| {
                 p.religion.as_ref().is_some_and(|x| x == "jewish")
-            })
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Convert all of Egypt to Jewish";
-        result.and(AchievementCondition::new(province_religions, desc));
+        result.and(condition);
 
         result
     }
@@ -2341,20 +2550,17 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Kilwa";
         result.and(AchievementCondition::new(playing, desc));
 
-        let province_religions = if result.completed() {
-            ["moluccas_region", "indonesia_region"]
-                .iter()
-                .all(|region| {
-                    self.all_provs_in_region(region, |p, _| {
-                        p.religion.as_ref() == self.country.religion.as_ref()
-                    })
-                })
+        let desc = "The Moluccas and Indonesia are Kilwa's religion";
+        let condition = if result.completed() {
+            let regions = ["moluccas_region", "indonesia_region"];
+            let progress = self.regions_progress(&regions, |p, _| {
+                p.religion.as_ref() == self.country.religion.as_ref()
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "The Moluccas and Indonesia are Kilwa's religion";
-        result.and(AchievementCondition::new(province_religions, desc));
+        result.and(condition);
 
         result
     }
@@ -2451,14 +2657,15 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Andalusia";
         result.and(AchievementCondition::new(playing, desc));
 
-        let owns_baltic = if result.completed() {
-            self.all_provs_in_region("iberia_region", |p, _| owned_and_cored_by(p, adu))
+        let desc = "Andalusia own and core all provinces of Iberia";
+        let condition = if result.completed() {
+            let progress =
+                self.regions_progress(&["iberia_region"], |p, _| owned_and_cored_by(p, adu));
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Andalusia own and core all provinces of Iberia";
-        result.and(AchievementCondition::new(owns_baltic, desc));
+        result.and(condition);
 
         result
     }
@@ -2482,14 +2689,16 @@ impl<'a> AchievementHunter<'a> {
         let desc = "enact the Unified Kalmar Monarchy government reform";
         result.and(AchievementCondition::new(reform, desc));
 
-        let territory = if result.completed() {
-            self.all_provs_in_region("scandinavia_region", |p, _| owned_and_cored_by(p, denmark))
+        let desc = "Own and core all of Scandinavia";
+        let condition = if result.completed() {
+            let progress = self.regions_progress(&["scandinavia_region"], |p, _| {
+                owned_and_cored_by(p, denmark)
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Own and core all of Scandinavia";
-        result.and(AchievementCondition::new(territory, desc));
+        result.and(condition);
 
         result
     }
@@ -2562,28 +2771,27 @@ impl<'a> AchievementHunter<'a> {
         let desc = "currently Livonia";
         result.and(AchievementCondition::new(current, desc));
 
-        let territory = if result.completed() {
-            self.all_provs_in_area("east_prussia_area", |p, _| owned_and_cored_by(p, self.tag))
-                && self
-                    .all_provs_in_area("west_prussia_area", |p, _| owned_and_cored_by(p, self.tag))
-                && self.all_provs_in_area("silesia_area", |p, _| owned_and_cored_by(p, self.tag))
-                && self.all_provs_in_region("north_german_region", |p, id| {
+        let desc = "own the territory of the North German Confederation as core provinces";
+        let condition = if result.completed() {
+            let areas = ["east_prussia_area", "west_prussia_area", "silesia_area"];
+            let mut progress = self.areas_progress(&areas, |p, _| owned_and_cored_by(p, self.tag));
+            progress.merge(self.regions_progress(&["north_german_region"], |p, id| {
+                owned_and_cored_by(p, self.tag)
+                    || self.game.province_area(&id).is_some_and(|a| {
+                        matches!(a, "bohemia_area" | "moravia_area" | "erzgebirge_area")
+                    })
+            }));
+            let ids = [1859, 4523, 4526, 254, 2963, 1931];
+            progress.merge(
+                self.provinces_progress(ids.into_iter().map(ProvinceId::from), |p, _| {
                     owned_and_cored_by(p, self.tag)
-                        || self.game.province_area(&id).is_some_and(|a| {
-                            matches!(a, "bohemia_area" | "moravia_area" | "erzgebirge_area")
-                        })
-                })
-                && self.owns_core_province_id(ProvinceId::from(1859))
-                && self.owns_core_province_id(ProvinceId::from(4523))
-                && self.owns_core_province_id(ProvinceId::from(4526))
-                && self.owns_core_province_id(ProvinceId::from(254))
-                && self.owns_core_province_id(ProvinceId::from(2963))
-                && self.owns_core_province_id(ProvinceId::from(1931))
+                }),
+            );
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-        let desc = "own the territory of the North German Confederation as core provinces";
-        result.and(AchievementCondition::new(territory, desc));
+        result.and(condition);
 
         result
     }
@@ -2690,33 +2898,27 @@ impl<'a> AchievementHunter<'a> {
             .chain(std::iter::once(self.tag))
             .collect();
 
-        let has_all_provinces = if result.completed()
-            && self.save.meta.savegame_version.second == 35
-        {
-            [343, 341, 361, 236, 245, 96, 134].iter().all(|&id| {
-                self.save
-                    .game
-                    .provinces
-                    .get(&ProvinceId::new(id))
-                    .and_then(|prov| prov.owner.as_ref())
-                    .is_some_and(|owner| self_core_eyalets.contains(owner))
-            }) && [
+        let desc = "Has all provinces to form Roman Empire";
+        let owned_by_eyalets = |province: &Province, _: ProvinceId| {
+            province
+                .owner
+                .as_ref()
+                .is_some_and(|o| self_core_eyalets.contains(o))
+        };
+        let condition = if result.completed() && self.save.meta.savegame_version.second == 35 {
+            let ids = [343, 341, 361, 236, 245, 96, 134];
+            let mut progress =
+                self.provinces_progress(ids.into_iter().map(ProvinceId::new), owned_by_eyalets);
+            let regions = [
                 "italy_region",
                 "france_region",
                 "iberia_region",
                 "balkan_region",
                 "anatolia_region",
                 "mashriq_region",
-            ]
-            .iter()
-            .all(|region| {
-                self.all_provs_in_region(region, |province, _| {
-                    province
-                        .owner
-                        .as_ref()
-                        .is_some_and(|o| self_core_eyalets.contains(o))
-                })
-            })
+            ];
+            progress.merge(self.regions_progress(&regions, owned_by_eyalets));
+            AchievementCondition::from_progress(desc, progress)
         } else if result.completed() && self.save.meta.savegame_version.second > 35 {
             // Hard coded list derived from BYZ_roman_empire_decision_trigger
             // in 02_scripted_triggers_missions.txt
@@ -2790,13 +2992,19 @@ impl<'a> AchievementHunter<'a> {
                 .filter_map(|p| p.owner)
                 .filter(|owner| self_core_eyalets.contains(owner))
                 .count();
-            owned_provs >= 425
+
+            // The decision only requires a number of the provinces to be
+            // owned, so there is no single province that is missing
+            let progress = ConditionProgress {
+                current: owned_provs,
+                total: 425,
+                missing: Vec::new(),
+            };
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Has all provinces to form Roman Empire";
-        result.and(AchievementCondition::new(has_all_provinces, desc));
+        result.and(condition);
 
         result
     }
@@ -2810,18 +3018,17 @@ impl<'a> AchievementHunter<'a> {
         let desc = "started as Mysore";
         result.and(AchievementCondition::new(starter, desc));
 
-        let has_all_provinces = if result.completed() {
-            ["deccan_region", "coromandel_region"].iter().all(|region| {
-                self.all_provs_in_region(region, |prov, _| {
-                    self.owns_or_non_sovereign_subject_of_province(prov)
-                })
-            })
+        let desc = "Mysore owns or has subject owns correct provinces";
+        let condition = if result.completed() {
+            let regions = ["deccan_region", "coromandel_region"];
+            let progress = self.regions_progress(&regions, |prov, _| {
+                self.owns_or_non_sovereign_subject_of_province(prov)
+            });
+            AchievementCondition::from_progress(desc, progress)
         } else {
-            false
+            AchievementCondition::failed(desc)
         };
-
-        let desc = "Mysore owns or has subject owns correct provinces";
-        result.and(AchievementCondition::new(has_all_provinces, desc));
+        result.and(condition);
         result
     }
 
@@ -2898,3 +3105,57 @@ impl<'a> AchievementHunter<'a> {
         result
     }*/
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_achievement_result_completed() {
+        let mut result = AchievementResult::new(1);
+        assert!(result.completed());
+
+        result.and(AchievementCondition::completed("a"));
+        assert!(result.completed());
+
+        result.and(AchievementCondition::failed("b"));
+        assert!(!result.completed());
+    }
+
+    #[test]
+    fn test_condition_from_progress() {
+        let progress = ConditionProgress {
+            current: 2,
+            total: 3,
+            missing: vec![String::from("c")],
+        };
+        let cond = AchievementCondition::from_progress("own provinces", progress);
+        assert!(!cond.is_completed());
+        assert_eq!(cond.description(), "own provinces (2/3)");
+        assert_eq!(cond.progress().unwrap().missing, vec![String::from("c")]);
+
+        let progress = ConditionProgress {
+            current: 3,
+            total: 3,
+            missing: Vec::new(),
+        };
+        assert!(AchievementCondition::from_progress("own provinces", progress).is_completed());
+    }
+
+    #[test]
+    fn test_merge_progress() {
+        let mut progress = ConditionProgress {
+            current: 2,
+            total: 3,
+            missing: vec![String::from("c")],
+        };
+        progress.merge(ConditionProgress {
+            current: 1,
+            total: 2,
+            missing: vec![String::from("e")],
+        });
+        assert_eq!(progress.current, 3);
+        assert_eq!(progress.total, 5);
+        assert_eq!(progress.missing, vec![String::from("c"), String::from("e")]);
+    }
+}
@@ -0,0 +1,282 @@
+/*!
+Compare two saves of the same playthrough.
+
+The diff is computed from the state recorded in each save rather than by
+replaying history, so it describes where each country stood on the two dates
+and the notable events (wars and rulers) that happened in between.
+*/
+use crate::{Eu4GameError, shared::playthrough_id};
+use eu4save::{
+    CountryTag, Eu4Date, ProvinceId,
+    models::{Country, CountryEvent},
+    query::Query,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// A value as seen in the earlier and the later save
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta<T> {
+    pub before: T,
+    pub after: T,
+}
+
+impl Delta<f32> {
+    pub fn change(&self) -> f32 {
+        self.after - self.before
+    }
+}
+
+impl Delta<u8> {
+    pub fn change(&self) -> i32 {
+        i32::from(self.after) - i32::from(self.before)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdeaGroupDiff {
+    pub name: String,
+    pub ideas: Delta<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RulerChange {
+    pub date: Eu4Date,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountryDiff {
+    pub tag: CountryTag,
+    pub development: Delta<f32>,
+    pub provinces_gained: Vec<ProvinceId>,
+    pub provinces_lost: Vec<ProvinceId>,
+
+    /// Monthly income from taxation, production, trade, and gold
+    pub income: Delta<f32>,
+    pub manpower: Delta<f32>,
+    pub adm_tech: Delta<u8>,
+    pub dip_tech: Delta<u8>,
+    pub mil_tech: Delta<u8>,
+
+    /// Idea groups with a different number of ideas between the saves.
+    /// Groups that were picked or dropped are reported with zero ideas on
+    /// the side that lacks them.
+    pub ideas: Vec<IdeaGroupDiff>,
+    pub wars_started: Vec<String>,
+    pub wars_ended: Vec<String>,
+    pub rulers: Vec<RulerChange>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveDiff {
+    pub before: Eu4Date,
+    pub after: Eu4Date,
+
+    /// Countries that owned land in either save, sorted by tag
+    pub countries: Vec<CountryDiff>,
+}
+
+/// Compares two saves of the same playthrough. The saves may be given in any
+/// order, the earlier save is always treated as the "before" save.
+pub fn diff_saves(a: &Query, b: &Query) -> Result<SaveDiff, Eu4GameError> {
+    if playthrough_id(a) != playthrough_id(b) {
+        return Err(Eu4GameError::PlaythroughMismatch);
+    }
+
+    let (before, after) = if a.save().meta.date <= b.save().meta.date {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    Ok(SaveDiffer::new(before, after).diff())
+}
+
+struct SaveDiffer<'a> {
+    before: &'a Query,
+    after: &'a Query,
+    gained: HashMap<CountryTag, Vec<ProvinceId>>,
+    lost: HashMap<CountryTag, Vec<ProvinceId>>,
+}
+
+impl<'a> SaveDiffer<'a> {
+    fn new(before: &'a Query, after: &'a Query) -> Self {
+        let mut gained: HashMap<_, Vec<_>> = HashMap::new();
+        let mut lost: HashMap<_, Vec<_>> = HashMap::new();
+        let before_provinces = &before.save().game.provinces;
+        for (id, prov) in &after.save().game.provinces {
+            let old_owner = before_provinces.get(id).and_then(|x| x.owner);
+            if old_owner == prov.owner {
+                continue;
+            }
+
+            if let Some(owner) = prov.owner {
+                gained.entry(owner).or_default().push(*id);
+            }
+
+            if let Some(owner) = old_owner {
+                lost.entry(owner).or_default().push(*id);
+            }
+        }
+
+        for ids in gained.values_mut().chain(lost.values_mut()) {
+            ids.sort_unstable();
+        }
+
+        SaveDiffer {
+            before,
+            after,
+            gained,
+            lost,
+        }
+    }
+
+    fn diff(&self) -> SaveDiff {
+        let landed = |query: &'a Query| {
+            query
+                .save()
+                .game
+                .countries
+                .iter()
+                .filter(|(_, country)| country.num_of_cities > 0)
+                .map(|(tag, _)| *tag)
+        };
+
+        let tags: BTreeSet<_> = landed(self.before).chain(landed(self.after)).collect();
+        let countries = tags
+            .into_iter()
+            .filter_map(|tag| self.country_diff(tag))
+            .collect();
+
+        SaveDiff {
+            before: self.before.save().meta.date,
+            after: self.after.save().meta.date,
+            countries,
+        }
+    }
+
+    fn country_diff(&self, tag: CountryTag) -> Option<CountryDiff> {
+        let before = self.before.country(&tag);
+        let after = self.after.country(&tag);
+        if before.is_none() && after.is_none() {
+            return None;
+        }
+
+        let delta = |f: fn(&Query, &Country) -> f32| Delta {
+            before: before.map(|x| f(self.before, x)).unwrap_or(0.0),
+            after: after.map(|x| f(self.after, x)).unwrap_or(0.0),
+        };
+
+        let tech = |f: fn(&Country) -> u8| Delta {
+            before: before.map(f).unwrap_or(0),
+            after: after.map(f).unwrap_or(0),
+        };
+
+        let idea_groups = |country: Option<&Country>| -> BTreeMap<String, u8> {
+            country
+                .map(|x| x.active_idea_groups.iter().cloned().collect())
+                .unwrap_or_default()
+        };
+
+        let before_ideas = idea_groups(before);
+        let after_ideas = idea_groups(after);
+        let ideas = before_ideas
+            .keys()
+            .chain(after_ideas.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|name| IdeaGroupDiff {
+                name: name.clone(),
+                ideas: Delta {
+                    before: before_ideas.get(name).copied().unwrap_or(0),
+                    after: after_ideas.get(name).copied().unwrap_or(0),
+                },
+            })
+            .filter(|x| x.ideas.before != x.ideas.after)
+            .collect();
+
+        let (wars_started, wars_ended) = self.war_changes(tag);
+
+        Some(CountryDiff {
+            tag,
+            development: delta(|_, x| x.development),
+            provinces_gained: self.gained.get(&tag).cloned().unwrap_or_default(),
+            provinces_lost: self.lost.get(&tag).cloned().unwrap_or_default(),
+            income: delta(|query, x| {
+                let income = query.country_income_breakdown(x);
+                income.taxation + income.production + income.trade + income.gold
+            }),
+            manpower: delta(|_, x| x.manpower),
+            adm_tech: tech(|x| x.technology.adm_tech),
+            dip_tech: tech(|x| x.technology.dip_tech),
+            mil_tech: tech(|x| x.technology.mil_tech),
+            ideas,
+            wars_started,
+            wars_ended,
+            rulers: after.map(|x| self.ruler_changes(x)).unwrap_or_default(),
+        })
+    }
+
+    /// Wars the country participated in that started or ended between the
+    /// two saves
+    fn war_changes(&self, tag: CountryTag) -> (Vec<String>, Vec<String>) {
+        let start = self.before.save().meta.date;
+        let game = &self.after.save().game;
+
+        let ended_before: HashSet<_> = self
+            .before
+            .save()
+            .game
+            .previous_wars
+            .iter()
+            .map(|x| x.name.as_str())
+            .collect();
+
+        let active = game
+            .active_wars
+            .iter()
+            .filter(|war| war.participants.iter().any(|x| x.tag == tag))
+            .map(|war| (war.name.as_str(), &war.history));
+
+        let previous = game
+            .previous_wars
+            .iter()
+            .filter(|war| war.participants.iter().any(|x| x.tag == tag))
+            .map(|war| (war.name.as_str(), &war.history));
+
+        let mut started = Vec::new();
+        let mut ended = Vec::new();
+        for (name, history) in active.clone().chain(previous.clone()) {
+            let start_date = history.events.iter().map(|(date, _)| *date).min();
+            if start_date.is_some_and(|x| x > start) {
+                started.push(String::from(name));
+            }
+        }
+
+        for (name, _) in previous {
+            if !ended_before.contains(name) {
+                ended.push(String::from(name));
+            }
+        }
+
+        (started, ended)
+    }
+
+    /// Monarchs that took the throne after the earlier save
+    fn ruler_changes(&self, country: &Country) -> Vec<RulerChange> {
+        let start = self.before.save().meta.date;
+        country
+            .history
+            .events
+            .iter()
+            .filter(|(date, _)| *date > start)
+            .filter_map(|(date, event)| match event {
+                CountryEvent::Monarch(x) => Some(RulerChange {
+                    date: *date,
+                    name: x.name.clone(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
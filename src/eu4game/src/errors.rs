@@ -16,6 +16,9 @@ pub enum Eu4GameError {
     #[error("No meta file detected")]
     NoMeta,
 
+    #[error("saves are from different playthroughs")]
+    PlaythroughMismatch,
+
     #[error("save file is too large at: {0} bytes")]
     TooLarge(usize),
 
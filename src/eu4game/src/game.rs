@@ -5,7 +5,10 @@ use schemas::{
     eu4::Terrain,
     flatbuffers::{Follow, Vector},
 };
-use std::{cmp::Ordering, collections::HashMap};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque, hash_map::Entry},
+};
 
 #[derive(Debug)]
 pub struct LocalizedCountry {
@@ -18,6 +21,7 @@ pub struct GameReligion<'a> {
     pub index: usize,
     pub id: &'a str,
     pub name: &'a str,
+    pub group: Option<&'a str>,
     pub color: [u8; 3],
     pub allowed_conversions: Vec<&'a str>,
     pub negotiate_convert_on_dominant_religion: bool,
@@ -149,6 +153,47 @@ impl<'a> Game<'a> {
         })
     }
 
+    /// Provinces that share a border with the given province or are
+    /// connected to it by a strait
+    pub fn neighbors(&self, key: &ProvinceId) -> impl Iterator<Item = ProvinceId> + 'a {
+        let provs = self.data.provinces().unwrap();
+        let idx = binary_search_by(&provs, |x| x.key_compare_with_value(key.as_u16())).ok();
+        idx.and_then(|idx| provs.get(idx).neighbors())
+            .into_iter()
+            .flatten()
+            .map(|id| ProvinceId::new(i32::from(id)))
+    }
+
+    /// Returns the provinces on the path with the fewest steps between the
+    /// two provinces, including both ends, or none if they aren't connected.
+    pub fn shortest_path(&self, from: &ProvinceId, to: &ProvinceId) -> Option<Vec<ProvinceId>> {
+        let mut previous = HashMap::new();
+        let mut queue = VecDeque::from([*from]);
+        previous.insert(*from, *from);
+
+        while let Some(current) = queue.pop_front() {
+            if current == *to {
+                let mut path = vec![current];
+                let mut step = current;
+                while step != *from {
+                    step = previous[&step];
+                    path.push(step);
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for neighbor in self.neighbors(&current) {
+                if let Entry::Vacant(e) = previous.entry(neighbor) {
+                    e.insert(current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        None
+    }
+
     /// Count of provinces (includes placeholder ones found in save files)
     pub fn total_provinces(&self) -> usize {
         self.data.total_provinces() as usize
@@ -175,6 +220,7 @@ impl<'a> Game<'a> {
             index: idx,
             id: res.key(),
             name: res.name(),
+            group: res.group(),
             color: res.color().0,
             allowed_conversions: res.allowed_conversion().iter().flatten().collect(),
             force_convert_on_break: res.force_convert_on_break(),
@@ -391,6 +437,61 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    fn adjacency_game(edges: &[(u16, &[u16])]) -> Vec<u8> {
+        let mut buffer = schemas::flatbuffers::FlatBufferBuilder::new();
+        let provinces = edges
+            .iter()
+            .map(|(id, neighbors)| {
+                let neighbors = buffer.create_vector(neighbors);
+                schemas::eu4::Province::create(
+                    &mut buffer,
+                    &schemas::eu4::ProvinceArgs {
+                        id: *id,
+                        neighbors: Some(neighbors),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let provinces = buffer.create_vector(&provinces);
+        let game = schemas::eu4::Game::create(
+            &mut buffer,
+            &schemas::eu4::GameArgs {
+                provinces: Some(provinces),
+                ..Default::default()
+            },
+        );
+        buffer.finish(game, None);
+        buffer.finished_data().to_vec()
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        // 1 - 2 - 3 - 4
+        //  \_ 5 _/      6
+        let data = adjacency_game(&[
+            (1, &[2, 5]),
+            (2, &[1, 3]),
+            (3, &[2, 4, 5]),
+            (4, &[3]),
+            (5, &[1, 3]),
+            (6, &[]),
+        ]);
+        let game = Game::from_flatbuffer(&data);
+        let id = |x: i32| ProvinceId::new(x);
+
+        let neighbors: Vec<_> = game.neighbors(&id(3)).collect();
+        assert_eq!(neighbors, vec![id(2), id(4), id(5)]);
+        assert_eq!(game.neighbors(&id(7)).count(), 0);
+
+        let path = game.shortest_path(&id(1), &id(4)).unwrap();
+        assert_eq!(path.len(), 4);
+        assert_eq!(path.first(), Some(&id(1)));
+        assert_eq!(path.last(), Some(&id(4)));
+        assert_eq!(game.shortest_path(&id(1), &id(1)), Some(vec![id(1)]));
+        assert_eq!(game.shortest_path(&id(1), &id(6)), None);
+    }
+
     #[test]
     fn test_129_game() {
         let game = Game::from_flatbuffer(eu4game_data::game_data(29));
@@ -198,11 +198,7 @@ impl Eu4Parser {
 
     #[cfg(feature = "embedded")]
     pub fn parse(&self, data: &[u8]) -> Result<Eu4SaveOutput, Eu4GameError> {
-        let tokens = schemas::resolver::Eu4FlatTokens::new();
-        let breakpoint = tokens.breakpoint();
-        let values = tokens.into_values();
-        let resolver = SegmentedResolver::from_parts(values, breakpoint, 10000);
-        self.parse_with(data, &resolver)
+        self.parse_with(data, &embedded_resolver())
     }
 
     fn deserialize<'de, T, D>(&self, deser: D) -> Result<T, Eu4GameError>
@@ -321,6 +317,15 @@ impl Eu4Parser {
     }
 }
 
+/// Token resolver over the binary tokens embedded in the build
+#[cfg(feature = "embedded")]
+pub fn embedded_resolver() -> SegmentedResolver<'static> {
+    let tokens = schemas::resolver::Eu4FlatTokens::new();
+    let breakpoint = tokens.breakpoint();
+    let values = tokens.into_values();
+    SegmentedResolver::from_parts(values, breakpoint, 10000)
+}
+
 pub fn parse_meta(data: &[u8], resolver: &SegmentedResolver) -> Result<Meta, Eu4GameError> {
     if pdx_zstd::is_zstd_compressed(data) {
         let mut decoder = pdx_zstd::Decoder::from_slice(data)?;
@@ -0,0 +1,37 @@
+use crate::utils;
+use eu4game::{Eu4GameError, diff::diff_saves, shared::Eu4Parser};
+use eu4save::query::Query;
+
+fn query(filename: &str) -> Query {
+    let data = utils::request(filename);
+    let save = Eu4Parser::new().parse(&data).unwrap().save;
+    Query::from_save(save)
+}
+
+#[test]
+fn test_diff_same_playthrough() {
+    let before = query("ita2.eu4");
+    let after = query("ita2_later.eu4");
+
+    let diff = diff_saves(&before, &after).unwrap();
+    assert!(diff.before < diff.after);
+    assert!(!diff.countries.is_empty());
+
+    // Saves can be given in either order
+    let reversed = diff_saves(&after, &before).unwrap();
+    assert_eq!(diff, reversed);
+
+    let gained = diff
+        .countries
+        .iter()
+        .any(|x| !x.provinces_gained.is_empty());
+    assert!(gained);
+}
+
+#[test]
+fn test_diff_different_playthroughs() {
+    let a = query("arda-persia.eu4");
+    let b = query("ragusa.bin.eu4");
+    let err = diff_saves(&a, &b).unwrap_err();
+    assert!(matches!(err, Eu4GameError::PlaythroughMismatch));
+}
@@ -1,6 +1,9 @@
 use crate::utils;
 use eu4game::shared::{Eu4Parser, Eu4SaveOutput};
-use eu4game::{achievements::AchievementHunter, game::Game};
+use eu4game::{
+    achievements::{AchievementEligibility, AchievementHunter, EligibilityCheck},
+    game::Game,
+};
 use eu4save::{models::SavegameVersion, query::Query};
 use std::error::Error;
 
@@ -25,3 +28,34 @@ pub fn old_saves_are_invalid() -> Result<(), Box<dyn Error>> {
     assert!(achievements.is_none());
     Ok(())
 }
+
+#[test]
+pub fn ineligible_saves_explain_why() -> Result<(), Box<dyn Error>> {
+    let data = utils::request("Ruskies.eu4");
+    let Eu4SaveOutput { save, encoding, .. } = Eu4Parser::new().parse(&data)?;
+    let game = Game::new(&save.meta.savegame_version);
+    let query = Query::from_save(save);
+    let province_owners = query.province_owners();
+    let nation_events = query.nation_events(&province_owners);
+    let player_histories = query.player_histories(&nation_events);
+
+    let eligibility = AchievementEligibility::check(encoding, &query, &game, &player_histories);
+    assert!(!eligibility.is_eligible());
+
+    // The 1.28 save is compared against the earliest bundled game data,
+    // which has a different number of provinces
+    let failed: Vec<_> = eligibility
+        .gates
+        .iter()
+        .filter(|x| !x.passed)
+        .map(|x| x.check)
+        .collect();
+    assert!(failed.contains(&EligibilityCheck::ProvinceCount));
+
+    // Every save reports its checksum and console gates, pass or fail
+    for check in [EligibilityCheck::Checksum, EligibilityCheck::Console] {
+        let gate = eligibility.gates.iter().find(|x| x.check == check);
+        assert!(gate.is_some_and(|x| !x.reason.is_empty()), "{:?}", check);
+    }
+    Ok(())
+}
@@ -98,6 +98,24 @@ fn test_je_maintiendrai() {
     assert!(completed_ids.contains(&48));
 }
 
+#[test]
+fn test_world_conqueror_progress() {
+    let data = utils::request("Dutch_WC.eu4");
+    let Eu4SaveOutput { save, encoding, .. } = Eu4Parser::new().parse(&data).unwrap();
+    let game = Game::new(&save.meta.savegame_version);
+    let query = Query::from_save(save);
+    let achievements = AchievementHunter::new(encoding, &query, &game).unwrap();
+    let result = achievements.world_conqueror();
+    let progress = result.conditions.iter().find_map(|x| x.progress()).unwrap();
+    assert!(progress.total > 0);
+    assert_eq!(progress.current, progress.total);
+    assert!(progress.missing.is_empty());
+
+    let result = achievements.one_faith();
+    let progress = result.conditions.iter().find_map(|x| x.progress()).unwrap();
+    assert_eq!(progress.total - progress.current, progress.missing.len());
+}
+
 #[test]
 fn test_stiff_upper_lippe() {
     let data = utils::request("Lippe.eu4");
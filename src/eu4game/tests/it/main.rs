@@ -1,3 +1,4 @@
+mod diff;
 mod invalid_ironman;
 mod ironman;
 mod playthrough_id;
@@ -1,6 +1,6 @@
 use crate::entity_profile::CountryRef;
 use crate::presentation::{CountryRefSource, Localized, UiLocationIdx, UiMarketId, present_dto};
-use eu5save::models::{LocationIdx, MarketId, ReligionId};
+use eu5save::models::{CultureId, LocationIdx, MarketId, ReligionId};
 
 present_dto! {
     pub(crate) mod workspace;
@@ -18,6 +18,17 @@ present_dto! {
         Wealth { value: f64 },
         UnrealizedTaxBase { value: f64 },
         Religion { religion: ReligionId => Localized<String> },
+        Culture { culture: CultureId => Localized<String> },
+        Literacy { value: f64 },
+        Satisfaction { value: f64 },
+        Prosperity { value: f64 },
+        Proximity { value: f64 },
+        MarketAccess { value: f64 },
+        MarketAttraction { value: f64 },
+        Occupation {
+            occupied_locations: u32,
+            controller: Option<CountryRefSource> => Option<CountryRef>,
+        },
         StateEfficacy { value: f64 },
     }
 
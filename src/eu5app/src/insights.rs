@@ -182,6 +182,88 @@ pub struct RgoScopeSummary {
     pub is_empty: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+#[cfg_attr(feature = "tsify", tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct CultureScopeSummary {
+    pub location_count: u32,
+    pub country_count: u32,
+    pub culture_count: u32,
+    pub total_population: u32,
+    pub is_empty: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+#[cfg_attr(feature = "tsify", tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct PopAttributeScopeSummary {
+    pub location_count: u32,
+    pub country_count: u32,
+    pub total_population: u32,
+    /// Pop size weighted average within the scope
+    pub avg_value: f64,
+    /// Pop size weighted average across all owned locations
+    pub baseline_avg_value: f64,
+    pub is_empty: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+#[cfg_attr(feature = "tsify", tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct ProsperityScopeSummary {
+    pub location_count: u32,
+    pub country_count: u32,
+    pub avg_prosperity: f64,
+    pub avg_proximity: f64,
+    pub avg_control: f64,
+    pub avg_market_access: f64,
+    pub is_empty: bool,
+}
+
+/// How a factor relates to prosperity across the locations in scope. Factor
+/// values are normalized to 0..1 (proximity is divided by 100).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+#[cfg_attr(feature = "tsify", tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct ProsperityFactor {
+    pub factor: String,
+    /// Pearson correlation between the factor and prosperity
+    pub correlation: f64,
+    /// Average factor value in the least prosperous quarter of locations
+    pub avg_in_poorest: f64,
+    /// Average factor value in the remaining locations
+    pub avg_in_rest: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+#[cfg_attr(feature = "tsify", tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct ProsperityBand {
+    pub band: String,
+    pub location_count: u32,
+    pub avg_proximity: f64,
+    pub avg_control: f64,
+    pub avg_market_access: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "tsify", derive(tsify::Tsify))]
+#[cfg_attr(feature = "tsify", tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct WarsScopeSummary {
+    pub location_count: u32,
+    pub occupied_location_count: u32,
+    pub occupied_development: f64,
+    pub war_count: u32,
+    pub battle_count: u32,
+    pub is_empty: bool,
+}
+
 pub mod distribution {
     use super::*;
 
@@ -542,6 +624,145 @@ pub mod religion {
     }
 }
 
+pub mod culture {
+    use super::*;
+
+    present_dto! {
+        pub(crate) mod workspace;
+        pub mod presentation;
+
+        pub PopulationCultureShare {
+            culture: eu5save::models::CultureId => Localized<String>,
+            color_hex: String,
+            population: u32,
+        }
+
+        pub CultureRow {
+            culture: eu5save::models::CultureId => Localized<String>,
+            color_hex: String,
+            population: u32,
+            population_share: f64,
+            dominant_location_count: u32,
+            dominant_country_count: u32,
+        }
+
+        pub CountryCultureRow {
+            country: crate::presentation::CountryRefSource => CountryRef,
+            total_population: u32,
+            culture_count: u32,
+            dominant_culture_share: f64,
+            top_cultures: Vec<workspace::PopulationCultureShare> => Vec<presentation::PopulationCultureShare>,
+        }
+
+        pub CultureInsightData {
+            scope: CultureScopeSummary,
+            cultures: Vec<workspace::CultureRow> => Vec<presentation::CultureRow>,
+            countries: Vec<workspace::CountryCultureRow> => Vec<presentation::CountryCultureRow>,
+        }
+    }
+}
+
+pub mod pop_attributes {
+    use super::*;
+
+    present_dto! {
+        pub(crate) mod workspace;
+        pub mod presentation;
+
+        pub PopTypeAttributeRow {
+            population_type: eu5save::models::PopulationType => String,
+            population: u32,
+            avg_value: f64,
+            baseline_avg_value: f64,
+        }
+
+        pub CountryPopTypeAttributeCell {
+            country: crate::presentation::CountryRefSource => CountryRef,
+            population_type: eu5save::models::PopulationType => String,
+            population: u32,
+            avg_value: f64,
+        }
+
+        pub PopAttributeLocation {
+            location: eu5save::models::LocationIdx => Localized<UiLocationIdx>,
+            owner: crate::presentation::CountryRefSource => CountryRef,
+            population: u32,
+            avg_value: f64,
+            lowest_type: Option<eu5save::models::PopulationType> => Option<String>,
+            lowest_type_value: f64,
+        }
+
+        pub PopAttributeInsightData {
+            metric_label: String,
+            scope: PopAttributeScopeSummary,
+            types: Vec<workspace::PopTypeAttributeRow> => Vec<presentation::PopTypeAttributeRow>,
+            country_cells: Vec<workspace::CountryPopTypeAttributeCell> => Vec<presentation::CountryPopTypeAttributeCell>,
+            lowest_locations: Vec<workspace::PopAttributeLocation> => Vec<presentation::PopAttributeLocation>,
+        }
+    }
+}
+
+pub mod prosperity {
+    use super::*;
+
+    present_dto! {
+        pub(crate) mod workspace;
+        pub mod presentation;
+
+        pub ProsperityLocation {
+            location: eu5save::models::LocationIdx => Localized<UiLocationIdx>,
+            owner: crate::presentation::CountryRefSource => CountryRef,
+            prosperity: f64,
+            proximity: f64,
+            control: f64,
+            market_access: f64,
+            development: f64,
+            limiting_factor: String,
+        }
+
+        pub ProsperityInsightData {
+            scope: ProsperityScopeSummary,
+            factors: Vec<ProsperityFactor>,
+            bands: Vec<ProsperityBand>,
+            poorest_locations: Vec<workspace::ProsperityLocation> => Vec<presentation::ProsperityLocation>,
+        }
+    }
+}
+
+pub mod wars {
+    use super::*;
+
+    present_dto! {
+        pub(crate) mod workspace;
+        pub mod presentation;
+
+        pub WarBattle {
+            location: eu5save::models::LocationIdx => Localized<UiLocationIdx>,
+            date: String,
+        }
+
+        pub WarSummary {
+            war_id: u32,
+            start_date: String,
+            battle_count: u32,
+            battles: Vec<workspace::WarBattle> => Vec<presentation::WarBattle>,
+        }
+
+        pub OccupiedLocation {
+            location: eu5save::models::LocationIdx => Localized<UiLocationIdx>,
+            owner: crate::presentation::CountryRefSource => CountryRef,
+            controller: crate::presentation::CountryRefSource => CountryRef,
+            development: f64,
+        }
+
+        pub WarsInsightData {
+            scope: WarsScopeSummary,
+            wars: Vec<workspace::WarSummary> => Vec<presentation::WarSummary>,
+            occupied_locations: Vec<workspace::OccupiedLocation> => Vec<presentation::OccupiedLocation>,
+        }
+    }
+}
+
 pub mod control {
     use super::*;
 
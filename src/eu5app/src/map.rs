@@ -36,6 +36,14 @@ pub enum MapMode {
     Wealth,
     UnrealizedTaxBase,
     Religion,
+    Culture,
+    Literacy,
+    Satisfaction,
+    Prosperity,
+    Proximity,
+    MarketAccess,
+    MarketAttraction,
+    Occupation,
     StateEfficacy,
 }
 
@@ -52,6 +60,14 @@ impl MapMode {
             MapMode::Wealth => "Wealth",
             MapMode::UnrealizedTaxBase => "Unrealized Tax Base",
             MapMode::Religion => "Religion",
+            MapMode::Culture => "Culture",
+            MapMode::Literacy => "Literacy",
+            MapMode::Satisfaction => "Satisfaction",
+            MapMode::Prosperity => "Prosperity",
+            MapMode::Proximity => "Proximity",
+            MapMode::MarketAccess => "Market Access",
+            MapMode::MarketAttraction => "Market Attraction",
+            MapMode::Occupation => "Occupation",
             MapMode::StateEfficacy => "State Efficacy",
         }
     }
@@ -26,17 +26,22 @@ use crate::game_data::{GameData, Localization};
 use crate::hover::presentation::DisplayData as HoverDisplayData;
 use crate::insights::buildings::presentation::BuildingLevelsInsightData;
 use crate::insights::control::presentation::{ControlInsightData, PoliticalWorldScoreboard};
+use crate::insights::culture::presentation::CultureInsightData;
 use crate::insights::development::presentation::DevelopmentInsightData;
 use crate::insights::markets::presentation::{
     MarketInsightData, MarketProductionLocationSummary, ScopedGoodSummary,
 };
+use crate::insights::pop_attributes::presentation::PopAttributeInsightData;
 use crate::insights::population::presentation::PopulationInsightData;
+use crate::insights::prosperity::presentation::ProsperityInsightData;
 use crate::insights::religion::presentation::ReligionInsightData;
 use crate::insights::rgo::presentation::RgoInsightData;
 use crate::insights::state_efficacy::presentation::StateEfficacyInsightData;
 use crate::insights::tax::presentation::{UnrealizedTaxBaseInsightData, WealthInsightData};
+use crate::insights::wars::presentation::WarsInsightData;
 use crate::overlay::OverlayBodyConfig;
 use crate::session::Eu5Workspace;
+use crate::session::workspace::PopAttribute;
 
 mod present_dto;
 
@@ -97,6 +102,34 @@ impl<'a, 'bump> Eu5Presenter<'a, 'bump> {
             .present(&self.ctx)
     }
 
+    pub fn calculate_culture_insight(&self) -> CultureInsightData {
+        self.workspace
+            .calculate_culture_insight()
+            .present(&self.ctx)
+    }
+
+    pub fn calculate_literacy_insight(&self) -> PopAttributeInsightData {
+        self.workspace
+            .calculate_pop_attribute_insight(PopAttribute::Literacy)
+            .present(&self.ctx)
+    }
+
+    pub fn calculate_satisfaction_insight(&self) -> PopAttributeInsightData {
+        self.workspace
+            .calculate_pop_attribute_insight(PopAttribute::Satisfaction)
+            .present(&self.ctx)
+    }
+
+    pub fn calculate_prosperity_insight(&self) -> ProsperityInsightData {
+        self.workspace
+            .calculate_prosperity_insight()
+            .present(&self.ctx)
+    }
+
+    pub fn calculate_wars_insight(&self) -> WarsInsightData {
+        self.workspace.calculate_wars_insight().present(&self.ctx)
+    }
+
     pub fn calculate_building_levels_insight(&self) -> BuildingLevelsInsightData {
         self.workspace
             .calculate_building_levels_insight()
@@ -20,6 +20,7 @@ use crate::hover::workspace::{
 };
 use crate::insights::buildings::workspace::*;
 use crate::insights::control::workspace::*;
+use crate::insights::culture::workspace::*;
 use crate::insights::development::workspace::{
     CountryDevSummary, DevTopLocation, DevelopmentInsightData,
 };
@@ -28,16 +29,21 @@ use crate::insights::markets::workspace::{
     GoodMarketBalanceCell, MarketInsightData, MarketProductionLocationSummary,
     ProductionLocationSummary, ScopedGoodSummary, ScopedMarketSummary,
 };
+use crate::insights::pop_attributes::workspace::*;
 use crate::insights::population::workspace::*;
+use crate::insights::prosperity::workspace::*;
 use crate::insights::religion::workspace::*;
 use crate::insights::rgo::workspace::*;
 use crate::insights::state_efficacy::workspace::*;
 use crate::insights::tax::workspace::*;
+use crate::insights::wars::workspace::*;
 use crate::insights::{
-    BuildingLevelsScopeSummary, ControlBandSegment, ControlScopeSummary, DevelopmentScopeSummary,
-    DistributionBucket, GoodBreakdownEntry, MarketScopeSummary, PopulationConcentrationPoint,
-    PopulationRankSegment, PopulationScopeSummary, PopulationTypeProfileRow, RgoScopeSummary,
-    StateEfficacyScopeSummary, UnrealizedTaxBaseScope, WealthScope,
+    BuildingLevelsScopeSummary, ControlBandSegment, ControlScopeSummary, CultureScopeSummary,
+    DevelopmentScopeSummary, DistributionBucket, GoodBreakdownEntry, MarketScopeSummary,
+    PopAttributeScopeSummary, PopulationConcentrationPoint, PopulationRankSegment,
+    PopulationScopeSummary, PopulationTypeProfileRow, ProsperityBand, ProsperityFactor,
+    ProsperityScopeSummary, RgoScopeSummary, StateEfficacyScopeSummary, UnrealizedTaxBaseScope,
+    WarsScopeSummary, WealthScope,
 };
 use crate::overlay::{OverlayBodyConfigSource, OverlayTableSource, TableCellSource};
 use crate::presentation::{CountryRefSource, Eu5Presenter, MarketRefSource};
@@ -77,6 +83,29 @@ pub struct Eu5Workspace<'bump> {
     derived_entity_kind: Option<EntityKind>,
 }
 
+/// A per-pop value that is averaged by pop size for map modes and insights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PopAttribute {
+    Literacy,
+    Satisfaction,
+}
+
+impl PopAttribute {
+    fn value(self, pop: &eu5save::models::Population) -> f64 {
+        match self {
+            PopAttribute::Literacy => pop.literacy,
+            PopAttribute::Satisfaction => pop.satisfaction,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            PopAttribute::Literacy => "Literacy",
+            PopAttribute::Satisfaction => "Satisfaction",
+        }
+    }
+}
+
 enum SelectionSetOperation {
     Add,
     Remove,
@@ -67,6 +67,42 @@ impl<'bump> Eu5Workspace<'bump> {
                     religion: religion_id,
                 })
                 .unwrap_or(HoverStatSource::None),
+            MapMode::Culture => self
+                .location_dominant_culture(location)
+                .map(|culture| HoverStatSource::Culture { culture })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Literacy => self
+                .location_pop_average(location, PopAttribute::Literacy)
+                .map(|value| HoverStatSource::Literacy { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Satisfaction => self
+                .location_pop_average(location, PopAttribute::Satisfaction)
+                .map(|value| HoverStatSource::Satisfaction { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Prosperity => HoverStatSource::Prosperity {
+                value: location.prosperity,
+            },
+            MapMode::Proximity => HoverStatSource::Proximity {
+                value: location.proximity,
+            },
+            MapMode::MarketAccess => HoverStatSource::MarketAccess {
+                value: location.market_access,
+            },
+            MapMode::MarketAttraction => HoverStatSource::MarketAttraction {
+                value: location.market_attraction,
+            },
+            MapMode::Occupation => {
+                let occupied = super::map_render::is_occupied(location);
+                let controller = occupied
+                    .then(|| location.controller.real_id())
+                    .flatten()
+                    .and_then(|id| self.gamestate().countries.get(id.country_id()))
+                    .map(|country_idx| self.country_ref_from_country_idx(country_idx));
+                HoverStatSource::Occupation {
+                    occupied_locations: u32::from(occupied),
+                    controller,
+                }
+            }
             MapMode::StateEfficacy => HoverStatSource::StateEfficacy {
                 value: location.control * location.development,
             },
@@ -213,6 +249,60 @@ impl<'bump> Eu5Workspace<'bump> {
                     religion: religion_id,
                 })
                 .unwrap_or(HoverStatSource::None),
+            MapMode::Culture => {
+                let mut cultures = FxHashMap::default();
+
+                for entry in self.gamestate().locations.iter() {
+                    let location = entry.location();
+                    if location.owner == owner_id {
+                        for (culture_id, population) in self.location_culture_population(location) {
+                            *cultures.entry(culture_id).or_default() += population;
+                        }
+                    }
+                }
+
+                super::map_render::dominant_culture(&cultures)
+                    .map(|culture| HoverStatSource::Culture { culture })
+                    .unwrap_or(HoverStatSource::None)
+            }
+            MapMode::Literacy => self
+                .country_pop_average(owner_id, PopAttribute::Literacy)
+                .map(|value| HoverStatSource::Literacy { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Satisfaction => self
+                .country_pop_average(owner_id, PopAttribute::Satisfaction)
+                .map(|value| HoverStatSource::Satisfaction { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Prosperity => self
+                .country_location_average(owner_id, |loc| loc.prosperity)
+                .map(|value| HoverStatSource::Prosperity { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Proximity => self
+                .country_location_average(owner_id, |loc| loc.proximity)
+                .map(|value| HoverStatSource::Proximity { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::MarketAccess => self
+                .country_location_average(owner_id, |loc| loc.market_access)
+                .map(|value| HoverStatSource::MarketAccess { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::MarketAttraction => self
+                .country_location_average(owner_id, |loc| loc.market_attraction)
+                .map(|value| HoverStatSource::MarketAttraction { value })
+                .unwrap_or(HoverStatSource::None),
+            MapMode::Occupation => {
+                let occupied_locations = self
+                    .gamestate()
+                    .locations
+                    .iter()
+                    .map(|entry| entry.location())
+                    .filter(|location| location.owner == owner_id)
+                    .filter(|location| super::map_render::is_occupied(location))
+                    .count() as u32;
+                HoverStatSource::Occupation {
+                    occupied_locations,
+                    controller: None,
+                }
+            }
             MapMode::StateEfficacy => {
                 let mut total = 0.0;
 
@@ -227,4 +317,47 @@ impl<'bump> Eu5Workspace<'bump> {
             }
         }
     }
+
+    /// Unweighted average of a location value across a country's locations
+    fn country_location_average(
+        &self,
+        owner_id: CountryId,
+        value: impl Fn(&eu5save::models::Location<'_>) -> f64,
+    ) -> Option<f64> {
+        let mut sum = 0.0;
+        let mut count = 0_u32;
+
+        for entry in self.gamestate().locations.iter() {
+            let location = entry.location();
+            if location.owner == owner_id {
+                sum += value(location);
+                count += 1;
+            }
+        }
+
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Pop size weighted average of a pop attribute across a country's locations
+    fn country_pop_average(&self, owner_id: CountryId, attribute: PopAttribute) -> Option<f64> {
+        let mut size = 0.0;
+        let mut weighted = 0.0;
+
+        for entry in self.gamestate().locations.iter() {
+            let location = entry.location();
+            if location.owner != owner_id {
+                continue;
+            }
+
+            for &pop_id in location.population.pops {
+                let Some(pop) = self.gamestate().population.database.lookup(pop_id) else {
+                    continue;
+                };
+                size += pop.size;
+                weighted += attribute.value(pop) * pop.size;
+            }
+        }
+
+        (size > 0.0).then(|| weighted / size)
+    }
 }
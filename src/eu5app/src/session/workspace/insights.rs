@@ -36,6 +36,100 @@ fn political_world_display_rows(
         .collect()
 }
 
+/// Cultures ordered by population, ties broken by the lowest id
+fn ranked_cultures(
+    population_by_culture: &FxHashMap<eu5save::models::CultureId, f64>,
+) -> Vec<(eu5save::models::CultureId, f64)> {
+    let mut cultures: Vec<_> = population_by_culture
+        .iter()
+        .map(|(&k, &v)| (k, v))
+        .collect();
+    cultures.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    cultures
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0_u32), |(sum, count), x| (sum + x, count + 1));
+    if count == 0 { 0.0 } else { sum / count as f64 }
+}
+
+/// Pearson correlation coefficient, zero when either series is constant
+fn pearson(xs: &[f64], ys: &[f64]) -> f64 {
+    let x_mean = mean(xs.iter().copied());
+    let y_mean = mean(ys.iter().copied());
+    let mut cov = 0.0;
+    let mut x_var = 0.0;
+    let mut y_var = 0.0;
+    for (x, y) in xs.iter().zip(ys) {
+        cov += (x - x_mean) * (y - y_mean);
+        x_var += (x - x_mean).powi(2);
+        y_var += (y - y_mean).powi(2);
+    }
+
+    if x_var <= 0.0 || y_var <= 0.0 {
+        0.0
+    } else {
+        cov / (x_var.sqrt() * y_var.sqrt())
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct PopAttributeAgg {
+    population: f64,
+    value_num: f64,
+}
+
+impl PopAttributeAgg {
+    fn add(&mut self, size: f64, value: f64) {
+        self.population += size;
+        self.value_num += value * size;
+    }
+
+    fn avg(&self) -> f64 {
+        if self.population > 0.0 {
+            self.value_num / self.population
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Pop attribute averages per pop type, worldwide and within the scope
+#[derive(Default)]
+struct PopTypeAttributeTally {
+    baseline: FxHashMap<PopulationType, PopAttributeAgg>,
+    scoped: FxHashMap<PopulationType, PopAttributeAgg>,
+}
+
+impl PopTypeAttributeTally {
+    fn add(&mut self, kind: PopulationType, size: f64, value: f64, in_scope: bool) {
+        self.baseline.entry(kind).or_default().add(size, value);
+        if in_scope {
+            self.scoped.entry(kind).or_default().add(size, value);
+        }
+    }
+
+    /// Scoped pop types, largest population first
+    fn rows(&self) -> Vec<PopTypeAttributeRow> {
+        let mut rows: Vec<PopTypeAttributeRow> = self
+            .scoped
+            .iter()
+            .map(|(&population_type, agg)| PopTypeAttributeRow {
+                population_type,
+                population: (agg.population * 1000.0).floor() as u32,
+                avg_value: agg.avg(),
+                baseline_avg_value: self
+                    .baseline
+                    .get(&population_type)
+                    .map(|b| b.avg())
+                    .unwrap_or(0.0),
+            })
+            .collect();
+        rows.sort_by(|a, b| b.population.cmp(&a.population));
+        rows
+    }
+}
+
 impl<'bump> Eu5Workspace<'bump> {
     pub(crate) fn calculate_political_world_scoreboard(&self) -> PoliticalWorldScoreboard {
         #[derive(Default)]
@@ -1540,6 +1634,490 @@ impl<'bump> Eu5Workspace<'bump> {
         }
     }
 
+    pub(crate) fn calculate_culture_insight(&self) -> CultureInsightData {
+        use eu5save::models::CultureId;
+
+        const TOP_COUNTRY_CULTURES: usize = 3;
+
+        #[derive(Default)]
+        struct CountryCultureAgg {
+            total_population: f64,
+            population_by_culture: FxHashMap<CultureId, f64>,
+        }
+
+        let is_empty = self.selection_state.is_empty();
+        let mut location_count = 0_u32;
+        let mut total_population = 0.0;
+        let mut population_by_culture: FxHashMap<CultureId, f64> = FxHashMap::default();
+        let mut dominant_locations: FxHashMap<CultureId, u32> = FxHashMap::default();
+        let mut country_aggs: FxHashMap<CountryId, CountryCultureAgg> = FxHashMap::default();
+
+        for entry in self.gamestate.locations.iter() {
+            let idx = entry.idx();
+            if !is_empty && !self.selection_state.contains(idx) {
+                continue;
+            }
+
+            let loc = entry.location();
+            let Some(owner_id) = loc.owner.real_id().map(|r| r.country_id()) else {
+                continue;
+            };
+
+            location_count += 1;
+            let cultures = self.location_culture_population(loc);
+            if let Some(culture_id) = super::map_render::dominant_culture(&cultures) {
+                *dominant_locations.entry(culture_id).or_default() += 1;
+            }
+
+            let country = country_aggs.entry(owner_id).or_default();
+            for (culture_id, population) in cultures {
+                total_population += population;
+                country.total_population += population;
+                *country.population_by_culture.entry(culture_id).or_default() += population;
+                *population_by_culture.entry(culture_id).or_default() += population;
+            }
+        }
+
+        let culture_color_hex = |cid: CultureId| -> Option<String> {
+            let culture = self.gamestate.culture_manager.lookup(cid)?;
+            Some(format!(
+                "#{:02x}{:02x}{:02x}",
+                culture.color.0[0], culture.color.0[1], culture.color.0[2]
+            ))
+        };
+
+        let mut dominant_countries: FxHashMap<CultureId, u32> = FxHashMap::default();
+        let mut countries: Vec<CountryCultureRow> = country_aggs
+            .iter()
+            .filter(|(_, agg)| agg.total_population > 0.0)
+            .filter_map(|(&country_id, agg)| {
+                let cidx = self.gamestate.countries.get(country_id)?;
+
+                let by_culture = ranked_cultures(&agg.population_by_culture);
+                let (dominant_id, dominant_pop) = by_culture.first().copied()?;
+                *dominant_countries.entry(dominant_id).or_default() += 1;
+
+                let top_cultures = by_culture
+                    .iter()
+                    .take(TOP_COUNTRY_CULTURES)
+                    .filter_map(|&(cid, pop)| {
+                        Some(PopulationCultureShare {
+                            culture: cid,
+                            color_hex: culture_color_hex(cid)?,
+                            population: pop as u32,
+                        })
+                    })
+                    .collect();
+
+                Some(CountryCultureRow {
+                    country: self.country_ref_from_country_idx(cidx),
+                    total_population: agg.total_population as u32,
+                    culture_count: by_culture.len() as u32,
+                    dominant_culture_share: dominant_pop / agg.total_population,
+                    top_cultures,
+                })
+            })
+            .collect();
+        countries.sort_by(|a, b| {
+            b.total_population.cmp(&a.total_population).then_with(|| {
+                a.country
+                    .country_idx
+                    .value()
+                    .cmp(&b.country.country_idx.value())
+            })
+        });
+
+        let mut cultures: Vec<CultureRow> = population_by_culture
+            .iter()
+            .filter_map(|(&cid, &population)| {
+                Some(CultureRow {
+                    culture: cid,
+                    color_hex: culture_color_hex(cid)?,
+                    population: population as u32,
+                    population_share: if total_population > 0.0 {
+                        population / total_population
+                    } else {
+                        0.0
+                    },
+                    dominant_location_count: dominant_locations.get(&cid).copied().unwrap_or(0),
+                    dominant_country_count: dominant_countries.get(&cid).copied().unwrap_or(0),
+                })
+            })
+            .collect();
+        cultures.sort_by(|a, b| {
+            b.population
+                .cmp(&a.population)
+                .then_with(|| a.culture.value().cmp(&b.culture.value()))
+        });
+
+        CultureInsightData {
+            scope: CultureScopeSummary {
+                location_count,
+                country_count: country_aggs.len() as u32,
+                culture_count: cultures.len() as u32,
+                total_population: total_population as u32,
+                is_empty,
+            },
+            cultures,
+            countries,
+        }
+    }
+
+    pub(crate) fn calculate_pop_attribute_insight(
+        &self,
+        attribute: PopAttribute,
+    ) -> PopAttributeInsightData {
+        const TOP_COUNTRIES: usize = 12;
+        const LOWEST_LOCATIONS: usize = 25;
+
+        let is_empty = self.selection_state.is_empty();
+        let mut location_count = 0_u32;
+        let mut baseline = PopAttributeAgg::default();
+        let mut scoped = PopAttributeAgg::default();
+        let mut type_tally = PopTypeAttributeTally::default();
+        let mut country_totals: FxHashMap<CountryId, PopAttributeAgg> = FxHashMap::default();
+        let mut country_types: FxHashMap<(CountryId, PopulationType), PopAttributeAgg> =
+            FxHashMap::default();
+        let mut locations = Vec::new();
+
+        for entry in self.gamestate.locations.iter() {
+            let idx = entry.idx();
+            let loc = entry.location();
+            let Some(owner_id) = loc.owner.real_id().map(|r| r.country_id()) else {
+                continue;
+            };
+            let in_scope = is_empty || self.selection_state.contains(idx);
+            let mut location = PopAttributeAgg::default();
+            let mut location_types: FxHashMap<PopulationType, PopAttributeAgg> =
+                FxHashMap::default();
+
+            for &pop_id in loc.population.pops {
+                let Some(pop) = self.gamestate.population.database.lookup(pop_id) else {
+                    continue;
+                };
+                let value = attribute.value(pop);
+                baseline.add(pop.size, value);
+                type_tally.add(pop.kind, pop.size, value, in_scope);
+                if in_scope {
+                    location.add(pop.size, value);
+                    location_types
+                        .entry(pop.kind)
+                        .or_default()
+                        .add(pop.size, value);
+                    country_totals
+                        .entry(owner_id)
+                        .or_default()
+                        .add(pop.size, value);
+                    country_types
+                        .entry((owner_id, pop.kind))
+                        .or_default()
+                        .add(pop.size, value);
+                }
+            }
+
+            if !in_scope {
+                continue;
+            }
+
+            location_count += 1;
+            if location.population <= 0.0 {
+                continue;
+            }
+
+            scoped.population += location.population;
+            scoped.value_num += location.value_num;
+
+            let lowest = location_types
+                .iter()
+                .min_by(|a, b| a.1.avg().total_cmp(&b.1.avg()))
+                .map(|(&kind, agg)| (kind, agg.avg()));
+            locations.push((idx, owner_id, location, lowest));
+        }
+
+        let types = type_tally.rows();
+
+        let mut top_countries: Vec<(CountryId, f64)> = country_totals
+            .iter()
+            .map(|(&country_id, agg)| (country_id, agg.population))
+            .collect();
+        top_countries.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_countries.truncate(TOP_COUNTRIES);
+
+        let mut country_cells = Vec::new();
+        for &(country_id, _) in &top_countries {
+            let Some(cidx) = self.gamestate.countries.get(country_id) else {
+                continue;
+            };
+            let country = self.country_ref_from_country_idx(cidx);
+            for row in &types {
+                let Some(agg) = country_types.get(&(country_id, row.population_type)) else {
+                    continue;
+                };
+                country_cells.push(CountryPopTypeAttributeCell {
+                    country: country.clone(),
+                    population_type: row.population_type,
+                    population: (agg.population * 1000.0).floor() as u32,
+                    avg_value: agg.avg(),
+                });
+            }
+        }
+
+        locations.sort_by(|a, b| {
+            a.2.avg()
+                .total_cmp(&b.2.avg())
+                .then_with(|| b.2.population.total_cmp(&a.2.population))
+        });
+        let lowest_locations = locations
+            .into_iter()
+            .filter_map(|(idx, owner_id, agg, lowest)| {
+                let cidx = self.gamestate.countries.get(owner_id)?;
+                Some(PopAttributeLocation {
+                    location: idx,
+                    owner: self.country_ref_from_country_idx(cidx),
+                    population: (agg.population * 1000.0).floor() as u32,
+                    avg_value: agg.avg(),
+                    lowest_type: lowest.map(|(kind, _)| kind),
+                    lowest_type_value: lowest.map(|(_, value)| value).unwrap_or(0.0),
+                })
+            })
+            .take(LOWEST_LOCATIONS)
+            .collect();
+
+        PopAttributeInsightData {
+            metric_label: attribute.label().to_string(),
+            scope: PopAttributeScopeSummary {
+                location_count,
+                country_count: country_totals.len() as u32,
+                total_population: (scoped.population * 1000.0).floor() as u32,
+                avg_value: scoped.avg(),
+                baseline_avg_value: baseline.avg(),
+                is_empty,
+            },
+            types,
+            country_cells,
+            lowest_locations,
+        }
+    }
+
+    pub(crate) fn calculate_prosperity_insight(&self) -> ProsperityInsightData {
+        const POOREST_LOCATIONS: usize = 25;
+        const BAND_COUNT: usize = 5;
+        const FACTORS: [&str; 3] = ["Proximity", "Control", "Market Access"];
+
+        struct Sample {
+            idx: eu5save::models::LocationIdx,
+            owner_id: CountryId,
+            prosperity: f64,
+            development: f64,
+            /// Normalized proximity, control, and market access
+            factors: [f64; 3],
+        }
+
+        let is_empty = self.selection_state.is_empty();
+        let mut samples = Vec::new();
+        let mut countries: FxHashSet<CountryId> = FxHashSet::default();
+
+        for entry in self.gamestate.locations.iter() {
+            let idx = entry.idx();
+            if !is_empty && !self.selection_state.contains(idx) {
+                continue;
+            }
+
+            let loc = entry.location();
+            let Some(owner_id) = loc.owner.real_id().map(|r| r.country_id()) else {
+                continue;
+            };
+
+            countries.insert(owner_id);
+            samples.push(Sample {
+                idx,
+                owner_id,
+                prosperity: loc.prosperity,
+                development: loc.development,
+                factors: [loc.proximity / 100.0, loc.control, loc.market_access],
+            });
+        }
+
+        samples.sort_by(|a, b| {
+            a.prosperity
+                .total_cmp(&b.prosperity)
+                .then_with(|| b.development.total_cmp(&a.development))
+        });
+
+        let prosperity: Vec<f64> = samples.iter().map(|s| s.prosperity).collect();
+        let poorest_count = samples.len().div_ceil(4);
+        let (poorest, rest) = samples.split_at(poorest_count);
+        let factors = FACTORS
+            .iter()
+            .enumerate()
+            .map(|(i, &factor)| {
+                let values: Vec<f64> = samples.iter().map(|s| s.factors[i]).collect();
+                ProsperityFactor {
+                    factor: factor.to_string(),
+                    correlation: pearson(&values, &prosperity),
+                    avg_in_poorest: mean(poorest.iter().map(|s| s.factors[i])),
+                    avg_in_rest: mean(rest.iter().map(|s| s.factors[i])),
+                }
+            })
+            .collect();
+
+        let bands = (0..BAND_COUNT)
+            .map(|band| {
+                let lo = band as f64 / BAND_COUNT as f64;
+                let hi = (band + 1) as f64 / BAND_COUNT as f64;
+                let in_band: Vec<&Sample> = samples
+                    .iter()
+                    .filter(|s| s.prosperity >= lo && (s.prosperity < hi || band == BAND_COUNT - 1))
+                    .collect();
+                ProsperityBand {
+                    band: format!("{:.0}–{:.0}%", lo * 100.0, hi * 100.0),
+                    location_count: in_band.len() as u32,
+                    avg_proximity: mean(in_band.iter().map(|s| s.factors[0])),
+                    avg_control: mean(in_band.iter().map(|s| s.factors[1])),
+                    avg_market_access: mean(in_band.iter().map(|s| s.factors[2])),
+                }
+            })
+            .collect();
+
+        let poorest_locations = samples
+            .iter()
+            .filter_map(|sample| {
+                let cidx = self.gamestate.countries.get(sample.owner_id)?;
+                let limiting = sample
+                    .factors
+                    .iter()
+                    .enumerate()
+                    .min_by(|a, b| a.1.total_cmp(b.1))
+                    .map(|(i, _)| FACTORS[i])
+                    .unwrap_or_default();
+                Some(ProsperityLocation {
+                    location: sample.idx,
+                    owner: self.country_ref_from_country_idx(cidx),
+                    prosperity: sample.prosperity,
+                    proximity: sample.factors[0] * 100.0,
+                    control: sample.factors[1],
+                    market_access: sample.factors[2],
+                    development: sample.development,
+                    limiting_factor: limiting.to_string(),
+                })
+            })
+            .take(POOREST_LOCATIONS)
+            .collect();
+
+        ProsperityInsightData {
+            scope: ProsperityScopeSummary {
+                location_count: samples.len() as u32,
+                country_count: countries.len() as u32,
+                avg_prosperity: mean(samples.iter().map(|s| s.prosperity)),
+                avg_proximity: mean(samples.iter().map(|s| s.factors[0] * 100.0)),
+                avg_control: mean(samples.iter().map(|s| s.factors[1])),
+                avg_market_access: mean(samples.iter().map(|s| s.factors[2])),
+                is_empty,
+            },
+            factors,
+            bands,
+            poorest_locations,
+        }
+    }
+
+    pub(crate) fn calculate_wars_insight(&self) -> WarsInsightData {
+        let is_empty = self.selection_state.is_empty();
+        let in_scope =
+            |idx: eu5save::models::LocationIdx| is_empty || self.selection_state.contains(idx);
+
+        let mut location_count = 0_u32;
+        let mut occupied_development = 0.0;
+        let mut occupied_locations = Vec::new();
+        for entry in self.gamestate.locations.iter() {
+            let idx = entry.idx();
+            let loc = entry.location();
+            if !in_scope(idx) || loc.owner.real_id().is_none() {
+                continue;
+            }
+
+            location_count += 1;
+            if !super::map_render::is_occupied(loc) {
+                continue;
+            }
+
+            let Some(owner_idx) = self.gamestate.countries.get(loc.owner) else {
+                continue;
+            };
+            let Some(controller_idx) = self.gamestate.countries.get(loc.controller) else {
+                continue;
+            };
+
+            occupied_development += loc.development;
+            occupied_locations.push(OccupiedLocation {
+                location: idx,
+                owner: self.country_ref_from_country_idx(owner_idx),
+                controller: self.country_ref_from_country_idx(controller_idx),
+                development: loc.development,
+            });
+        }
+        occupied_locations.sort_by(|a, b| {
+            b.development
+                .total_cmp(&a.development)
+                .then_with(|| a.location.cmp(&b.location))
+        });
+
+        let mut battle_count = 0_u32;
+        let mut wars: Vec<WarSummary> = self
+            .gamestate
+            .war_manager
+            .database
+            .iter_with_id()
+            .filter_map(|(war_id, war)| {
+                let mut battles: Vec<_> = war
+                    .battles
+                    .iter()
+                    .filter_map(|battle| {
+                        let idx = self.gamestate.locations.get(battle.location)?;
+                        Some((idx, battle.date))
+                    })
+                    .collect();
+
+                // With a selection, only show wars that were fought in it
+                if !is_empty && !battles.iter().any(|&(idx, _)| in_scope(idx)) {
+                    return None;
+                }
+
+                battle_count += war.battles.len() as u32;
+                battles.sort_by(|a, b| b.1.cmp(&a.1));
+                Some(WarSummary {
+                    war_id: war_id.value(),
+                    start_date: war.start_date.date_fmt().to_string(),
+                    battle_count: war.battles.len() as u32,
+                    battles: battles
+                        .into_iter()
+                        .map(|(location, date)| WarBattle {
+                            location,
+                            date: date.date_fmt().to_string(),
+                        })
+                        .collect(),
+                })
+            })
+            .collect();
+        wars.sort_by(|a, b| {
+            b.battle_count
+                .cmp(&a.battle_count)
+                .then_with(|| a.war_id.cmp(&b.war_id))
+        });
+
+        WarsInsightData {
+            scope: WarsScopeSummary {
+                location_count,
+                occupied_location_count: occupied_locations.len() as u32,
+                occupied_development,
+                war_count: wars.len() as u32,
+                battle_count,
+                is_empty,
+            },
+            wars,
+            occupied_locations,
+        }
+    }
+
     pub(crate) fn calculate_building_levels_insight(&self) -> BuildingLevelsInsightData<'_> {
         use crate::presentation::BuildingKeyRef;
         use eu5save::models::CountryId;
@@ -2348,4 +2926,85 @@ mod tests {
     fn political_world_rows_return_empty_for_empty_input() {
         assert!(political_world_display_rows(Vec::new()).is_empty());
     }
+
+    #[test]
+    fn ranked_cultures_sort_by_population_then_id() {
+        use eu5save::models::CultureId;
+
+        let cultures = FxHashMap::from_iter([
+            (CultureId::new(3), 50.0),
+            (CultureId::new(2), 120.0),
+            (CultureId::new(1), 50.0),
+        ]);
+
+        let ranked = ranked_cultures(&cultures);
+        assert_eq!(
+            ranked,
+            [
+                (CultureId::new(2), 120.0),
+                (CultureId::new(1), 50.0),
+                (CultureId::new(3), 50.0),
+            ]
+        );
+        assert_eq!(
+            super::super::map_render::dominant_culture(&cultures),
+            Some(CultureId::new(2))
+        );
+    }
+
+    #[test]
+    fn dominant_culture_breaks_ties_by_lowest_id() {
+        use eu5save::models::CultureId;
+
+        let cultures = FxHashMap::from_iter([(CultureId::new(4), 10.0), (CultureId::new(2), 10.0)]);
+        assert_eq!(
+            super::super::map_render::dominant_culture(&cultures),
+            Some(CultureId::new(2))
+        );
+        assert_eq!(
+            super::super::map_render::dominant_culture(&FxHashMap::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn pearson_detects_direction_of_correlation() {
+        let xs = [1.0, 2.0, 3.0, 4.0];
+        assert!((pearson(&xs, &[2.0, 4.0, 6.0, 8.0]) - 1.0).abs() < 1e-9);
+        assert!((pearson(&xs, &[8.0, 6.0, 4.0, 2.0]) + 1.0).abs() < 1e-9);
+        assert_eq!(pearson(&xs, &[5.0, 5.0, 5.0, 5.0]), 0.0);
+        assert_eq!(mean(std::iter::empty()), 0.0);
+    }
+
+    #[test]
+    fn pop_type_rows_cover_scoped_types() {
+        let mut tally = PopTypeAttributeTally::default();
+        tally.add(PopulationType::Peasants, 2.0, 0.5, true);
+        tally.add(PopulationType::Peasants, 2.0, 0.1, false);
+        tally.add(PopulationType::Nobles, 0.5, 0.9, true);
+        tally.add(PopulationType::Clergy, 1.0, 0.4, false);
+
+        let rows = tally.rows();
+        assert!(!rows.is_empty());
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(rows[0].population_type, PopulationType::Peasants);
+        assert_eq!(rows[0].population, 2000);
+        assert!((rows[0].avg_value - 0.5).abs() < 1e-9);
+        assert!((rows[0].baseline_avg_value - 0.3).abs() < 1e-9);
+
+        assert_eq!(rows[1].population_type, PopulationType::Nobles);
+        assert_eq!(rows[1].population, 500);
+        assert!((rows[1].avg_value - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn occupation_requires_a_foreign_controller() {
+        use super::super::map_render::is_foreign_controlled;
+
+        let owner = CountryId::new(5);
+        assert!(is_foreign_controlled(owner, CountryId::new(7)));
+        assert!(!is_foreign_controlled(owner, owner));
+        assert!(!is_foreign_controlled(owner, CountryId::default()));
+    }
 }
@@ -461,6 +461,65 @@ impl<'bump> Eu5Workspace<'bump> {
         GpuColor::from(owner_religion.color.0)
     }
 
+    /// Population of each culture in a location. Pops without a culture are
+    /// excluded.
+    pub(crate) fn location_culture_population(
+        &self,
+        location: &eu5save::models::Location<'_>,
+    ) -> FxHashMap<eu5save::models::CultureId, f64> {
+        let mut result = FxHashMap::default();
+        for &pop_id in location.population.pops {
+            let Some(pop) = self.gamestate.population.database.lookup(pop_id) else {
+                continue;
+            };
+            let Some(culture_id) = pop.culture else {
+                continue;
+            };
+            *result.entry(culture_id).or_default() += (pop.size * 1000.0).floor();
+        }
+        result
+    }
+
+    /// The culture with the largest population in a location
+    pub(crate) fn location_dominant_culture(
+        &self,
+        location: &eu5save::models::Location<'_>,
+    ) -> Option<eu5save::models::CultureId> {
+        dominant_culture(&self.location_culture_population(location))
+    }
+
+    /// Pop size weighted average of a pop attribute in a location, or none if
+    /// the location has no pops
+    pub(crate) fn location_pop_average(
+        &self,
+        location: &eu5save::models::Location<'_>,
+        attribute: PopAttribute,
+    ) -> Option<f64> {
+        let mut size = 0.0;
+        let mut weighted = 0.0;
+        for &pop_id in location.population.pops {
+            let Some(pop) = self.gamestate.population.database.lookup(pop_id) else {
+                continue;
+            };
+            size += pop.size;
+            weighted += attribute.value(pop) * pop.size;
+        }
+
+        (size > 0.0).then(|| weighted / size)
+    }
+
+    fn culture_color(&self, culture_id: Option<eu5save::models::CultureId>) -> GpuColor {
+        let Some(culture_id) = culture_id else {
+            return GpuColor::UNOWNED;
+        };
+
+        let Some(culture) = self.gamestate.culture_manager.lookup(culture_id) else {
+            return GpuColor::DEBUG;
+        };
+
+        GpuColor::from(culture.color.0)
+    }
+
     pub(super) fn build_location_arrays(&mut self) {
         for location in self.gamestate.locations.iter() {
             let Some(gpu_index) = self.gpu_indices[location.idx()] else {
@@ -527,6 +586,16 @@ impl<'bump> Eu5Workspace<'bump> {
             MapMode::Wealth => self.apply_wealth_colors(),
             MapMode::UnrealizedTaxBase => self.apply_unrealized_tax_base_colors(),
             MapMode::Religion => self.apply_religion_colors(),
+            MapMode::Culture => self.apply_culture_colors(),
+            MapMode::Literacy => self.apply_pop_attribute_colors(PopAttribute::Literacy),
+            MapMode::Satisfaction => self.apply_pop_attribute_colors(PopAttribute::Satisfaction),
+            MapMode::Prosperity => self.apply_location_value_colors(|loc| loc.prosperity, 1.0),
+            MapMode::Proximity => self.apply_location_value_colors(|loc| loc.proximity, 100.0),
+            MapMode::MarketAccess => self.apply_location_value_colors(|loc| loc.market_access, 1.0),
+            MapMode::MarketAttraction => {
+                self.apply_location_value_colors(|loc| loc.market_attraction, 1.0)
+            }
+            MapMode::Occupation => self.apply_occupation_colors(),
             MapMode::StateEfficacy => self.apply_state_efficacy_colors(),
         };
 
@@ -595,6 +664,62 @@ impl<'bump> Eu5Workspace<'bump> {
         gradient::MapLegend::Qualitative
     }
 
+    fn apply_occupation_colors(&mut self) -> gradient::MapLegend {
+        // Unoccupied land is muted so that occupied locations (owner striped
+        // with controller) and battle sites (striped red) stand out.
+        const MUTE: f32 = 0.65;
+        let battle_color = GpuColor::from_rgb(200, 30, 30);
+
+        let battle_sites: FxHashSet<eu5save::models::LocationIdx> = self
+            .gamestate
+            .war_manager
+            .database
+            .iter()
+            .flat_map(|war| war.battles.iter())
+            .filter_map(|battle| self.gamestate.locations.get(battle.location))
+            .collect();
+
+        // Collect color data first to avoid borrow conflicts
+        let mut color_data = Vec::new();
+        for entry in self.gamestate.locations.iter() {
+            let location_idx = entry.idx();
+            let location = entry.location();
+            let terrain = self.location_terrain(location_idx);
+            let colors = if terrain.is_water() {
+                (GpuColor::WATER, GpuColor::WATER)
+            } else if !terrain.is_passable() {
+                (GpuColor::IMPASSABLE, GpuColor::IMPASSABLE)
+            } else if location.owner.is_dummy() {
+                (GpuColor::UNOWNED, GpuColor::UNOWNED)
+            } else {
+                let owner = self.location_political_color(location_idx);
+                if is_occupied(location) {
+                    (owner, self.location_control_color(location_idx))
+                } else {
+                    let muted = owner.blend(GpuColor::UNOWNED, MUTE);
+                    if battle_sites.contains(&location_idx) {
+                        (muted, battle_color)
+                    } else {
+                        (muted, muted)
+                    }
+                }
+            };
+            color_data.push((location_idx, colors));
+        }
+
+        // Apply colors
+        for (location_idx, (primary, secondary)) in color_data {
+            let Some(gpu_index) = self.gpu_indices[location_idx] else {
+                continue;
+            };
+            let mut gpu_location = self.location_arrays.get_mut(gpu_index);
+            gpu_location.set_primary_color(primary);
+            gpu_location.set_secondary_color(secondary);
+        }
+
+        gradient::MapLegend::Qualitative
+    }
+
     fn apply_control_colors(&mut self) -> gradient::MapLegend {
         // Collect color data first to avoid borrow conflicts
         let mut color_data = Vec::new();
@@ -924,7 +1049,160 @@ impl<'bump> Eu5Workspace<'bump> {
         gradient::MapLegend::Qualitative
     }
 
+    /// Colors owned locations on a fixed 0..max domain
+    fn apply_location_value_colors(
+        &mut self,
+        value: impl Fn(&eu5save::models::Location<'_>) -> f64,
+        max: f64,
+    ) -> gradient::MapLegend {
+        // Collect color data first to avoid borrow conflicts
+        let mut color_data = Vec::new();
+        for entry in self.gamestate.locations.iter() {
+            let location_idx = entry.idx();
+            let terrain = self.location_terrain(location_idx);
+            let color = if terrain.is_water() {
+                GpuColor::WATER
+            } else if !terrain.is_passable() {
+                GpuColor::IMPASSABLE
+            } else if entry.location().owner.is_dummy() {
+                GpuColor::UNOWNED
+            } else {
+                gradient::interpolate_eu5_gradient(
+                    value(entry.location()),
+                    max,
+                    GradientScale::Linear,
+                )
+            };
+            color_data.push((location_idx, color));
+        }
+
+        // Apply colors
+        for (location_idx, color) in color_data {
+            let Some(gpu_index) = self.gpu_indices[location_idx] else {
+                continue;
+            };
+            let mut gpu_location = self.location_arrays.get_mut(gpu_index);
+            gpu_location.set_primary_color(color);
+        }
+
+        // Copy primary colors to secondary to disable stripes
+        self.location_arrays.copy_primary_to_secondary();
+
+        gradient::MapLegend::Quantitative(gradient::sequential(GradientScale::Linear, 0.0, max))
+    }
+
+    fn apply_pop_attribute_colors(&mut self, attribute: PopAttribute) -> gradient::MapLegend {
+        // Collect color data first to avoid borrow conflicts
+        let mut color_data = Vec::new();
+        for entry in self.gamestate.locations.iter() {
+            let location_idx = entry.idx();
+            let terrain = self.location_terrain(location_idx);
+            let color = if terrain.is_water() {
+                GpuColor::WATER
+            } else if !terrain.is_passable() {
+                GpuColor::IMPASSABLE
+            } else if entry.location().owner.is_dummy() {
+                GpuColor::UNOWNED
+            } else {
+                self.location_pop_average(entry.location(), attribute)
+                    .map(|value| {
+                        gradient::interpolate_eu5_gradient(value, 1.0, GradientScale::Linear)
+                    })
+                    .unwrap_or(GpuColor::UNOWNED)
+            };
+            color_data.push((location_idx, color));
+        }
+
+        // Apply colors
+        for (location_idx, color) in color_data {
+            let Some(gpu_index) = self.gpu_indices[location_idx] else {
+                continue;
+            };
+            let mut gpu_location = self.location_arrays.get_mut(gpu_index);
+            gpu_location.set_primary_color(color);
+        }
+
+        // Copy primary colors to secondary to disable stripes
+        self.location_arrays.copy_primary_to_secondary();
+
+        gradient::MapLegend::Quantitative(gradient::sequential(GradientScale::Linear, 0.0, 1.0))
+    }
+
+    fn apply_culture_colors(&mut self) -> gradient::MapLegend {
+        // Each location is colored by its dominant culture and striped with the
+        // dominant culture of its owner's realm
+        let mut location_cultures = Vec::with_capacity(self.gamestate.locations.len());
+        let mut owner_cultures: FxHashMap<CountryId, FxHashMap<eu5save::models::CultureId, f64>> =
+            FxHashMap::default();
+        for entry in self.gamestate.locations.iter() {
+            let location = entry.location();
+            let cultures = self.location_culture_population(location);
+            location_cultures.push((entry.idx(), dominant_culture(&cultures)));
+
+            if let Some(owner_id) = location.owner.real_id().map(|x| x.country_id()) {
+                let owner = owner_cultures.entry(owner_id).or_default();
+                for (culture_id, population) in cultures {
+                    *owner.entry(culture_id).or_default() += population;
+                }
+            }
+        }
+
+        let owner_dominant: FxHashMap<CountryId, eu5save::models::CultureId> = owner_cultures
+            .iter()
+            .filter_map(|(owner_id, cultures)| Some((*owner_id, dominant_culture(cultures)?)))
+            .collect();
+
+        for (location_idx, culture_id) in location_cultures {
+            let terrain = self.location_terrain(location_idx);
+            let (primary, secondary) = if terrain.is_water() {
+                (GpuColor::WATER, GpuColor::WATER)
+            } else if !terrain.is_passable() {
+                (GpuColor::IMPASSABLE, GpuColor::IMPASSABLE)
+            } else {
+                let location = self.gamestate.locations.index(location_idx).location();
+                let owner_culture = location
+                    .owner
+                    .real_id()
+                    .and_then(|x| owner_dominant.get(&x.country_id()).copied())
+                    .or(culture_id);
+                (
+                    self.culture_color(culture_id),
+                    self.culture_color(owner_culture),
+                )
+            };
+
+            let Some(gpu_index) = self.gpu_indices[location_idx] else {
+                continue;
+            };
+            let mut gpu_location = self.location_arrays.get_mut(gpu_index);
+            gpu_location.set_primary_color(primary);
+            gpu_location.set_secondary_color(secondary);
+        }
+
+        gradient::MapLegend::Qualitative
+    }
+
     pub fn get_map_mode(&self) -> MapMode {
         self.current_map_mode
     }
 }
+
+/// A location is occupied when it is controlled by a country other than its owner
+pub(crate) fn is_occupied(location: &eu5save::models::Location<'_>) -> bool {
+    is_foreign_controlled(location.owner, location.controller)
+}
+
+/// Whether a real country other than the owner controls the location
+pub(crate) fn is_foreign_controlled(owner: CountryId, controller: CountryId) -> bool {
+    controller.real_id().is_some() && controller != owner
+}
+
+/// The culture with the largest population, ties broken by the lowest id
+pub(crate) fn dominant_culture(
+    cultures: &FxHashMap<eu5save::models::CultureId, f64>,
+) -> Option<eu5save::models::CultureId> {
+    cultures
+        .iter()
+        .max_by(|a, b| a.1.total_cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(culture_id, _)| *culture_id)
+}
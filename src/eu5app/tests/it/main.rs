@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use eu5app::{Eu5Workspace, MapMode};
 use eu5save::models::LocationIdx;
 
-const ALL_MAP_MODES: [MapMode; 11] = [
+const ALL_MAP_MODES: [MapMode; 19] = [
     MapMode::Political,
     MapMode::Control,
     MapMode::Development,
@@ -16,6 +16,14 @@ const ALL_MAP_MODES: [MapMode; 11] = [
     MapMode::Wealth,
     MapMode::UnrealizedTaxBase,
     MapMode::Religion,
+    MapMode::Culture,
+    MapMode::Literacy,
+    MapMode::Satisfaction,
+    MapMode::Prosperity,
+    MapMode::Proximity,
+    MapMode::MarketAccess,
+    MapMode::MarketAttraction,
+    MapMode::Occupation,
     MapMode::StateEfficacy,
 ];
 
@@ -31,6 +39,14 @@ fn mode_name(mode: MapMode) -> &'static str {
         MapMode::Wealth => "wealth",
         MapMode::UnrealizedTaxBase => "unrealized_tax_base",
         MapMode::Religion => "religion",
+        MapMode::Culture => "culture",
+        MapMode::Literacy => "literacy",
+        MapMode::Satisfaction => "satisfaction",
+        MapMode::Prosperity => "prosperity",
+        MapMode::Proximity => "proximity",
+        MapMode::MarketAccess => "market_access",
+        MapMode::MarketAttraction => "market_attraction",
+        MapMode::Occupation => "occupation",
         MapMode::StateEfficacy => "state_efficacy",
     }
 }
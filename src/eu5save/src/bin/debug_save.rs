@@ -162,6 +162,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .map(|w| w.battles.len())
             .sum::<usize>()
     );
+    println!(
+        "total battle losses: {}",
+        save.war_manager
+            .database
+            .iter()
+            .flat_map(|w| w.battles.iter())
+            .map(|b| b.attacker.losses + b.defender.losses)
+            .sum::<f64>()
+    );
     println!(
         "number of units: {}",
         save.unit_manager.database.iter().count()
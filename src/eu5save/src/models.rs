@@ -87,6 +87,10 @@ pub struct Gamestate<'bump> {
     pub trade_manager: TradeManager<'bump>,
     pub religion_manager: ReligionManager<'bump>,
     pub culture_manager: CultureManager<'bump>,
+
+    /// True when the save was played in ironman mode
+    #[arena(default, deserialize_with = "deserialize_present")]
+    pub ironman: bool,
 }
 
 #[derive(Debug, ArenaDeserialize)]
@@ -194,6 +198,18 @@ impl<'bump, Of: ArenaDeserialize<'bump> + 'bump> ArenaDeserialize<'bump>
     }
 }
 
+/// Deserializes any value as true, marking that the field exists
+fn deserialize_present<'de, 'bump, D>(
+    deserializer: D,
+    _allocator: &'bump bumpalo::Bump,
+) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    <serde::de::IgnoredAny as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(true)
+}
+
 impl<'bump> Gamestate<'bump> {
     /// Finds the maximum population across all locations in the gamestate.
     pub fn location_max_population(&self) -> (f64, LocationId) {
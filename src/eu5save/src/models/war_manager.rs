@@ -1,6 +1,6 @@
 use crate::{
     Eu5Date,
-    models::{LocationId, de::Maybe},
+    models::{CharacterId, CountryId, LocationId, bstr::BStr, de::Maybe},
 };
 use bumpalo_serde::{ArenaDeserialize, ArenaSeed};
 use serde::{Deserialize, de};
@@ -12,7 +12,21 @@ pub struct WarManager<'bump> {
     pub database: WarDatabase<'bump>,
 }
 
-impl<'bump> WarManager<'bump> {}
+impl<'bump> WarManager<'bump> {
+    pub fn get(&self, id: WarId) -> Option<&War<'bump>> {
+        if let Some(x) = self.database.ids.get(id.value() as usize)
+            && *x == id
+        {
+            return self.database.values[id.value() as usize].as_ref();
+        }
+
+        self.database
+            .ids
+            .iter()
+            .position(|&x| x == id)
+            .and_then(|idx| self.database.values[idx].as_ref())
+    }
+}
 
 #[derive(Debug, PartialEq)]
 pub struct WarDatabase<'bump> {
@@ -25,6 +39,14 @@ impl<'bump> WarDatabase<'bump> {
     pub fn iter(&self) -> impl Iterator<Item = &War<'bump>> {
         self.values.iter().filter_map(|x| x.as_ref())
     }
+
+    /// Returns an iterator over all wars in the database alongside their ids
+    pub fn iter_with_id(&self) -> impl Iterator<Item = (WarId, &War<'bump>)> {
+        self.ids
+            .iter()
+            .zip(self.values.iter())
+            .filter_map(|(&id, v)| v.as_ref().map(|w| (id, w)))
+    }
 }
 
 #[derive(
@@ -48,14 +70,138 @@ impl WarId {
 #[derive(Debug, ArenaDeserialize, PartialEq)]
 pub struct War<'bump> {
     pub start_date: Eu5Date,
+    #[arena(default)]
+    pub original_attacker: CountryId,
+    #[arena(default)]
+    pub original_attacker_target: CountryId,
+    #[arena(default)]
+    pub original_defender: CountryId,
+    #[arena(default, alias = "all")]
+    pub participants: &'bump [WarParticipant],
+    #[arena(duplicated, alias = "war_goal")]
+    pub war_goals: &'bump [WarGoal<'bump>],
+    pub attacker_score: Option<f64>,
+    pub defender_score: Option<f64>,
     #[arena(duplicated, alias = "battle")]
     pub battles: &'bump [Battle],
 }
 
+impl War<'_> {
+    /// Countries fighting on the attacking side, including those that left
+    pub fn attackers(&self) -> impl Iterator<Item = &WarParticipant> {
+        self.participants
+            .iter()
+            .filter(|x| x.side == WarSide::Attacker)
+    }
+
+    /// Countries fighting on the defending side, including those that left
+    pub fn defenders(&self) -> impl Iterator<Item = &WarParticipant> {
+        self.participants
+            .iter()
+            .filter(|x| x.side == WarSide::Defender)
+    }
+
+    /// War score from the attacker's point of view: positive when the
+    /// attackers are winning. `None` when the save doesn't record either
+    /// side's score.
+    pub fn war_score(&self) -> Option<f64> {
+        match (self.attacker_score, self.defender_score) {
+            (None, None) => None,
+            (attacker, defender) => Some(attacker.unwrap_or(0.0) - defender.unwrap_or(0.0)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, ArenaDeserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarSide {
+    #[serde(alias = "Attacker")]
+    Attacker,
+    #[serde(alias = "Defender")]
+    Defender,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, ArenaDeserialize, PartialEq)]
+pub struct WarParticipant {
+    #[arena(default)]
+    pub country: CountryId,
+    #[arena(default)]
+    pub side: WarSide,
+    pub joined: Option<Eu5Date>,
+    pub left: Option<Eu5Date>,
+}
+
+#[derive(Debug, ArenaDeserialize, PartialEq)]
+pub struct WarGoal<'bump> {
+    #[arena(alias = "type")]
+    pub kind: Option<BStr<'bump>>,
+    pub casus_belli: Option<BStr<'bump>>,
+    #[arena(default)]
+    pub target: CountryId,
+}
+
 #[derive(Debug, ArenaDeserialize, PartialEq)]
 pub struct Battle {
     pub location: LocationId,
     pub date: Eu5Date,
+    #[arena(default)]
+    pub attacker: BattleSide,
+    #[arena(default)]
+    pub defender: BattleSide,
+    /// True when the attacking side won the battle
+    pub result: Option<bool>,
+}
+
+impl Battle {
+    /// The side that won the battle, or `None` when the save doesn't record
+    /// a result
+    pub fn winner(&self) -> Option<WarSide> {
+        self.result.map(|attacker_won| {
+            if attacker_won {
+                WarSide::Attacker
+            } else {
+                WarSide::Defender
+            }
+        })
+    }
+
+    pub fn side(&self, side: WarSide) -> Option<&BattleSide> {
+        match side {
+            WarSide::Attacker => Some(&self.attacker),
+            WarSide::Defender => Some(&self.defender),
+            WarSide::Unknown => None,
+        }
+    }
+}
+
+/// One side of a battle. Unit counts and losses are in regiment strength as
+/// recorded by the save.
+#[derive(Debug, Default, ArenaDeserialize, PartialEq)]
+pub struct BattleSide {
+    #[arena(default)]
+    pub country: CountryId,
+    #[arena(alias = "character")]
+    pub commander: Option<CharacterId>,
+    #[arena(default)]
+    pub infantry: f64,
+    #[arena(default)]
+    pub cavalry: f64,
+    #[arena(default)]
+    pub artillery: f64,
+    #[arena(default)]
+    pub auxiliary: f64,
+    #[arena(default)]
+    pub losses: f64,
+}
+
+impl BattleSide {
+    /// Total strength that entered the battle
+    pub fn total(&self) -> f64 {
+        self.infantry + self.cavalry + self.artillery + self.auxiliary
+    }
 }
 
 #[inline]
@@ -97,3 +243,83 @@ where
 
     deserializer.deserialize_map(WarVisitor(allocator))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jomini::TextDeserializer;
+
+    #[test]
+    fn war_without_scores_has_no_war_score() {
+        let data = r#"
+start_date=1340.5.1
+battle={
+    location=1
+    date=1340.7.1
+    result=no
+}
+"#;
+        let allocator = bumpalo::Bump::new();
+        let d = TextDeserializer::from_utf8_slice(data.as_bytes())
+            .expect("Failed to create deserializer");
+        let war = War::deserialize_in_arena(&d, &allocator).expect("war deserializes");
+
+        assert_eq!(war.war_score(), None);
+        assert_eq!(war.battles[0].winner(), Some(WarSide::Defender));
+    }
+
+    #[test]
+    fn war_deserializes_participants_goals_and_battles() {
+        let data = r#"
+start_date=1340.5.1
+original_attacker=12
+original_attacker_target=34
+original_defender=34
+all={
+    { country=12 side=attacker joined=1340.5.1 }
+    { country=34 side=defender joined=1340.5.1 }
+    { country=56 side=defender joined=1340.6.1 left=1341.1.1 }
+}
+war_goal={
+    type=take_location
+    casus_belli=cb_claim
+    target=34
+}
+attacker_score=25.5
+defender_score=4
+battle={
+    location=1
+    date=1340.7.1
+    attacker={ country=12 character=7 infantry=8000 cavalry=2000 losses=1500 }
+    defender={ country=34 infantry=6000 artillery=500 losses=3000 }
+    result=yes
+}
+battle={
+    location=2
+    date=1340.9.1
+}
+"#;
+        let allocator = bumpalo::Bump::new();
+        let d = TextDeserializer::from_utf8_slice(data.as_bytes())
+            .expect("Failed to create deserializer");
+        let war = War::deserialize_in_arena(&d, &allocator).expect("war deserializes");
+
+        assert_eq!(war.original_attacker, CountryId::new(12));
+        assert_eq!(war.original_defender, CountryId::new(34));
+        assert_eq!(war.attackers().count(), 1);
+        assert_eq!(war.defenders().count(), 2);
+        assert!(war.participants[2].left.is_some());
+        assert_eq!(war.war_goals.len(), 1);
+        assert_eq!(war.war_goals[0].target, CountryId::new(34));
+        assert_eq!(war.war_score(), Some(21.5));
+
+        assert_eq!(war.battles.len(), 2);
+        let battle = &war.battles[0];
+        assert_eq!(battle.winner(), Some(WarSide::Attacker));
+        assert_eq!(battle.attacker.commander, Some(CharacterId::new(7)));
+        assert_eq!(battle.attacker.total(), 10000.0);
+        assert_eq!(battle.defender.losses, 3000.0);
+        assert_eq!(war.battles[1].winner(), None);
+        assert_eq!(war.battles[1].attacker.total(), 0.0);
+    }
+}
@@ -339,3 +339,67 @@ fn deserialization_regression_test(#[case] filename: &str) {
     can_deserialize_meta(&save);
     can_deserialize_gamestate(&save);
 }
+
+#[test]
+fn war_model_regression_test() {
+    let resolver = &*TOKENS;
+    let mut battle_count = 0;
+    for filename in ["debug-1.0.eu5", "Clandeboye.eu5", "mp_cas_1374_03_06.eu5"] {
+        let file = utils::request_file(filename);
+        let save = Eu5File::from_file(file).unwrap();
+        let bump = bumpalo::Bump::new();
+        let gamestate = match save.gamestate().unwrap() {
+            eu5save::SaveContentKind::Text(mut txt) => {
+                Gamestate::deserialize_in_arena(&mut txt.deserializer(), &bump)
+            }
+            eu5save::SaveContentKind::Binary(mut bin) => {
+                // Skip deserialization if we don't have tokens
+                if resolver.is_empty() {
+                    continue;
+                }
+                let save_resolver = SaveResolver::from_file(&save, resolver)
+                    .expect("failed to create save resolver");
+                Gamestate::deserialize_in_arena(&mut bin.deserializer(&save_resolver), &bump)
+            }
+        }
+        .expect("failed to deserialize gamestate");
+
+        for (war_id, war) in gamestate.war_manager.database.iter_with_id() {
+            let war_id = war_id.value();
+            assert!(
+                war.attackers().next().is_some(),
+                "{filename}: war {war_id} has no attackers"
+            );
+            assert!(
+                war.defenders().next().is_some(),
+                "{filename}: war {war_id} has no defenders"
+            );
+            assert!(
+                war.war_score().is_some(),
+                "{filename}: war {war_id} has no war score"
+            );
+
+            for battle in war.battles {
+                battle_count += 1;
+                assert!(
+                    battle.winner().is_some(),
+                    "{filename}: battle in war {war_id} has no result"
+                );
+                assert!(
+                    battle.attacker.country.real_id().is_some(),
+                    "{filename}: battle in war {war_id} has no attacking country"
+                );
+                assert!(
+                    battle.defender.country.real_id().is_some(),
+                    "{filename}: battle in war {war_id} has no defending country"
+                );
+            }
+        }
+    }
+
+    // Without tokens only the text save is checked, which may not have
+    // any battles
+    if !resolver.is_empty() {
+        assert!(battle_count > 0, "expected battles in the regression saves");
+    }
+}
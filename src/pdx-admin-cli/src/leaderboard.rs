@@ -0,0 +1,157 @@
+use anyhow::{Context, bail};
+use applib::{
+    leaderboard::{LeaderboardSave, compute_leaderboards},
+    parser::ParseResult,
+};
+use clap::Args;
+use csv::Reader;
+use rayon::prelude::*;
+use serde::{Deserialize, Deserializer, de};
+use std::{collections::HashMap, fmt, io, io::Read, path::PathBuf, process::ExitCode};
+use walkdir::WalkDir;
+
+/// Computes the achievement leaderboards offline from reparsed saves
+#[derive(Args)]
+pub struct LeaderboardArgs {
+    /// Path to database export (csv) with the upload date and leaderboard
+    /// qualification of each save
+    #[arg(long)]
+    reference: PathBuf,
+
+    /// Files and directories to parse
+    #[arg(action = clap::ArgAction::Append)]
+    files: Vec<PathBuf>,
+}
+
+impl LeaderboardArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let rdr = csv::Reader::from_path(&self.reference)
+            .with_context(|| format!("unable to open: {}", self.reference.display()))?;
+        let records = extract_leaderboard_records(rdr)?;
+
+        let paths: Vec<_> = self
+            .files
+            .iter()
+            .flat_map(|fp| WalkDir::new(fp).into_iter().filter_map(|e| e.ok()))
+            .filter(|e| e.file_type().is_file())
+            .filter(|e| e.path().extension().is_none())
+            .map(|e| e.into_path())
+            .collect();
+
+        let mut saves = paths
+            .par_iter()
+            .map(|path| {
+                let Some(save_id) = path.file_name().and_then(|x| x.to_str()) else {
+                    bail!("bad file name: {}", path.display());
+                };
+
+                let Some(record) = records.get(save_id) else {
+                    tracing::warn!("save not found in reference: {save_id}");
+                    return Ok(None);
+                };
+
+                let data = std::fs::read(path)
+                    .with_context(|| format!("failed to read: {}", path.display()))?;
+                let save = applib::parser::parse_save_data(&data)
+                    .with_context(|| format!("save: {save_id}"))?;
+
+                let file = match save {
+                    ParseResult::InvalidPatch(_) => bail!("unable parse patch"),
+                    ParseResult::Parsed(x) => *x,
+                    _ => bail!("not an eu4 save"),
+                };
+
+                let save = LeaderboardSave {
+                    save_id: String::from(save_id),
+                    file,
+                    leaderboard_qualified: record.leaderboard_qualified,
+                };
+                Ok(Some((record.created_on.as_str(), save)))
+            })
+            .filter_map(|entry| entry.transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Leaderboards break ties by upload order
+        saves.sort_by_key(|(created_on, _)| *created_on);
+        let saves: Vec<_> = saves.into_iter().map(|(_, save)| save).collect();
+
+        let leaderboards = compute_leaderboards(&saves);
+        let stdout = io::stdout();
+        let mut locked = stdout.lock();
+        serde_json::to_writer(&mut locked, &leaderboards)?;
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaderboardRecord {
+    id: String,
+
+    /// Postgres timestamps with the same time zone sort chronologically
+    created_on: String,
+    #[serde(deserialize_with = "deserialize_postgres_bool")]
+    leaderboard_qualified: bool,
+}
+
+fn deserialize_postgres_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct PostgresBoolVisitor;
+
+    impl de::Visitor<'_> for PostgresBoolVisitor {
+        type Value = bool;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("postgres boolean")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match v {
+                "t" | "true" => Ok(true),
+                "f" | "false" => Ok(false),
+                _ => Err(de::Error::custom("unrecognized boolean")),
+            }
+        }
+    }
+
+    deserializer.deserialize_str(PostgresBoolVisitor)
+}
+
+fn extract_leaderboard_records<T: Read>(
+    mut rdr: Reader<T>,
+) -> anyhow::Result<HashMap<String, LeaderboardRecord>> {
+    let mut records = HashMap::new();
+    let mut raw_record = csv::StringRecord::new();
+    let headers = rdr.headers().context("unable to get csv header")?.clone();
+    while rdr.read_record(&mut raw_record)? {
+        let record: LeaderboardRecord = raw_record.deserialize(Some(&headers))?;
+        records.insert(record.id.clone(), record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_extract_leaderboard_records() {
+        let data = "id,created_on,filename,leaderboard_qualified,score_days
+a,2021-11-25 22:02:26.501926+00,a.eu4,t,100
+b,2020-05-25 12:20:56.962656+00,b.eu4,f,200
+";
+
+        let rdr = csv::Reader::from_reader(Cursor::new(data));
+        let records = extract_leaderboard_records(rdr).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records["a"].leaderboard_qualified);
+        assert!(!records["b"].leaderboard_qualified);
+        assert!(records["b"].created_on < records["a"].created_on);
+    }
+}
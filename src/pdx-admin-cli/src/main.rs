@@ -4,6 +4,7 @@
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+mod leaderboard;
 mod og;
 mod reprocess;
 mod transcode;
@@ -26,6 +27,8 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Computes the achievement leaderboards offline from reparsed saves
+    Leaderboard(leaderboard::LeaderboardArgs),
     /// Regenerate EU4 OG preview images from local save files
     Og(og::OgArgs),
     /// Produces a delta to apply to database from reparsed saves
@@ -53,6 +56,7 @@ fn main() -> ExitCode {
         .init();
 
     let exit_code = match &cli.command {
+        Commands::Leaderboard(x) => x.run(),
         Commands::Og(x) => x.run(),
         Commands::Reprocess(x) => x.run(),
         Commands::Transcode(x) => x.run(),
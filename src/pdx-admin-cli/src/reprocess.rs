@@ -118,6 +118,7 @@ impl ReprocessArgs {
                         let save = match save {
                             ParseResult::InvalidPatch(_) => bail!("unable parse patch"),
                             ParseResult::Parsed(x) => *x,
+                            _ => bail!("not an eu4 save"),
                         };
 
                         match existing_records.get(&save_id) {
@@ -1,10 +1,6 @@
 use anyhow::Context;
 use clap::Args;
-use std::{
-    io::{Cursor, Read, Seek},
-    path::PathBuf,
-    process::ExitCode,
-};
+use std::{path::PathBuf, process::ExitCode};
 use walkdir::WalkDir;
 
 /// Re-encode save container format
@@ -26,91 +22,29 @@ impl TranscodeArgs {
             .flat_map(|fp| WalkDir::new(fp).into_iter().filter_map(|e| e.ok()))
             .filter(|e| e.file_type().is_file());
 
-        let mut buf = vec![0u8; rawzip::RECOMMENDED_BUFFER_SIZE];
         for file in files {
             let path = file.path();
-            let file = std::fs::File::open(path)
-                .with_context(|| format!("unable to open: {}", path.display()))?;
+            let data = std::fs::read(path)
+                .with_context(|| format!("unable to read: {}", path.display()))?;
+            let file_length = data.len();
 
-            let file_length = file
-                .metadata()
-                .with_context(|| format!("unable to get metadata: {}", path.display()))?
-                .len();
+            let transcoded = applib::transcode::transcode(data)
+                .with_context(|| format!("unable to transcode: {}", path.display()))?;
 
-            let locator = rawzip::ZipLocator::new().max_search_space(1024);
-            let data = match locator.locate_in_file(file, &mut buf) {
-                Ok(zip) => {
-                    let mut is_encoded = true;
-                    let mut files = Vec::new();
-                    let mut entries = zip.entries(&mut buf);
-                    while let Some(entry) = entries.next_entry()? {
-                        let file_path = entry.file_path();
-                        if !matches!(file_path.as_ref(), b"meta" | b"gamestate" | b"ai") {
-                            continue;
-                        }
-
-                        is_encoded &= entry.compression_method() == rawzip::CompressionMethod::ZSTD;
-                        files.push((String::from(file_path.try_normalize()?), entry.wayfinder()));
-                    }
-
-                    if is_encoded {
-                        println!("{} already zstd", path.display());
-                        continue;
-                    }
-
-                    let out = Vec::with_capacity(file_length as usize);
-                    let cursor = Cursor::new(out);
-                    let mut out_zip = rawzip::ZipArchiveWriter::new(cursor);
-
-                    for (name, wayfinder) in files {
-                        let (mut out_file, config) = out_zip
-                            .new_file(&name)
-                            .compression_method(rawzip::CompressionMethod::ZSTD)
-                            .start()?;
-                        let enc = pdx_zstd::Encoder::new(&mut out_file, 7)?;
-                        let mut writer = config.wrap(enc);
-                        let entry = zip.get_entry(wayfinder)?;
-                        let reader = flate2::read::DeflateDecoder::new(entry.reader());
-                        let mut reader = entry.verifying_reader(reader);
-                        std::io::copy(&mut reader, &mut writer)?;
-                        let (writer, output) = writer.finish()?;
-                        writer.finish()?;
-                        out_file.finish(output)?;
-                    }
-
-                    out_zip.finish()?.into_inner()
-                }
-                Err((mut file, _)) => {
-                    let mut header = [0u8; 4];
-                    file.seek(std::io::SeekFrom::Start(0))
-                        .with_context(|| format!("unable to seek: {}", path.display()))?;
-                    file.read_exact(&mut header)
-                        .with_context(|| format!("unable to read header: {}", path.display()))?;
-
-                    if pdx_zstd::is_zstd_compressed(&header) {
-                        println!("{} already zstd", path.display());
-                        continue;
-                    }
-
-                    file.seek(std::io::SeekFrom::Start(0))
-                        .with_context(|| format!("unable to seek: {}", path.display()))?;
-
-                    let out = Vec::with_capacity(file_length as usize);
-                    let mut cursor = Cursor::new(out);
-                    let mut encoder = pdx_zstd::Encoder::new(&mut cursor, 7)?;
-                    std::io::copy(&mut file, &mut encoder)
-                        .with_context(|| format!("unable to copy: {}", path.display()))?;
-                    encoder
-                        .finish()
-                        .with_context(|| format!("unable to finish: {}", path.display()))?;
-                    cursor.into_inner()
-                }
-            };
+            if !transcoded.reencoded {
+                println!("{} already zstd", path.display());
+                continue;
+            }
 
             let out_path = self.dest.join(path.file_name().unwrap());
-            std::fs::write(&out_path, &data)
+            std::fs::write(&out_path, &transcoded.data)
                 .with_context(|| format!("unable to write to {}", out_path.display()))?;
-            println!("{} {}/{}", out_path.display(), file_length, data.len());
+            println!(
+                "{} {}/{}",
+                out_path.display(),
+                file_length,
+                transcoded.data.len()
+            );
         }
 
         Ok(ExitCode::SUCCESS)
@@ -37,6 +37,7 @@ struct GameData<'a> {
     terrain: &'a [(schemas::eu4::Terrain, f32)],
     provinces: &'a [GameProvince],
     center_locations: &'a HashMap<u16, (u16, u16)>,
+    adjacencies: &'a HashMap<u16, Vec<u16>>,
     localization: &'a HashMap<String, String>,
     religions: &'a [religion::Religion],
     religious_rebels: &'a HashMap<String, religion::ReligiousRebels>,
@@ -64,6 +65,7 @@ where
     let advs = generate_advisors(fs, imaging, &localization, out_dir, options)?;
     let center_locations = translate_map(fs, imaging, out_dir, options)?;
     let (total_provs, provs) = generate_provinces(fs, game_version, &center_locations)?;
+    let adjacencies = generate_adjacencies(fs)?;
     let terrain = generate_terrain(fs)?;
 
     // Generate map data
@@ -103,6 +105,7 @@ where
             terrain: &terrain,
             provinces: &provs,
             center_locations: &center_locations,
+            adjacencies: &adjacencies,
             localization: &localization,
             religions: &religions_data,
             religious_rebels: &religious_rebels,
@@ -1098,6 +1101,42 @@ fn process_province_definitions<P: FileProvider + ?Sized>(
     Ok(center_locations)
 }
 
+fn generate_adjacencies<P: FileProvider + ?Sized>(
+    fs: &P,
+) -> anyhow::Result<HashMap<u16, Vec<u16>>> {
+    let definitions = fs.read_file("map/definition.csv")?;
+    let definitions = map::parse_definition(&definitions);
+    let definitions: HashMap<_, _> = definitions.into_iter().map(|(id, rgb)| (rgb, id)).collect();
+
+    let provinces_file_data = fs.read_file("map/provinces.bmp")?;
+    let provinces_bmp =
+        rawbmp::Bmp::parse(provinces_file_data.as_slice()).context("unable to parse bmp")?;
+    let Pixels::Rgb(pixs) = provinces_bmp.pixels();
+    let width = provinces_bmp.dib_header.width.unsigned_abs() as usize;
+    let pixels = pixs
+        .map(|pix| definitions.get(&pix).copied().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    let mut adjacencies = map::province_adjacencies(&pixels, width);
+    adjacencies.remove(&0);
+    for neighbors in adjacencies.values_mut() {
+        neighbors.remove(&0);
+    }
+
+    let straits = fs.read_file("map/adjacencies.csv")?;
+    for (from, to) in map::parse_adjacencies(&straits) {
+        adjacencies.entry(from).or_default().insert(to);
+        adjacencies.entry(to).or_default().insert(from);
+    }
+
+    let result = adjacencies
+        .into_iter()
+        .map(|(id, neighbors)| (id, neighbors.into_iter().collect()))
+        .collect();
+
+    Ok(result)
+}
+
 fn generate_province_definition_binaries<'a, P>(
     fs: &P,
     definitions: impl Iterator<Item = (&'a u16, &'a Rgb)>,
@@ -1398,6 +1437,11 @@ fn generate_output_files(out_game_dir: &Path, game_data: &GameData) -> anyhow::R
             .get(&province.id.as_u16())
             .ok_or_else(|| anyhow::anyhow!("province not found in map: {}", &province.id))?;
 
+        let neighbors = game_data
+            .adjacencies
+            .get(&province.id.as_u16())
+            .map(|x| buffer.create_vector(x));
+
         let entry = schemas::eu4::Province::create(
             &mut buffer,
             &schemas::eu4::ProvinceArgs {
@@ -1406,6 +1450,7 @@ fn generate_output_files(out_game_dir: &Path, game_data: &GameData) -> anyhow::R
                 province_is_on_an_island: province.province_is_on_an_island,
                 center_x,
                 center_y,
+                neighbors,
             },
         );
         provinces.push(entry);
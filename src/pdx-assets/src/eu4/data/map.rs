@@ -2,7 +2,10 @@ use eu4save::ProvinceId;
 use jomini::Scalar;
 use rawbmp::{Bmp, Pixels, Rgb};
 use serde::Deserialize;
-use std::{collections::HashMap, io::Cursor};
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::Cursor,
+};
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct Terrain {
@@ -83,6 +86,60 @@ pub fn parse_definition(data: &[u8]) -> HashMap<u16, Rgb> {
     result
 }
 
+/// Parses the straits and other crossings from adjacencies.csv. Canals are
+/// excluded as they need to be built before they can be crossed.
+pub fn parse_adjacencies(data: &[u8]) -> Vec<(u16, u16)> {
+    let mut result = Vec::new();
+    let mut record = csv::ByteRecord::new();
+    let mut rdr = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .has_headers(true)
+        .flexible(true)
+        .from_reader(Cursor::new(data));
+
+    while rdr.read_byte_record(&mut record).unwrap() {
+        // The file is terminated by a row of -1
+        let (Ok(from), Ok(to)) = (
+            Scalar::new(&record[0]).to_u64(),
+            Scalar::new(&record[1]).to_u64(),
+        ) else {
+            break;
+        };
+
+        if record.get(2).is_some_and(|kind| kind == b"canal") {
+            continue;
+        }
+
+        result.push((from as u16, to as u16));
+    }
+
+    result
+}
+
+/// Computes the neighbors of each province from the province id of each pixel
+/// in the province bitmap. The map wraps around horizontally.
+pub fn province_adjacencies(pixels: &[u16], width: usize) -> HashMap<u16, BTreeSet<u16>> {
+    let mut result: HashMap<u16, BTreeSet<u16>> = HashMap::new();
+    for (i, &id) in pixels.iter().enumerate() {
+        let right = if (i + 1) % width == 0 {
+            i + 1 - width
+        } else {
+            i + 1
+        };
+
+        let below = Some(i + width).filter(|&x| x < pixels.len());
+        for other in std::iter::once(right).chain(below) {
+            let other_id = pixels[other];
+            if other_id != id {
+                result.entry(id).or_default().insert(other_id);
+                result.entry(other_id).or_default().insert(id);
+            }
+        }
+    }
+
+    result
+}
+
 pub fn parse_terrain_bmp(
     terrainbmp: &[u8],
     province_area: &[u16],
@@ -145,6 +202,34 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_parse_adjacencies() {
+        let data = "From;To;Type;Through;start_x;start_y;stop_x;stop_y;Comment
+12;6;sea;1258;3003;1811;3000;1785;Gotland-Småland
+1;25;sea;1264;3000;1800;2990;1790;Stockholm-Uppland
+2195;2196;canal;2196;-1;-1;-1;-1;Suez
+-1;-1;;-1;-1;-1;-1;-1;-1
+";
+
+        let actual = parse_adjacencies(data.as_bytes());
+        assert_eq!(actual, vec![(12, 6), (1, 25)]);
+    }
+
+    #[test]
+    fn test_province_adjacencies() {
+        #[rustfmt::skip]
+        let pixels = [
+            1, 1, 2,
+            3, 3, 2,
+        ];
+
+        let actual = province_adjacencies(&pixels, 3);
+        let neighbors = |id: u16| actual.get(&id).unwrap().iter().copied().collect::<Vec<_>>();
+        assert_eq!(neighbors(1), vec![2, 3]);
+        assert_eq!(neighbors(2), vec![1, 3]);
+        assert_eq!(neighbors(3), vec![1, 2]);
+    }
+
     #[test]
     fn test_parse_terrain() {
         let data = "categories = {
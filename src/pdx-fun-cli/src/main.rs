@@ -8,6 +8,7 @@ mod ai_development;
 mod index_image;
 mod province_borders;
 mod province_names;
+mod save_diff;
 mod smallest_province;
 mod terrain_mapper;
 
@@ -37,6 +38,8 @@ enum Commands {
     ProvinceBorders(province_borders::ProvinceBordersArgs),
     /// Produces a tsv of most common habitable province letter
     ProvinceNames(province_names::ProvinceNamesArgs),
+    /// Produces a tsv of how countries changed between two saves of a playthrough
+    SaveDiff(save_diff::SaveDiffArgs),
     /// Finds the smallest province on the map
     SmallestProvince(smallest_province::SmallestProvinceArgs),
     /// Terrain mapper (currently disabled)
@@ -66,6 +69,7 @@ fn main() -> ExitCode {
         Commands::IndexImage(x) => x.run(),
         Commands::ProvinceBorders(x) => x.run(),
         Commands::ProvinceNames(x) => x.run(),
+        Commands::SaveDiff(x) => x.run(),
         Commands::SmallestProvince(x) => x.run(),
         Commands::TerrainMapper(x) => x.run(),
     };
@@ -0,0 +1,86 @@
+use anyhow::Context;
+use clap::Args;
+use eu4game::{diff::diff_saves, shared::Eu4Parser};
+use eu4save::{PdsDate, query::Query};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// Produces a tsv of how countries changed between two saves of a playthrough
+#[derive(Args)]
+pub struct SaveDiffArgs {
+    /// The earlier save
+    #[clap(value_parser)]
+    before: PathBuf,
+
+    /// The later save
+    #[clap(value_parser)]
+    after: PathBuf,
+
+    /// Only report these countries
+    #[clap(long = "tag", action = clap::ArgAction::Append)]
+    tags: Vec<String>,
+}
+
+fn parse_query(path: &Path) -> anyhow::Result<Query> {
+    let save_data =
+        std::fs::read(path).with_context(|| format!("unable to read: {}", path.display()))?;
+    let save = Eu4Parser::new()
+        .parse(&save_data)
+        .with_context(|| format!("unable to parse: {}", path.display()))?
+        .save;
+    Ok(Query::from_save(save))
+}
+
+impl SaveDiffArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let before = parse_query(&self.before)?;
+        let after = parse_query(&self.after)?;
+        let diff = diff_saves(&before, &after)?;
+
+        println!(
+            "tag\tdevelopment\tprovinces_gained\tprovinces_lost\tincome\tmanpower\ttech\tideas\twars_started\twars_ended\trulers"
+        );
+
+        let countries = diff
+            .countries
+            .iter()
+            .filter(|x| self.tags.is_empty() || self.tags.contains(&x.tag.to_string()));
+
+        for country in countries {
+            let ideas = country
+                .ideas
+                .iter()
+                .map(|x| format!("{} {:+}", x.name, x.ideas.change()))
+                .collect::<Vec<_>>()
+                .join(";");
+
+            let rulers = country
+                .rulers
+                .iter()
+                .map(|x| format!("{} ({})", x.name, x.date.iso_8601()))
+                .collect::<Vec<_>>()
+                .join(";");
+
+            println!(
+                "{}\t{:+.1}\t{}\t{}\t{:+.2}\t{:+.0}\t{:+}/{:+}/{:+}\t{}\t{}\t{}\t{}",
+                country.tag,
+                country.development.change(),
+                country.provinces_gained.len(),
+                country.provinces_lost.len(),
+                country.income.change(),
+                country.manpower.change(),
+                country.adm_tech.change(),
+                country.dip_tech.change(),
+                country.mil_tech.change(),
+                ideas,
+                country.wars_started.join(";"),
+                country.wars_ended.join(";"),
+                rulers,
+            );
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,165 @@
+/*!
+
+Serde helpers for shapes that recur across Paradox save formats.
+
+Game databases are commonly keyed by id where a pruned entry is written as
+the bare string `none` in place of an object:
+
+```text
+database={
+    1={ name="foo" }
+    2=none
+}
+```
+
+[`maybe_map`] deserializes such a database into a `HashMap<K, Option<V>>`
+and [`Maybe`] handles a single entry.
+
+*/
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, Unexpected},
+};
+use std::{collections::HashMap, fmt, hash::Hash, marker::PhantomData};
+
+/// A value that is either an object or the literal `none`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Maybe<T>(Option<T>);
+
+impl<T> Maybe<T> {
+    #[inline]
+    pub fn into_value(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Maybe<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Maybe<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MaybeVisitor<T> {
+            marker: PhantomData<Maybe<T>>,
+        }
+        impl<'de, T1> de::Visitor<'de> for MaybeVisitor<T1>
+        where
+            T1: Deserialize<'de>,
+        {
+            type Value = Maybe<T1>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_fmt(format_args!(
+                    "struct {} or none",
+                    std::any::type_name::<T1>()
+                ))
+            }
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match v {
+                    "none" => Ok(Maybe(None)),
+                    _ => Err(E::invalid_value(Unexpected::Other(v), &self)),
+                }
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                T1::deserialize(de::value::MapAccessDeserializer::new(map)).map(|x| Maybe(Some(x)))
+            }
+        }
+        deserializer.deserialize_map(MaybeVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Deserialize a database where pruned entries are written as `none`
+pub fn maybe_map<'de, D, K, V>(deser: D) -> Result<HashMap<K, Option<V>>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Hash + Eq,
+    V: Deserialize<'de>,
+{
+    struct MaybeVisitor<K, V> {
+        marker: PhantomData<HashMap<K, Option<V>>>,
+    }
+
+    impl<'de, K1, V1> de::Visitor<'de> for MaybeVisitor<K1, V1>
+    where
+        K1: Deserialize<'de> + Hash + Eq,
+        V1: Deserialize<'de>,
+    {
+        type Value = HashMap<K1, Option<V1>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("A maybe map")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut result = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+            while let Some((key, value)) = map.next_entry::<K1, Maybe<V1>>()? {
+                result.insert(key, value.into_value());
+            }
+
+            Ok(result)
+        }
+    }
+
+    deser.deserialize_map(MaybeVisitor {
+        marker: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jomini::text::de::from_utf8_slice;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Entry {
+        name: String,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Database {
+        #[serde(deserialize_with = "maybe_map")]
+        database: HashMap<u32, Option<Entry>>,
+    }
+
+    #[test]
+    fn test_maybe_map() {
+        let data = b"database={ 1={ name=\"foo\" } 2=none }";
+        let out: Database = from_utf8_slice(data).unwrap();
+        assert_eq!(out.database.len(), 2);
+        assert_eq!(out.database[&1].as_ref().unwrap().name, "foo");
+        assert_eq!(out.database[&2], None);
+    }
+
+    #[test]
+    fn test_maybe_rejects_other_strings() {
+        from_utf8_slice::<Database>(b"database={ 1=None }").unwrap_err();
+    }
+
+    #[test]
+    fn test_maybe_field() {
+        #[derive(Debug, Deserialize)]
+        struct Holder {
+            first: Maybe<Entry>,
+            second: Maybe<Entry>,
+        }
+
+        let out: Holder = from_utf8_slice(b"first={ name=bar } second=none").unwrap();
+        assert_eq!(out.first.into_value().unwrap().name, "bar");
+        assert_eq!(out.second.into_value(), None);
+    }
+}
@@ -822,6 +822,7 @@ impl<'a> Province<'a> {
   pub const VT_PROVINCE_IS_ON_AN_ISLAND: flatbuffers::VOffsetT = 8;
   pub const VT_CENTER_X: flatbuffers::VOffsetT = 10;
   pub const VT_CENTER_Y: flatbuffers::VOffsetT = 12;
+  pub const VT_NEIGHBORS: flatbuffers::VOffsetT = 14;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -830,9 +831,10 @@ impl<'a> Province<'a> {
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ProvinceArgs
+    args: &'args ProvinceArgs<'args>
   ) -> flatbuffers::WIPOffset<Province<'bldr>> {
     let mut builder = ProvinceBuilder::new(_fbb);
+    if let Some(x) = args.neighbors { builder.add_neighbors(x); }
     builder.add_center_y(args.center_y);
     builder.add_center_x(args.center_x);
     builder.add_id(args.id);
@@ -887,6 +889,14 @@ impl<'a> Province<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<u16>(Province::VT_CENTER_Y, Some(0)).unwrap()}
   }
+  /// Provinces that share a border or are connected by a strait
+  #[inline]
+  pub fn neighbors(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(Province::VT_NEIGHBORS, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Province<'_> {
@@ -901,18 +911,20 @@ impl flatbuffers::Verifiable for Province<'_> {
      .visit_field::<bool>("province_is_on_an_island", Self::VT_PROVINCE_IS_ON_AN_ISLAND, false)?
      .visit_field::<u16>("center_x", Self::VT_CENTER_X, false)?
      .visit_field::<u16>("center_y", Self::VT_CENTER_Y, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("neighbors", Self::VT_NEIGHBORS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ProvinceArgs {
+pub struct ProvinceArgs<'a> {
     pub id: u16,
     pub terrain: Terrain,
     pub province_is_on_an_island: bool,
     pub center_x: u16,
     pub center_y: u16,
+    pub neighbors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
 }
-impl<'a> Default for ProvinceArgs {
+impl<'a> Default for ProvinceArgs<'a> {
   #[inline]
   fn default() -> Self {
     ProvinceArgs {
@@ -921,6 +933,7 @@ impl<'a> Default for ProvinceArgs {
       province_is_on_an_island: false,
       center_x: 0,
       center_y: 0,
+      neighbors: None,
     }
   }
 }
@@ -951,6 +964,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ProvinceBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<u16>(Province::VT_CENTER_Y, center_y, 0);
   }
   #[inline]
+  pub fn add_neighbors(&mut self, neighbors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Province::VT_NEIGHBORS, neighbors);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ProvinceBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ProvinceBuilder {
@@ -973,6 +990,7 @@ impl core::fmt::Debug for Province<'_> {
       ds.field("province_is_on_an_island", &self.province_is_on_an_island());
       ds.field("center_x", &self.center_x());
       ds.field("center_y", &self.center_y());
+      ds.field("neighbors", &self.neighbors());
       ds.finish()
   }
 }
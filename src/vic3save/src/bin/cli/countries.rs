@@ -0,0 +1,76 @@
+use crate::{output, saves::SaveArgs};
+use clap::Args;
+use serde::Serialize;
+use std::process::ExitCode;
+use vic3save::stats::Vic3CountryStats;
+
+/// Lists every country's GDP, population and literacy trend
+#[derive(Args)]
+pub struct CountriesArgs {
+    #[command(flatten)]
+    args: SaveArgs,
+
+    /// Only output the given countries (can be repeated)
+    #[arg(long, action = clap::ArgAction::Append)]
+    tag: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CountryRow<'a> {
+    save: &'a str,
+    tag: &'a str,
+    gdp: Option<f64>,
+    gdp_growth: Option<f64>,
+    population: Option<f64>,
+    population_growth: Option<f64>,
+    literacy: Option<f64>,
+    literacy_growth: Option<f64>,
+}
+
+impl CountriesArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let saves = self.args.saves()?;
+        let mut rows = Vec::new();
+        for (name, save) in &saves {
+            let countries = save
+                .country_manager
+                .database
+                .values()
+                .filter_map(|x| x.as_ref())
+                .filter(|x| self.tag.is_empty() || self.tag.contains(&x.definition));
+
+            for country in countries {
+                rows.push(CountryRow {
+                    save: name,
+                    tag: country.definition.as_str(),
+                    gdp: latest(&country.gdp),
+                    gdp_growth: country.gdp.gdp_growth().last().map(|(_, x)| x),
+                    population: latest(&country.pop_statistics.trend_population),
+                    population_growth: yearly_growth(&country.pop_statistics.trend_population),
+                    literacy: latest(&country.literacy),
+                    literacy_growth: yearly_growth(&country.literacy),
+                });
+            }
+        }
+
+        rows.sort_by(|a, b| {
+            a.save.cmp(b.save).then_with(|| {
+                let a_gdp = a.gdp.unwrap_or_default();
+                let b_gdp = b.gdp.unwrap_or_default();
+                b_gdp.total_cmp(&a_gdp)
+            })
+        });
+
+        output::emit(self.args.format, &rows)?;
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn latest(stats: &Vic3CountryStats) -> Option<f64> {
+    stats.iter().last().map(|(_, x)| x)
+}
+
+/// The rate of change over the last year of the time series
+fn yearly_growth(stats: &Vic3CountryStats) -> Option<f64> {
+    stats.growth_rate(365).last().map(|(_, x)| x)
+}
@@ -0,0 +1,55 @@
+use crate::{output, saves::CountryArgs};
+use anyhow::Context;
+use clap::Args;
+use serde::Serialize;
+use std::process::ExitCode;
+
+/// Lists the diplomatic pacts a country is a party to
+#[derive(Args)]
+pub struct DiplomacyArgs {
+    #[command(flatten)]
+    args: CountryArgs,
+}
+
+#[derive(Serialize)]
+struct PactRow<'a> {
+    save: &'a str,
+    tag: &'a str,
+    action: &'a str,
+    counterpart: &'a str,
+    initiator: bool,
+}
+
+impl DiplomacyArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let saves = self.args.save.saves()?;
+        let mut rows = Vec::new();
+        for (name, save) in &saves {
+            let tag = self.args.country(save)?.definition.as_str();
+            let country_id = save
+                .get_country_id(tag)
+                .with_context(|| format!("country not found: {}", tag))?;
+
+            for pact in save.country_diplomatic_pacts(country_id) {
+                let counterpart = save
+                    .country_manager
+                    .database
+                    .get(&pact.counterpart(country_id))
+                    .and_then(|x| x.as_ref())
+                    .map(|x| x.definition.as_str())
+                    .unwrap_or_default();
+
+                rows.push(PactRow {
+                    save: name,
+                    tag,
+                    action: pact.action.as_str(),
+                    counterpart,
+                    initiator: pact.first_country == country_id,
+                });
+            }
+        }
+
+        output::emit(self.args.save.format, &rows)?;
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -1,17 +1,45 @@
-use jomini::{TextTape, TextWriterBuilder};
+use crate::output::{self, SaveFormat};
+use anyhow::Context;
+use clap::Args;
 use std::{
-    error::Error,
-    io::{BufWriter, Write, stdout},
+    io::{BufWriter, Read, stdout},
+    path::PathBuf,
+    process::ExitCode,
 };
 
-pub fn run(file_data: &[u8]) -> Result<(), Box<dyn Error>> {
-    let tape = TextTape::from_slice(file_data).unwrap();
+/// Pretty prints plaintext saves
+#[derive(Args)]
+pub struct FmtArgs {
+    /// Plaintext files to format, reads from stdin when omitted
+    #[arg(action = clap::ArgAction::Append)]
+    files: Vec<PathBuf>,
+
+    /// Output format. Csv and table aren't supported as a save can't be
+    /// flattened into rows
+    #[arg(long, value_enum, default_value_t = SaveFormat::Text)]
+    format: SaveFormat,
+}
+
+impl FmtArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        if self.files.is_empty() {
+            let mut buf = Vec::new();
+            std::io::stdin().lock().read_to_end(&mut buf)?;
+            format_data(self.format, &buf)?;
+        }
+
+        for path in &self.files {
+            let data = std::fs::read(path)
+                .with_context(|| format!("unable to read: {}", path.display()))?;
+            format_data(self.format, &data)
+                .with_context(|| format!("unable to format: {}", path.display()))?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn format_data(format: SaveFormat, file_data: &[u8]) -> anyhow::Result<()> {
     let stdout = stdout();
-    let stdout_lock = stdout.lock();
-    let buf_stdout = BufWriter::new(stdout_lock);
-    let mut writer = TextWriterBuilder::new().from_writer(buf_stdout);
-    writer.write_tape(&tape).unwrap();
-    let mut buf_stdout = writer.into_inner();
-    buf_stdout.flush().unwrap();
-    Ok(())
+    output::emit_save(format, file_data, BufWriter::new(stdout.lock()))
 }
@@ -0,0 +1,47 @@
+use crate::{output, saves::CountryArgs};
+use anyhow::Context;
+use clap::Args;
+use serde::Serialize;
+use std::process::ExitCode;
+
+/// Lists the interest groups of a country by clout
+#[derive(Args)]
+pub struct InterestGroupsArgs {
+    #[command(flatten)]
+    args: CountryArgs,
+}
+
+#[derive(Serialize)]
+struct InterestGroupRow<'a> {
+    save: &'a str,
+    tag: &'a str,
+    interest_group: &'a str,
+    clout: f64,
+    in_government: bool,
+}
+
+impl InterestGroupsArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let saves = self.args.save.saves()?;
+        let mut rows = Vec::new();
+        for (name, save) in &saves {
+            let tag = self.args.country(save)?.definition.as_str();
+            let country_id = save
+                .get_country_id(tag)
+                .with_context(|| format!("country not found: {}", tag))?;
+
+            for ig in save.country_interest_groups(country_id) {
+                rows.push(InterestGroupRow {
+                    save: name,
+                    tag,
+                    interest_group: ig.definition.as_str(),
+                    clout: ig.clout,
+                    in_government: ig.in_government,
+                });
+            }
+        }
+
+        output::emit(self.args.save.format, &rows)?;
+        Ok(ExitCode::SUCCESS)
+    }
+}
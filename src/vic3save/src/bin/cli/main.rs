@@ -1,26 +1,64 @@
-use std::{env, error::Error, io::Read};
-
+mod countries;
+mod diplomacy;
 mod fmt;
+mod interest_groups;
 mod markets;
 mod melt;
+mod output;
+mod saves;
+mod states;
 mod stats_cli;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = env::args().collect();
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
 
-    match args[1].as_str() {
-        "fmt" => {
-            let stdin = std::io::stdin();
-            let mut lock = stdin.lock();
-            let mut buf = Vec::new();
-            lock.read_to_end(&mut buf)?;
-            fmt::run(&buf)
-        }
-        "melt" => melt::run(&args[2..]),
-        "stats" => stats_cli::run(&args[2..]),
-        "market" => markets::run(&args[2..]),
-        x => panic!("unrecognized argument: {}", x),
-    }?;
+#[derive(Parser)]
+#[command(author, version, about = "Victoria 3 save analysis commands", long_about = None)]
+#[command(propagate_version = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Lists every country's GDP, population and literacy trend
+    Countries(countries::CountriesArgs),
+    /// Lists the diplomatic pacts a country is a party to
+    Diplomacy(diplomacy::DiplomacyArgs),
+    /// Pretty prints plaintext saves
+    Fmt(fmt::FmtArgs),
+    /// Lists the interest groups of a country by clout
+    Igs(interest_groups::InterestGroupsArgs),
+    /// Estimates goods prices from the buildings of a country
+    Market(markets::MarketArgs),
+    /// Converts binary saves into plaintext
+    Melt(melt::MeltArgs),
+    /// Lists the population, standard of living, and employment of states
+    States(states::StatesArgs),
+    /// Produces a time series of a country's GDP and standard of living
+    Stats(stats_cli::StatsArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    Ok(())
+    let exit_code = match &cli.command {
+        Commands::Countries(x) => x.run(),
+        Commands::Diplomacy(x) => x.run(),
+        Commands::Fmt(x) => x.run(),
+        Commands::Igs(x) => x.run(),
+        Commands::Market(x) => x.run(),
+        Commands::Melt(x) => x.run(),
+        Commands::States(x) => x.run(),
+        Commands::Stats(x) => x.run(),
+    };
+
+    match exit_code {
+        Ok(e) => e,
+        Err(err) => {
+            eprintln!("application error: {:?}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
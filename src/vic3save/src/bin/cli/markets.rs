@@ -1,29 +1,55 @@
-use std::error::Error;
-use std::fs;
+use crate::{output, saves::CountryArgs};
+use clap::Args;
+use serde::Serialize;
+use std::process::ExitCode;
 use vic3save::markets::goods_price_based_on_buildings;
-use vic3save::savefile::Vic3Save;
-use vic3save::{BasicTokenResolver, DeserializeVic3, Vic3File};
 
-pub fn run(raw_args: &[String]) -> Result<(), Box<dyn Error>> {
-    let file = fs::File::open(&raw_args[0])?;
-    let file = Vic3File::from_file(file)?;
-    let file_data = std::fs::read("assets/vic3.txt").unwrap_or_default();
-    let resolver = BasicTokenResolver::from_text_lines(file_data.as_slice())?;
-    let save: Vic3Save = (&file).deserialize(resolver)?;
-    let tag = save.get_last_played_country().definition.as_ref();
-    println!("Market for country {}", tag);
-    let country = save.get_country(tag).expect("tag to be found");
-    let states = &country.states;
-    let goods_prices = goods_price_based_on_buildings(
-        save.building_manager
-            .database
-            .values()
-            .filter_map(|x| x.as_ref())
-            .filter(|b| states.contains(&b.state)),
-    )?;
+/// Estimates goods prices from the buildings of a country
+#[derive(Args)]
+pub struct MarketArgs {
+    #[command(flatten)]
+    args: CountryArgs,
+}
+
+#[derive(Serialize)]
+struct MarketRow<'a> {
+    save: &'a str,
+    tag: &'a str,
+    good: String,
+    estimated_price: f64,
+}
+
+impl MarketArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let saves = self.args.save.saves()?;
+        let mut rows = Vec::new();
+        for (name, save) in &saves {
+            let country = self.args.country(save)?;
+            let tag = country.definition.as_str();
+            let states = &country.states;
+            let goods_prices = goods_price_based_on_buildings(
+                save.building_manager
+                    .database
+                    .values()
+                    .filter_map(|x| x.as_ref())
+                    .filter(|b| states.contains(&b.state)),
+            )?;
+
+            let mut save_rows = goods_prices
+                .iter()
+                .map(|(good, price)| MarketRow {
+                    save: name,
+                    tag,
+                    good: good.to_string(),
+                    estimated_price: *price,
+                })
+                .collect::<Vec<_>>();
+
+            save_rows.sort_by(|a, b| a.good.cmp(&b.good));
+            rows.extend(save_rows);
+        }
 
-    for (good, val) in goods_prices {
-        println!("{}: {}", good, val);
+        output::emit(self.args.save.format, &rows)?;
+        Ok(ExitCode::SUCCESS)
     }
-    Ok(())
 }
@@ -1,15 +1,81 @@
-use std::{error::Error, fs, io::BufWriter};
-use vic3save::{BasicTokenResolver, FailedResolveStrategy, MeltOptions, Vic3File, Vic3Melt};
-
-pub fn run(raw_args: &[String]) -> Result<(), Box<dyn Error>> {
-    let file = fs::File::open(&raw_args[0])?;
-    let file = Vic3File::from_file(file)?;
-    let file_data = std::fs::read("assets/vic3.txt").unwrap_or_default();
-    let resolver = BasicTokenResolver::from_text_lines(file_data.as_slice())?;
-    let stdout = std::io::stdout();
-    let handle = stdout.lock();
-    let mut buffer = BufWriter::new(handle);
-    let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Error);
-    (&file).melt(options, &resolver, &mut buffer)?;
-    Ok(())
+use crate::{
+    output::{self, SaveFormat},
+    saves::token_resolver,
+};
+use anyhow::Context;
+use clap::Args;
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+use vic3save::{FailedResolveStrategy, MeltOptions, Vic3File, Vic3Melt};
+
+/// Converts binary saves into plaintext
+#[derive(Args)]
+pub struct MeltArgs {
+    /// Save files to melt
+    #[arg(required = true, action = clap::ArgAction::Append)]
+    files: Vec<PathBuf>,
+
+    /// Directory to write melted saves into (named `<save>_melted.<ext>`),
+    /// otherwise melted output is written to stdout
+    #[arg(long)]
+    dest: Option<PathBuf>,
+
+    /// Output format. Text is written as melted while json is converted
+    /// from the melted output. Csv and table aren't supported as a save
+    /// can't be flattened into rows
+    #[arg(long, value_enum, default_value_t = SaveFormat::Text)]
+    format: SaveFormat,
+
+    /// Token file used to resolve binary saves
+    #[arg(long, default_value = "assets/vic3.txt")]
+    tokens: PathBuf,
+}
+
+impl MeltArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let resolver = token_resolver(&self.tokens)?;
+        let options = MeltOptions::new().on_failed_resolve(FailedResolveStrategy::Error);
+
+        for path in &self.files {
+            let file = fs::File::open(path)
+                .with_context(|| format!("unable to open: {}", path.display()))?;
+            let file = Vic3File::from_file(file)
+                .with_context(|| format!("unable to parse: {}", path.display()))?;
+
+            let mut buffer: BufWriter<Box<dyn Write>> = match &self.dest {
+                Some(dest) => {
+                    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                    let out_path =
+                        dest.join(format!("{}_melted.{}", stem, self.format.extension()));
+                    let out = fs::File::create(&out_path)
+                        .with_context(|| format!("unable to create: {}", out_path.display()))?;
+                    BufWriter::new(Box::new(out))
+                }
+                None => BufWriter::new(Box::new(std::io::stdout().lock())),
+            };
+
+            match self.format {
+                SaveFormat::Text => {
+                    (&file)
+                        .melt(options, &resolver, &mut buffer)
+                        .with_context(|| format!("unable to melt: {}", path.display()))?;
+                    buffer.flush()?;
+                }
+                SaveFormat::Json => {
+                    let mut melted = Vec::new();
+                    (&file)
+                        .melt(options, &resolver, &mut melted)
+                        .with_context(|| format!("unable to melt: {}", path.display()))?;
+                    output::emit_save(self.format, &melted, buffer)
+                        .with_context(|| format!("unable to convert: {}", path.display()))?;
+                }
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
 }
@@ -0,0 +1,118 @@
+use jomini::{TextTape, TextWriterBuilder, json::JsonOptions};
+use serde::Serialize;
+use std::io::{BufWriter, Write};
+
+/// Format of commands that output rows (countries, states, market, etc).
+///
+/// This is kept separate from [`SaveFormat`] as the two outputs have a
+/// different shape: rows are flat records that can be written as csv or an
+/// aligned table but have no save representation, while a save is a nested
+/// tree that can be written as text or json but can't be flattened into csv
+/// or table columns. A single enum would accept combinations that can only
+/// fail at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+    Table,
+}
+
+/// Format of commands that output a plaintext save (melt and fmt). See
+/// [`Format`] for why csv and table aren't offered here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SaveFormat {
+    Text,
+    Json,
+}
+
+impl SaveFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SaveFormat::Text => "v3",
+            SaveFormat::Json => "json",
+        }
+    }
+}
+
+/// Writes plaintext save data in the requested format. Text is pretty printed
+/// and json is written as a pretty printed object.
+pub fn emit_save<W: Write>(format: SaveFormat, data: &[u8], writer: W) -> anyhow::Result<()> {
+    let tape = TextTape::from_slice(data)?;
+    match format {
+        SaveFormat::Text => {
+            let mut writer = TextWriterBuilder::new().from_writer(writer);
+            writer.write_tape(&tape)?;
+            writer.into_inner().flush()?;
+        }
+        SaveFormat::Json => {
+            let options = JsonOptions::new().with_prettyprint(true);
+            let mut writer = writer;
+            tape.utf8_reader()
+                .json()
+                .with_options(options)
+                .to_writer(&mut writer)?;
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the rows to stdout in the requested format. Rows must be flat
+/// structs so that they can be represented as csv records.
+pub fn emit<T: Serialize>(format: Format, rows: &[T]) -> anyhow::Result<()> {
+    let stdout = std::io::stdout();
+    let mut writer = BufWriter::new(stdout.lock());
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut writer, rows)?;
+            writeln!(writer)?;
+        }
+        Format::Csv => {
+            let mut csv = csv::Writer::from_writer(&mut writer);
+            for row in rows {
+                csv.serialize(row)?;
+            }
+            csv.flush()?;
+        }
+        Format::Table => write_table(&mut writer, rows)?,
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Renders the rows as whitespace aligned columns by first writing them as
+/// csv records and then padding each field to the width of its column.
+fn write_table<T: Serialize, W: Write>(mut writer: W, rows: &[T]) -> anyhow::Result<()> {
+    let mut csv = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        csv.serialize(row)?;
+    }
+    let data = csv.into_inner()?;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(data.as_slice());
+    let records = reader.records().collect::<Result<Vec<_>, _>>()?;
+
+    let mut widths = Vec::new();
+    for record in &records {
+        widths.resize(widths.len().max(record.len()), 0);
+        for (width, field) in widths.iter_mut().zip(record.iter()) {
+            *width = (*width).max(field.chars().count());
+        }
+    }
+
+    for record in &records {
+        let line = record
+            .iter()
+            .zip(widths.iter())
+            .map(|(field, width)| format!("{:<width$}", field, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+
+    Ok(())
+}
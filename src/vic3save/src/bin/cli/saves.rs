@@ -0,0 +1,78 @@
+use crate::output::Format;
+use anyhow::Context;
+use clap::Args;
+use std::path::{Path, PathBuf};
+use vic3save::savefile::{Vic3Country, Vic3Save};
+use vic3save::{BasicTokenResolver, DeserializeVic3, Vic3File};
+
+/// Arguments shared by the commands that analyze saves
+#[derive(Args)]
+pub struct SaveArgs {
+    /// Save files to analyze
+    #[arg(required = true, action = clap::ArgAction::Append)]
+    pub files: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    pub format: Format,
+
+    /// Token file used to resolve binary saves
+    #[arg(long, default_value = "assets/vic3.txt")]
+    pub tokens: PathBuf,
+}
+
+impl SaveArgs {
+    /// Deserializes each save, pairing it with its file name
+    pub fn saves(&self) -> anyhow::Result<Vec<(String, Vic3Save)>> {
+        let resolver = token_resolver(&self.tokens)?;
+        self.files
+            .iter()
+            .map(|path| Ok((save_name(path), load_save(path, &resolver)?)))
+            .collect()
+    }
+}
+
+/// Arguments shared by the commands that analyze a single country per save
+#[derive(Args)]
+pub struct CountryArgs {
+    #[command(flatten)]
+    pub save: SaveArgs,
+
+    /// Country to analyze, defaults to the last played country
+    #[arg(long)]
+    pub tag: Option<String>,
+}
+
+impl CountryArgs {
+    pub fn country<'a>(&self, save: &'a Vic3Save) -> anyhow::Result<&'a Vic3Country> {
+        match self.tag.as_deref() {
+            Some(tag) => save
+                .get_country(tag)
+                .with_context(|| format!("country not found: {}", tag)),
+            None => Ok(save.get_last_played_country()),
+        }
+    }
+}
+
+pub fn token_resolver(path: &Path) -> anyhow::Result<BasicTokenResolver> {
+    let file_data = std::fs::read(path).unwrap_or_default();
+    let resolver = BasicTokenResolver::from_text_lines(file_data.as_slice())?;
+    Ok(resolver)
+}
+
+pub fn load_save(path: &Path, resolver: &BasicTokenResolver) -> anyhow::Result<Vic3Save> {
+    let file =
+        std::fs::File::open(path).with_context(|| format!("unable to open: {}", path.display()))?;
+    let file = Vic3File::from_file(file)
+        .with_context(|| format!("unable to parse: {}", path.display()))?;
+    let save = (&file)
+        .deserialize(resolver)
+        .with_context(|| format!("unable to deserialize: {}", path.display()))?;
+    Ok(save)
+}
+
+pub fn save_name(path: &Path) -> String {
+    path.file_name()
+        .map(|x| x.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,49 @@
+use crate::{output, saves::CountryArgs};
+use anyhow::Context;
+use clap::Args;
+use serde::Serialize;
+use std::process::ExitCode;
+
+/// Lists the population, standard of living, and employment of states
+#[derive(Args)]
+pub struct StatesArgs {
+    #[command(flatten)]
+    args: CountryArgs,
+}
+
+#[derive(Serialize)]
+struct StateRow<'a> {
+    save: &'a str,
+    tag: &'a str,
+    state: u32,
+    population: i64,
+    standard_of_living: f64,
+    employment_rate: f64,
+}
+
+impl StatesArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let saves = self.args.save.saves()?;
+        let mut rows = Vec::new();
+        for (name, save) in &saves {
+            let tag = self.args.country(save)?.definition.as_str();
+            let country_id = save
+                .get_country_id(tag)
+                .with_context(|| format!("country not found: {}", tag))?;
+
+            for state in save.country_state_stats(country_id) {
+                rows.push(StateRow {
+                    save: name,
+                    tag,
+                    state: state.state,
+                    population: state.population,
+                    standard_of_living: state.standard_of_living,
+                    employment_rate: state.employment_rate(),
+                });
+            }
+        }
+
+        output::emit(self.args.save.format, &rows)?;
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -1,44 +1,67 @@
-use std::error::Error;
-use std::fs;
-use vic3save::savefile::Vic3Save;
+use crate::{output, saves::CountryArgs};
+use clap::Args;
+use serde::Serialize;
+use std::process::ExitCode;
+use vic3save::PdsDate;
 use vic3save::stats::Vic3CountryStatsRateIter;
-use vic3save::{BasicTokenResolver, DeserializeVic3, Vic3File};
 
-pub fn run(raw_args: &[String]) -> Result<(), Box<dyn Error>> {
-    let file = fs::File::open(&raw_args[0])?;
-    let file = Vic3File::from_file(file)?;
-    let file_data = std::fs::read("assets/vic3.txt").unwrap_or_default();
-    let resolver = BasicTokenResolver::from_text_lines(file_data.as_slice())?;
-    let save: Vic3Save = (&file).deserialize(resolver)?;
-    let tag = save.get_last_played_country().definition.as_ref();
-    let country = save.get_country(tag).expect("tag to be found");
+/// Produces a time series of a country's GDP and standard of living
+#[derive(Args)]
+pub struct StatsArgs {
+    #[command(flatten)]
+    args: CountryArgs,
+}
 
-    let gdp_line = country.gdp.iter();
-    let sol_line = country.avgsoltrend.iter();
-    let pop_line = || country.pop_statistics.trend_population.iter();
-    let gdpc_line = || {
-        pop_line()
-            .zip_aligned(country.gdp.iter())
-            .map(|(date, (pop, gdp))| (date, (gdp / (pop / 100_000.0))))
-    };
-    let gdpc_growth = Vic3CountryStatsRateIter::new(gdpc_line(), 365);
-    for (date, [gdp, gdp_growth, sol, gdpc, gdpc_growth]) in gdp_line
-        .zip_aligned(country.gdp.gdp_growth())
-        .zip_aligned(sol_line)
-        .zip_aligned(gdpc_line())
-        .zip_aligned(gdpc_growth)
-        .flat()
-    {
-        println!(
-            "{:?}\t{:.2}\t{:0.2}\t{:.2}\t{:.2}\t{:0.2}",
-            date,
-            gdp / 1000000.0,
-            gdp_growth,
-            gdpc,
-            sol,
-            gdpc_growth,
-        );
-    }
+#[derive(Serialize)]
+struct StatsRow<'a> {
+    save: &'a str,
+    tag: &'a str,
+    date: String,
+    gdp_millions: f64,
+    gdp_growth: f64,
+    gdp_per_capita: f64,
+    standard_of_living: f64,
+    gdp_per_capita_growth: f64,
+}
 
-    Ok(())
+impl StatsArgs {
+    pub fn run(&self) -> anyhow::Result<ExitCode> {
+        let saves = self.args.save.saves()?;
+        let mut rows = Vec::new();
+        for (name, save) in &saves {
+            let country = self.args.country(save)?;
+            let tag = country.definition.as_str();
+
+            let gdp_line = country.gdp.iter();
+            let sol_line = country.avgsoltrend.iter();
+            let pop_line = || country.pop_statistics.trend_population.iter();
+            let gdpc_line = || {
+                pop_line()
+                    .zip_aligned(country.gdp.iter())
+                    .map(|(date, (pop, gdp))| (date, (gdp / (pop / 100_000.0))))
+            };
+            let gdpc_growth = Vic3CountryStatsRateIter::new(gdpc_line(), 365);
+            for (date, [gdp, gdp_growth, sol, gdpc, gdpc_growth]) in gdp_line
+                .zip_aligned(country.gdp.gdp_growth())
+                .zip_aligned(sol_line)
+                .zip_aligned(gdpc_line())
+                .zip_aligned(gdpc_growth)
+                .flat()
+            {
+                rows.push(StatsRow {
+                    save: name,
+                    tag,
+                    date: date.game_fmt().to_string(),
+                    gdp_millions: gdp / 1000000.0,
+                    gdp_growth,
+                    gdp_per_capita: gdpc,
+                    standard_of_living: sol,
+                    gdp_per_capita_growth: gdpc_growth,
+                });
+            }
+        }
+
+        output::emit(self.args.save.format, &rows)?;
+        Ok(ExitCode::SUCCESS)
+    }
 }
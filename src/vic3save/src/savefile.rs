@@ -1,9 +1,7 @@
 use crate::{Vic3Date, markets::Vic3Building, stats::Vic3CountryStats};
-use serde::{
-    Deserialize, Deserializer,
-    de::{self, DeserializeOwned, Unexpected},
-};
-use std::{collections::HashMap, fmt, hash::Hash, marker::PhantomData};
+use pdx_serde::maybe_map;
+use serde::{Deserialize, de::DeserializeOwned};
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct MetaData {
@@ -38,102 +36,131 @@ pub struct Vic3Country {
     pub pop_statistics: PopStatistics,
     #[serde(default)]
     pub states: Vec<u32>,
+    pub market: Option<u32>,
 }
 
-#[derive(Debug, PartialEq)]
-struct Maybe<T>(Option<T>);
-impl<'de, T> de::Deserialize<'de> for Maybe<T>
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Vic3Manager<Of>
 where
-    T: Deserialize<'de>,
+    Of: DeserializeOwned,
 {
-    fn deserialize<D>(deserializer: D) -> Result<Maybe<T>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct MaybeVisitor<T> {
-            marker: PhantomData<Maybe<T>>,
-        }
-        impl<'de, T1> de::Visitor<'de> for MaybeVisitor<T1>
-        where
-            T1: Deserialize<'de>,
-        {
-            type Value = Maybe<T1>;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_fmt(format_args!(
-                    "struct {} or none",
-                    std::any::type_name::<T1>()
-                ))
-            }
-            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match v {
-                    "none" => Ok(Maybe(None)),
-                    _ => Err(E::invalid_value(Unexpected::Other(v), &self)),
-                }
-            }
+    #[serde(deserialize_with = "maybe_map")]
+    pub database: HashMap<u32, Option<Of>>,
+}
 
-            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
-            where
-                A: de::MapAccess<'de>,
-            {
-                T1::deserialize(de::value::MapAccessDeserializer::new(map)).map(|x| Maybe(Some(x)))
-            }
+impl<Of> Default for Vic3Manager<Of>
+where
+    Of: DeserializeOwned,
+{
+    fn default() -> Self {
+        Self {
+            database: HashMap::new(),
         }
-        deserializer.deserialize_map(MaybeVisitor {
-            marker: PhantomData,
-        })
     }
 }
 
-fn maybe_map<'de, D, K, V>(deser: D) -> Result<HashMap<K, Option<V>>, D::Error>
+impl<Of> Vic3Manager<Of>
 where
-    D: Deserializer<'de>,
-    K: Deserialize<'de> + Hash + Eq,
-    V: Deserialize<'de>,
+    Of: DeserializeOwned,
 {
-    struct MaybeVisitor<K, V> {
-        marker: PhantomData<HashMap<K, Option<V>>>,
+    /// Iterate over the entries that have not been removed from the database
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Of)> {
+        self.database
+            .iter()
+            .filter_map(|(id, x)| x.as_ref().map(|x| (*id, x)))
     }
+}
 
-    impl<'de, K1, V1> de::Visitor<'de> for MaybeVisitor<K1, V1>
-    where
-        K1: Deserialize<'de> + Hash + Eq,
-        V1: Deserialize<'de>,
-    {
-        type Value = HashMap<K1, Option<V1>>;
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Vic3State {
+    pub country: Option<u32>,
+    pub region: Option<u32>,
+}
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("A maybe map")
-        }
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Vic3Pop {
+    #[serde(rename = "type")]
+    pub pop_type: String,
+    pub location: Option<u32>,
+    pub culture: Option<u32>,
+    pub workplace: Option<u32>,
+    #[serde(default)]
+    pub workforce: i64,
+    #[serde(default)]
+    pub dependents: i64,
 
-        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-        where
-            A: de::MapAccess<'de>,
-        {
-            let mut result = HashMap::with_capacity(map.size_hint().unwrap_or(0));
-            while let Some((key, value)) = map.next_entry::<K1, Maybe<V1>>()? {
-                result.insert(key, value.0);
-            }
+    /// The wealth of the pop which is what the game displays as the standard
+    /// of living
+    #[serde(default)]
+    pub wealth: f64,
+}
 
-            Ok(result)
-        }
+impl Vic3Pop {
+    pub fn size(&self) -> i64 {
+        self.workforce + self.dependents
     }
 
-    deser.deserialize_map(MaybeVisitor {
-        marker: PhantomData,
-    })
+    pub fn is_employed(&self) -> bool {
+        self.workplace.is_some()
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
-pub struct Vic3Manager<Of>
-where
-    Of: DeserializeOwned,
-{
-    #[serde(deserialize_with = "maybe_map")]
-    pub database: HashMap<u32, Option<Of>>,
+pub struct Vic3InterestGroup {
+    pub definition: String,
+    pub country: u32,
+    #[serde(default)]
+    pub clout: f64,
+    #[serde(default)]
+    pub in_government: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Vic3DiplomaticPact {
+    pub first_country: u32,
+    pub second_country: u32,
+    pub action: String,
+}
+
+impl Vic3DiplomaticPact {
+    pub fn involves(&self, country_id: u32) -> bool {
+        self.first_country == country_id || self.second_country == country_id
+    }
+
+    /// The other party of the pact from the point of view of the given
+    /// country
+    pub fn counterpart(&self, country_id: u32) -> u32 {
+        if self.first_country == country_id {
+            self.second_country
+        } else {
+            self.first_country
+        }
+    }
+}
+
+/// Population aggregates of a single state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vic3StateStats {
+    pub state: u32,
+    pub country: Option<u32>,
+    pub region: Option<u32>,
+    pub population: i64,
+    pub workforce: i64,
+    pub employed: i64,
+
+    /// Population weighted average of pop wealth
+    pub standard_of_living: f64,
+}
+
+impl Vic3StateStats {
+    /// The share of the workforce with a workplace
+    pub fn employment_rate(&self) -> f64 {
+        if self.workforce == 0 {
+            0.0
+        } else {
+            self.employed as f64 / self.workforce as f64
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -148,6 +175,14 @@ pub struct Vic3Save {
     pub counters: Counters,
     pub country_manager: Vic3Manager<Vic3Country>,
     pub building_manager: Vic3Manager<Vic3Building>,
+    #[serde(default, alias = "states")]
+    pub state_manager: Vic3Manager<Vic3State>,
+    #[serde(default, alias = "pops")]
+    pub pop_manager: Vic3Manager<Vic3Pop>,
+    #[serde(default)]
+    pub interest_groups: Vic3Manager<Vic3InterestGroup>,
+    #[serde(default)]
+    pub diplomatic_manager: Vic3Manager<Vic3DiplomaticPact>,
     pub previous_played: Vec<Player>,
 }
 
@@ -160,6 +195,96 @@ impl Vic3Save {
             .find(|country| country.definition == country_tag)
     }
 
+    pub fn get_country_id(&self, country_tag: &str) -> Option<u32> {
+        self.country_manager
+            .iter()
+            .find(|(_, country)| country.definition == country_tag)
+            .map(|(id, _)| id)
+    }
+
+    /// Aggregate the pops of every state. States are returned in ascending
+    /// id order.
+    pub fn state_stats(&self) -> Vec<Vic3StateStats> {
+        let mut stats: HashMap<u32, Vic3StateStats> = self
+            .state_manager
+            .iter()
+            .map(|(id, state)| {
+                let stats = Vic3StateStats {
+                    state: id,
+                    country: state.country,
+                    region: state.region,
+                    population: 0,
+                    workforce: 0,
+                    employed: 0,
+                    standard_of_living: 0.0,
+                };
+                (id, stats)
+            })
+            .collect();
+
+        for (_, pop) in self.pop_manager.iter() {
+            let Some(state) = pop.location.and_then(|x| stats.get_mut(&x)) else {
+                continue;
+            };
+
+            let size = pop.size();
+            state.population += size;
+            state.workforce += pop.workforce;
+            if pop.is_employed() {
+                state.employed += pop.workforce;
+            }
+
+            // Temporarily accumulate the weighted sum
+            state.standard_of_living += pop.wealth * size as f64;
+        }
+
+        let mut result: Vec<_> = stats.into_values().collect();
+        for state in result.iter_mut() {
+            if state.population > 0 {
+                state.standard_of_living /= state.population as f64;
+            }
+        }
+
+        result.sort_unstable_by_key(|x| x.state);
+        result
+    }
+
+    /// Aggregate the pops of every state owned by the given country
+    pub fn country_state_stats(&self, country_id: u32) -> Vec<Vic3StateStats> {
+        let mut result = self.state_stats();
+        result.retain(|x| x.country == Some(country_id));
+        result
+    }
+
+    /// The interest groups of a country ordered by descending clout
+    pub fn country_interest_groups(&self, country_id: u32) -> Vec<&Vic3InterestGroup> {
+        let mut result: Vec<_> = self
+            .interest_groups
+            .iter()
+            .map(|(_, ig)| ig)
+            .filter(|ig| ig.country == country_id)
+            .collect();
+        result.sort_unstable_by(|a, b| b.clout.total_cmp(&a.clout));
+        result
+    }
+
+    /// The diplomatic pacts that the country is a party to ordered by action
+    /// and then by the other party
+    pub fn country_diplomatic_pacts(&self, country_id: u32) -> Vec<&Vic3DiplomaticPact> {
+        let mut result: Vec<_> = self
+            .diplomatic_manager
+            .iter()
+            .map(|(_, pact)| pact)
+            .filter(|pact| pact.involves(country_id))
+            .collect();
+        result.sort_unstable_by(|a, b| {
+            a.action
+                .cmp(&b.action)
+                .then(a.counterpart(country_id).cmp(&b.counterpart(country_id)))
+        });
+        result
+    }
+
     pub fn get_last_played_country(&self) -> &Vic3Country {
         let country = self
             .previous_played
@@ -266,6 +391,21 @@ counters={
 	week=2660
 	province_theater=144569
 }"#;
+
+    /// Parses a save made of the given sections plus empty required ones
+    fn save_with(sections: &[u8]) -> super::Vic3Save {
+        let mut data = br#"
+meta_data={ version="1.9.2" game_date=1836.1.1 }
+counters={ }
+country_manager={ database={ } }
+building_manager={ database={ } }
+previous_played={ }
+"#
+        .to_vec();
+        data.extend_from_slice(sections);
+        from_utf8_slice(&data).unwrap()
+    }
+
     #[test]
     fn test_full_save() {
         let out: Vic3Save = from_utf8_slice(SAVE_FILE).unwrap();
@@ -273,6 +413,57 @@ counters={
         assert_eq!(country.definition, "GER");
     }
 
+    #[test]
+    fn test_state_stats() {
+        let save = save_with(
+            br#"
+states={ database={
+    1={ country=16777216 region=10 }
+    2=none
+}}
+pops={ database={
+    100={ type="laborers" location=1 culture=3 workplace=55 workforce=1000 dependents=1000 wealth=5 }
+    101={ type="peasants" location=1 culture=3 workforce=3000 dependents=3000 wealth=3 }
+    102=none
+}}"#,
+        );
+
+        let stats = save.state_stats();
+        assert_eq!(stats.len(), 1);
+        let state = &stats[0];
+        assert_eq!(state.country, Some(16777216));
+        assert_eq!(state.population, 8000);
+        assert_eq!(state.workforce, 4000);
+        assert_eq!(state.employed, 1000);
+        assert_eq!(state.employment_rate(), 0.25);
+        assert_eq!(state.standard_of_living, 3.5);
+    }
+
+    #[test]
+    fn test_country_diplomatic_pacts() {
+        let save = save_with(
+            br#"
+diplomatic_manager={ database={
+    1={ first_country=1 second_country=2 action="alliance" }
+    2={ first_country=3 second_country=1 action="rivalry" }
+    3={ first_country=4 second_country=1 action="alliance" }
+    4={ first_country=2 second_country=3 action="alliance" }
+    5=none
+}}"#,
+        );
+
+        let pacts: Vec<_> = save
+            .country_diplomatic_pacts(1)
+            .into_iter()
+            .map(|x| (x.action.as_str(), x.counterpart(1)))
+            .collect();
+        assert_eq!(
+            pacts,
+            vec![("alliance", 2), ("alliance", 4), ("rivalry", 3)]
+        );
+        assert!(save.country_diplomatic_pacts(5).is_empty());
+    }
+
     #[test]
     fn test_country() {
         let country_segment = br#"country={101={
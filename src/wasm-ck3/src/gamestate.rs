@@ -0,0 +1,241 @@
+use ck3save::models::Metadata;
+use jomini::common::Date;
+use pdx_serde::maybe_map;
+use serde::{Deserialize, Deserializer, de};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+/// The subset of the CK3 gamestate that the analysis layer needs.
+///
+/// `ck3save::models::Gamestate` only models the metadata, so the rest of the
+/// save is deserialized here alongside it.
+#[derive(Debug, Deserialize)]
+pub struct Ck3Gamestate {
+    pub meta_data: Metadata,
+    pub date: Date,
+    pub bookmark_date: Option<Date>,
+    pub played_character: Option<PlayedCharacter>,
+    #[serde(default)]
+    pub living: HashMap<u32, Character>,
+    #[serde(default)]
+    pub dead_unprunable: HashMap<u32, Character>,
+    #[serde(default)]
+    pub dynasties: Dynasties,
+    #[serde(default)]
+    pub landed_titles: LandedTitles,
+}
+
+impl Ck3Gamestate {
+    pub fn character(&self, id: u32) -> Option<&Character> {
+        self.living
+            .get(&id)
+            .or_else(|| self.dead_unprunable.get(&id))
+    }
+
+    pub fn characters(&self) -> impl Iterator<Item = (u32, &Character)> {
+        self.living
+            .iter()
+            .chain(self.dead_unprunable.iter())
+            .map(|(id, c)| (*id, c))
+    }
+
+    pub fn house(&self, id: u32) -> Option<&DynastyHouse> {
+        self.dynasties
+            .dynasty_house
+            .get(&id)
+            .and_then(|x| x.as_ref())
+    }
+
+    pub fn dynasty(&self, id: u32) -> Option<&Dynasty> {
+        self.dynasties.dynasties.get(&id).and_then(|x| x.as_ref())
+    }
+
+    pub fn title(&self, id: u32) -> Option<&LandedTitle> {
+        self.landed_titles
+            .landed_titles
+            .get(&id)
+            .and_then(|x| x.as_ref())
+    }
+
+    pub fn titles(&self) -> impl Iterator<Item = (u32, &LandedTitle)> {
+        self.landed_titles
+            .landed_titles
+            .iter()
+            .filter_map(|(id, title)| title.as_ref().map(|t| (*id, t)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayedCharacter {
+    pub name: Option<String>,
+    pub character: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Character {
+    #[serde(default)]
+    pub first_name: String,
+    pub birth: Option<Date>,
+    #[serde(default)]
+    pub female: bool,
+    pub dynasty_house: Option<u32>,
+    #[serde(default)]
+    pub skill: Vec<i32>,
+    pub family_data: Option<FamilyData>,
+    pub dead_data: Option<DeadData>,
+}
+
+impl Character {
+    pub fn death(&self) -> Option<Date> {
+        self.dead_data.as_ref().and_then(|x| x.date)
+    }
+
+    pub fn children(&self) -> &[u32] {
+        self.family_data
+            .as_ref()
+            .map(|x| x.child.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FamilyData {
+    #[serde(default)]
+    pub child: Vec<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeadData {
+    pub date: Option<Date>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Dynasties {
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub dynasty_house: HashMap<u32, Option<DynastyHouse>>,
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub dynasties: HashMap<u32, Option<Dynasty>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DynastyHouse {
+    pub name: Option<String>,
+    pub localized_name: Option<String>,
+    pub key: Option<String>,
+    pub dynasty: Option<u32>,
+    pub head_of_house: Option<u32>,
+}
+
+impl DynastyHouse {
+    pub fn display_name(&self) -> Option<&str> {
+        self.localized_name
+            .as_deref()
+            .or(self.name.as_deref())
+            .or(self.key.as_deref())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Dynasty {
+    pub name: Option<String>,
+    pub localized_name: Option<String>,
+    pub key: Option<String>,
+}
+
+impl Dynasty {
+    pub fn display_name(&self) -> Option<&str> {
+        self.localized_name
+            .as_deref()
+            .or(self.name.as_deref())
+            .or(self.key.as_deref())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LandedTitles {
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub landed_titles: HashMap<u32, Option<LandedTitle>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LandedTitle {
+    pub key: String,
+    pub name: Option<String>,
+    pub holder: Option<u32>,
+    pub de_jure_liege: Option<u32>,
+    pub de_facto_liege: Option<u32>,
+    #[serde(default)]
+    pub history: BTreeMap<Date, TitleHistory>,
+}
+
+/// A single entry in a title's holder history. Entries are either a bare
+/// character id or an object describing the transfer (eg: `type=conquest`).
+/// A missing holder signifies the title was destroyed or vacated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TitleHistory {
+    pub holder: Option<u32>,
+}
+
+impl<'de> Deserialize<'de> for TitleHistory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TitleHistoryVisitor;
+
+        impl<'de> de::Visitor<'de> for TitleHistoryVisitor {
+            type Value = TitleHistory;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a character id or a title history object")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(TitleHistory {
+                    holder: u32::try_from(v).ok(),
+                })
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(TitleHistory {
+                    holder: u32::try_from(v).ok(),
+                })
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(TitleHistory {
+                    holder: v.parse().ok(),
+                })
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Transfer {
+                    holder: Option<u32>,
+                }
+
+                let transfer = Transfer::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(TitleHistory {
+                    holder: transfer.holder,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(TitleHistoryVisitor)
+    }
+}
@@ -1,11 +1,20 @@
+use crate::gamestate::{Character, Ck3Gamestate, LandedTitle};
+use crate::models::{
+    Ck3CharacterGroupStats, Ck3CharacterStats, Ck3DeathReason, Ck3DynastyTree, Ck3HeldTitle,
+    Ck3HeldTitles, Ck3House, Ck3NameRef, Ck3PlayedCharacter, Ck3RealmSizePoint,
+    Ck3RealmSizeResponse, Ck3Skills, Ck3TitleRank, Ck3TitleRef, Ck3TreeCharacter,
+};
 use ck3save::{
     Ck3Error, Ck3File, Ck3Melt, DeserializeCk3, FailedResolveStrategy, MeltOptions, SaveHeader,
-    models::{Gamestate, Metadata},
 };
+use jomini::common::{Date, PdsDate};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::io::Cursor;
 use wasm_bindgen::prelude::*;
 
+mod gamestate;
+mod models;
 mod tokens;
 pub use tokens::*;
 
@@ -18,7 +27,7 @@ pub struct Ck3Metadata {
 
 #[derive(Debug)]
 pub struct SaveFileImpl {
-    meta_data: Metadata,
+    save: Ck3Gamestate,
     encoding: SaveHeader,
 }
 
@@ -36,12 +45,36 @@ impl SaveFile {
     pub fn metadata(&self) -> JsValue {
         to_json_value(&self.0.metadata())
     }
+
+    pub fn get_played_character(&self) -> Option<Ck3PlayedCharacter> {
+        self.0.played_character()
+    }
+
+    pub fn get_dynasty_tree(&self) -> Ck3DynastyTree {
+        self.0.dynasty_tree()
+    }
+
+    pub fn get_held_titles(&self) -> Ck3HeldTitles {
+        Ck3HeldTitles {
+            titles: self.0.held_titles(),
+        }
+    }
+
+    pub fn get_realm_size(&self) -> Ck3RealmSizeResponse {
+        Ck3RealmSizeResponse {
+            data: self.0.realm_size(),
+        }
+    }
+
+    pub fn get_character_stats(&self) -> Ck3CharacterStats {
+        self.0.character_stats()
+    }
 }
 
 impl SaveFileImpl {
     pub fn metadata(&self) -> Ck3Metadata {
         Ck3Metadata {
-            version: self.meta_data.version.clone(),
+            version: self.save.meta_data.version.clone(),
             is_meltable: self.is_meltable(),
         }
     }
@@ -49,13 +82,405 @@ impl SaveFileImpl {
     fn is_meltable(&self) -> bool {
         self.encoding.kind().is_binary()
     }
+
+    fn player_id(&self) -> Option<u32> {
+        self.save.played_character.as_ref().map(|x| x.character)
+    }
+
+    fn player_house(&self) -> Option<u32> {
+        self.player_id()
+            .and_then(|id| self.save.character(id))
+            .and_then(|x| x.dynasty_house)
+    }
+
+    fn title_ref(&self, id: u32, title: &LandedTitle) -> Ck3TitleRef {
+        Ck3TitleRef {
+            id,
+            key: title.key.clone(),
+            name: title.name.clone(),
+            rank: title_rank(&title.key),
+        }
+    }
+
+    fn house_ref(&self, id: u32) -> Ck3NameRef {
+        Ck3NameRef {
+            id,
+            name: self
+                .save
+                .house(id)
+                .and_then(|x| x.display_name())
+                .map(String::from),
+        }
+    }
+
+    fn dynasty_ref(&self, id: u32) -> Ck3NameRef {
+        Ck3NameRef {
+            id,
+            name: self
+                .save
+                .dynasty(id)
+                .and_then(|x| x.display_name())
+                .map(String::from),
+        }
+    }
+
+    pub fn played_character(&self) -> Option<Ck3PlayedCharacter> {
+        let played = self.save.played_character.as_ref()?;
+        let id = played.character;
+        let character = self.save.character(id)?;
+        let house = character.dynasty_house;
+        let dynasty = house
+            .and_then(|x| self.save.house(x))
+            .and_then(|x| x.dynasty);
+
+        let titles = self.held_titles();
+        let titles_held = titles.len();
+        let primary_title = titles.into_iter().next().map(|x| x.title);
+
+        Some(Ck3PlayedCharacter {
+            id,
+            player_name: played.name.clone(),
+            name: character.first_name.clone(),
+            female: character.female,
+            birth: character.birth.map(|x| x.iso_8601().to_string()),
+            age: character
+                .birth
+                .map(|birth| age_at(birth, character.death().unwrap_or(self.save.date))),
+            house: house.map(|x| self.house_ref(x)),
+            dynasty: dynasty.map(|x| self.dynasty_ref(x)),
+            skills: skills(&character.skill),
+            primary_title,
+            titles_held,
+        })
+    }
+
+    /// Titles held by the played character ordered from the highest rank
+    /// down. The first title is considered the primary title.
+    pub fn held_titles(&self) -> Vec<Ck3HeldTitle> {
+        let Some(player) = self.player_id() else {
+            return Vec::new();
+        };
+
+        let mut result: Vec<_> = self
+            .save
+            .titles()
+            .filter(|(_, title)| title.holder == Some(player))
+            .map(|(id, title)| Ck3HeldTitle {
+                title: self.title_ref(id, title),
+                de_facto_liege: title
+                    .de_facto_liege
+                    .and_then(|liege| self.save.title(liege).map(|x| self.title_ref(liege, x))),
+                de_jure_hierarchy: self.de_jure_hierarchy(title),
+            })
+            .collect();
+
+        result.sort_unstable_by(|a, b| {
+            b.title
+                .rank
+                .cmp(&a.title.rank)
+                .then_with(|| a.title.key.cmp(&b.title.key))
+        });
+        result
+    }
+
+    fn de_jure_hierarchy(&self, title: &LandedTitle) -> Vec<Ck3TitleRef> {
+        let mut result = Vec::new();
+        let mut seen = HashSet::new();
+        let mut current = title.de_jure_liege;
+        while let Some(id) = current {
+            // Guard against malformed saves where the hierarchy loops
+            if !seen.insert(id) {
+                break;
+            }
+
+            let Some(liege) = self.save.title(id) else {
+                break;
+            };
+
+            result.push(self.title_ref(id, liege));
+            current = liege.de_jure_liege;
+        }
+
+        result
+    }
+
+    pub fn dynasty_tree(&self) -> Ck3DynastyTree {
+        let player = self.player_id();
+        let player_house = self.player_house();
+        let dynasty = player_house
+            .and_then(|x| self.save.house(x))
+            .and_then(|x| x.dynasty);
+
+        let house_ids: HashSet<u32> = match dynasty {
+            Some(dynasty) => self
+                .save
+                .dynasties
+                .dynasty_house
+                .iter()
+                .filter_map(|(id, house)| house.as_ref().map(|h| (*id, h)))
+                .filter(|(_, house)| house.dynasty == Some(dynasty))
+                .map(|(id, _)| id)
+                .chain(player_house)
+                .collect(),
+            None => player_house.into_iter().collect(),
+        };
+
+        let mut members: HashMap<u32, Vec<(u32, &Character)>> = HashMap::new();
+        for (id, character) in self.save.characters() {
+            if let Some(house) = character.dynasty_house.filter(|x| house_ids.contains(x)) {
+                members.entry(house).or_default().push((id, character));
+            }
+        }
+
+        let member_ids: HashSet<u32> = members
+            .values()
+            .flat_map(|x| x.iter().map(|(id, _)| *id))
+            .chain(player)
+            .collect();
+
+        let mut houses: Vec<_> = house_ids
+            .iter()
+            .map(|&id| {
+                let house = self.save.house(id);
+                let mut house_members = members.remove(&id).unwrap_or_default();
+                house_members.sort_unstable_by_key(|(id, character)| (character.birth, *id));
+                Ck3House {
+                    id,
+                    name: house.and_then(|x| x.display_name()).map(String::from),
+                    head_of_house: house.and_then(|x| x.head_of_house),
+                    is_player_house: Some(id) == player_house,
+                    members: house_members
+                        .into_iter()
+                        .map(|(id, character)| Ck3TreeCharacter {
+                            id,
+                            name: character.first_name.clone(),
+                            female: character.female,
+                            birth: character.birth.map(|x| x.iso_8601().to_string()),
+                            death: character.death().map(|x| x.iso_8601().to_string()),
+                            is_alive: self.save.living.contains_key(&id),
+                            children: character
+                                .children()
+                                .iter()
+                                .copied()
+                                .filter(|x| member_ids.contains(x))
+                                .collect(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        houses.sort_unstable_by_key(|x| (!x.is_player_house, x.id));
+
+        Ck3DynastyTree {
+            dynasty: dynasty.map(|x| self.dynasty_ref(x)),
+            houses,
+        }
+    }
+
+    /// Number of counties held by members of the played character's house
+    /// sampled yearly from the bookmark date. Title history only records
+    /// holders, so counties held by vassals outside the house are not
+    /// counted.
+    pub fn realm_size(&self) -> Vec<Ck3RealmSizePoint> {
+        let Some(player_house) = self.player_house() else {
+            return Vec::new();
+        };
+
+        let house_members: HashSet<u32> = self
+            .save
+            .characters()
+            .filter(|(_, character)| character.dynasty_house == Some(player_house))
+            .map(|(id, _)| id)
+            .collect();
+
+        let counties: Vec<_> = self
+            .save
+            .titles()
+            .map(|(_, title)| title)
+            .filter(|title| title_rank(&title.key) == Ck3TitleRank::County)
+            .collect();
+
+        let held_at = |date: Date| {
+            counties
+                .iter()
+                .filter_map(|title| title.history.range(..=date).next_back())
+                .filter_map(|(_, entry)| entry.holder)
+                .filter(|holder| house_members.contains(holder))
+                .count()
+        };
+
+        let end = self.save.date;
+        let start = self.save.bookmark_date.unwrap_or(end);
+        let mut result: Vec<_> = (start.year()..end.year())
+            .map(|year| Date::from_ymd(year, start.month(), start.day()))
+            .map(|date| Ck3RealmSizePoint {
+                date: date.iso_8601().to_string(),
+                counties: held_at(date),
+            })
+            .collect();
+
+        let current = counties
+            .iter()
+            .filter_map(|title| title.holder)
+            .filter(|holder| house_members.contains(holder))
+            .count();
+
+        result.push(Ck3RealmSizePoint {
+            date: end.iso_8601().to_string(),
+            counties: current,
+        });
+
+        result
+    }
+
+    pub fn character_stats(&self) -> Ck3CharacterStats {
+        let date = self.save.date;
+        let living = group_stats(self.save.living.values(), |x| {
+            x.birth.map(|b| age_at(b, date))
+        });
+        let dead = group_stats(self.save.dead_unprunable.values(), |x| {
+            x.birth.zip(x.death()).map(|(b, d)| age_at(b, d))
+        });
+
+        let mut reasons: HashMap<&str, usize> = HashMap::new();
+        for reason in self
+            .save
+            .dead_unprunable
+            .values()
+            .filter_map(|x| x.dead_data.as_ref())
+            .filter_map(|x| x.reason.as_deref())
+        {
+            *reasons.entry(reason).or_default() += 1;
+        }
+
+        let mut death_reasons: Vec<_> = reasons
+            .into_iter()
+            .map(|(reason, count)| Ck3DeathReason {
+                reason: String::from(reason),
+                count,
+            })
+            .collect();
+        death_reasons.sort_unstable_by(|a, b| b.count.cmp(&a.count).then(a.reason.cmp(&b.reason)));
+
+        Ck3CharacterStats {
+            living,
+            dead,
+            death_reasons,
+        }
+    }
+}
+
+fn title_rank(key: &str) -> Ck3TitleRank {
+    match key.split_once('_').map(|(prefix, _)| prefix) {
+        Some("b") => Ck3TitleRank::Barony,
+        Some("c") => Ck3TitleRank::County,
+        Some("d") => Ck3TitleRank::Duchy,
+        Some("k") => Ck3TitleRank::Kingdom,
+        Some("e") => Ck3TitleRank::Empire,
+        Some("h") => Ck3TitleRank::Hegemony,
+        _ => Ck3TitleRank::Other,
+    }
+}
+
+fn age_at(birth: Date, date: Date) -> i32 {
+    let years = i32::from(date.year()) - i32::from(birth.year());
+    if (date.month(), date.day()) < (birth.month(), birth.day()) {
+        years - 1
+    } else {
+        years
+    }
+}
+
+/// Skills are stored in the order: diplomacy, martial, stewardship,
+/// intrigue, learning, and prowess
+fn skills(values: &[i32]) -> Option<Ck3Skills> {
+    match values {
+        [
+            diplomacy,
+            martial,
+            stewardship,
+            intrigue,
+            learning,
+            prowess,
+            ..,
+        ] => Some(Ck3Skills {
+            diplomacy: f64::from(*diplomacy),
+            martial: f64::from(*martial),
+            stewardship: f64::from(*stewardship),
+            intrigue: f64::from(*intrigue),
+            learning: f64::from(*learning),
+            prowess: f64::from(*prowess),
+        }),
+        _ => None,
+    }
+}
+
+fn group_stats<'a>(
+    characters: impl Iterator<Item = &'a Character>,
+    age: impl Fn(&Character) -> Option<i32>,
+) -> Ck3CharacterGroupStats {
+    let mut result = Ck3CharacterGroupStats::default();
+    let mut total_age = 0i64;
+    let mut aged = 0usize;
+    let mut total_skills = [0f64; 6];
+    let mut skilled = 0usize;
+
+    for character in characters {
+        result.count += 1;
+        if character.female {
+            result.female += 1;
+        } else {
+            result.male += 1;
+        }
+
+        if let Some(age) = age(character) {
+            total_age += i64::from(age);
+            aged += 1;
+        }
+
+        if let Some(skills) = skills(&character.skill) {
+            let values = [
+                skills.diplomacy,
+                skills.martial,
+                skills.stewardship,
+                skills.intrigue,
+                skills.learning,
+                skills.prowess,
+            ];
+
+            for (total, value) in total_skills.iter_mut().zip(values) {
+                *total += value;
+            }
+            skilled += 1;
+        }
+    }
+
+    if aged > 0 {
+        result.average_age = Some(total_age as f64 / aged as f64);
+    }
+
+    if skilled > 0 {
+        let [diplomacy, martial, stewardship, intrigue, learning, prowess] =
+            total_skills.map(|x| x / skilled as f64);
+        result.average_skills = Some(Ck3Skills {
+            diplomacy,
+            martial,
+            stewardship,
+            intrigue,
+            learning,
+            prowess,
+        });
+    }
+
+    result
 }
 
 fn _parse_save(data: &[u8]) -> Result<SaveFile, Ck3Error> {
     let file = Ck3File::from_slice(data)?;
-    let save: Gamestate = (&file).deserialize(tokens::get_tokens())?;
+    let save: Ck3Gamestate = (&file).deserialize(tokens::get_tokens())?;
     Ok(SaveFile(SaveFileImpl {
-        meta_data: save.meta_data,
+        save,
         encoding: file.header().clone(),
     }))
 }
@@ -80,3 +505,161 @@ pub fn melt(data: &[u8]) -> Result<js_sys::Uint8Array, JsError> {
         .map(|x| js_sys::Uint8Array::from(x.as_slice()))
         .map_err(JsError::from)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAVE: &str = r#"SAV0100000000000000000000
+meta_data={
+    version="1.12.4"
+}
+date=1100.3.1
+bookmark_date=1098.3.1
+played_character={
+    name="Player"
+    character=1
+}
+living={
+    1={
+        first_name="William"
+        birth=1028.6.1
+        dynasty_house=10
+        skill={ 10 12 8 6 4 9 }
+        family_data={ child={ 2 4 } }
+    }
+    2={
+        first_name="Adela"
+        birth=1062.1.1
+        female=yes
+        dynasty_house=10
+        skill={ 6 2 8 10 12 3 }
+    }
+    4={
+        first_name="Harold"
+        birth=1070.1.1
+        dynasty_house=11
+    }
+}
+dead_unprunable={
+    3={
+        first_name="Robert"
+        birth=1000.6.1
+        dynasty_house=10
+        family_data={ child={ 1 } }
+        dead_data={ date=1035.7.2 reason="death_natural_causes" }
+    }
+}
+dynasties={
+    dynasty_house={
+        10={ name="Normandie" dynasty=20 head_of_house=1 }
+        11={ name="Godwin" dynasty=21 }
+        12=none
+    }
+    dynasties={
+        20={ key="dynn_normandie" }
+        21=none
+    }
+}
+landed_titles={
+    landed_titles={
+        100={ key="k_england" holder=1 history={ 1099.1.1=1 } }
+        101={ key="d_essex" de_jure_liege=100 }
+        102={
+            key="c_middlesex"
+            holder=1
+            de_jure_liege=101
+            de_facto_liege=100
+            history={ 1090.1.1=4 1099.6.1={ type=conquest holder=1 } }
+        }
+        103={ key="c_rouen" holder=2 history={ 1040.1.1=3 1050.1.1=1 1099.9.1=2 } }
+        104=none
+    }
+}
+"#;
+
+    fn save() -> SaveFileImpl {
+        _parse_save(SAVE.as_bytes()).unwrap().0
+    }
+
+    #[test]
+    fn test_played_character() {
+        let save = save();
+        let played = save.played_character().unwrap();
+        assert_eq!(played.id, 1);
+        assert_eq!(played.player_name.as_deref(), Some("Player"));
+        assert_eq!(played.name, "William");
+        assert_eq!(played.age, Some(71));
+        assert_eq!(played.house.unwrap().name.as_deref(), Some("Normandie"));
+        assert_eq!(
+            played.dynasty.unwrap().name.as_deref(),
+            Some("dynn_normandie")
+        );
+        assert_eq!(played.skills.unwrap().martial, 12.0);
+        assert_eq!(played.primary_title.unwrap().key, "k_england");
+        assert_eq!(played.titles_held, 2);
+    }
+
+    #[test]
+    fn test_held_titles() {
+        let titles = save().held_titles();
+        let keys: Vec<_> = titles.iter().map(|x| x.title.key.as_str()).collect();
+        assert_eq!(keys, vec!["k_england", "c_middlesex"]);
+
+        let county = &titles[1];
+        assert_eq!(county.title.rank, Ck3TitleRank::County);
+        assert_eq!(county.de_facto_liege.as_ref().unwrap().key, "k_england");
+        let hierarchy: Vec<_> = county
+            .de_jure_hierarchy
+            .iter()
+            .map(|x| x.key.as_str())
+            .collect();
+        assert_eq!(hierarchy, vec!["d_essex", "k_england"]);
+    }
+
+    #[test]
+    fn test_dynasty_tree() {
+        let tree = save().dynasty_tree();
+        assert_eq!(tree.dynasty.unwrap().id, 20);
+        assert_eq!(tree.houses.len(), 1);
+
+        let house = &tree.houses[0];
+        assert!(house.is_player_house);
+        assert_eq!(house.head_of_house, Some(1));
+
+        let members: Vec<_> = house.members.iter().map(|x| x.id).collect();
+        assert_eq!(members, vec![3, 1, 2]);
+        assert!(!house.members[0].is_alive);
+        assert_eq!(house.members[0].death.as_deref(), Some("1035-07-02"));
+
+        // Children outside of the dynasty are not part of the tree
+        assert_eq!(house.members[1].children, vec![2]);
+    }
+
+    #[test]
+    fn test_realm_size() {
+        let points = save().realm_size();
+        let counties: Vec<_> = points
+            .iter()
+            .map(|x| (x.date.as_str(), x.counties))
+            .collect();
+        assert_eq!(
+            counties,
+            vec![("1098-03-01", 1), ("1099-03-01", 1), ("1100-03-01", 2)]
+        );
+    }
+
+    #[test]
+    fn test_character_stats() {
+        let stats = save().character_stats();
+        assert_eq!(stats.living.count, 3);
+        assert_eq!(stats.living.female, 1);
+        assert_eq!(stats.living.male, 2);
+        assert_eq!(stats.living.average_skills.unwrap().learning, 8.0);
+
+        assert_eq!(stats.dead.count, 1);
+        assert_eq!(stats.dead.average_age, Some(35.0));
+        assert_eq!(stats.death_reasons.len(), 1);
+        assert_eq!(stats.death_reasons[0].reason, "death_natural_causes");
+    }
+}
@@ -0,0 +1,149 @@
+#![allow(nonstandard_style)]
+#![allow(clippy::empty_docs)]
+
+use serde::Serialize;
+use tsify::Tsify;
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Ck3PlayedCharacter {
+    pub id: u32,
+    pub player_name: Option<String>,
+    pub name: String,
+    pub female: bool,
+    pub birth: Option<String>,
+    pub age: Option<i32>,
+    pub house: Option<Ck3NameRef>,
+    pub dynasty: Option<Ck3NameRef>,
+    pub skills: Option<Ck3Skills>,
+    pub primary_title: Option<Ck3TitleRef>,
+    pub titles_held: usize,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3NameRef {
+    pub id: u32,
+    pub name: Option<String>,
+}
+
+#[derive(Tsify, Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3Skills {
+    pub diplomacy: f64,
+    pub martial: f64,
+    pub stewardship: f64,
+    pub intrigue: f64,
+    pub learning: f64,
+    pub prowess: f64,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Ck3DynastyTree {
+    pub dynasty: Option<Ck3NameRef>,
+    pub houses: Vec<Ck3House>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3House {
+    pub id: u32,
+    pub name: Option<String>,
+    pub head_of_house: Option<u32>,
+    pub is_player_house: bool,
+    pub members: Vec<Ck3TreeCharacter>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3TreeCharacter {
+    pub id: u32,
+    pub name: String,
+    pub female: bool,
+    pub birth: Option<String>,
+    pub death: Option<String>,
+    pub is_alive: bool,
+    pub children: Vec<u32>,
+}
+
+#[derive(Tsify, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Ck3TitleRank {
+    Other,
+    Barony,
+    County,
+    Duchy,
+    Kingdom,
+    Empire,
+    Hegemony,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3TitleRef {
+    pub id: u32,
+    pub key: String,
+    pub name: Option<String>,
+    pub rank: Ck3TitleRank,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3HeldTitle {
+    pub title: Ck3TitleRef,
+    pub de_facto_liege: Option<Ck3TitleRef>,
+
+    /// The de jure lieges of the title ordered from the direct liege up to
+    /// the top of the hierarchy
+    pub de_jure_hierarchy: Vec<Ck3TitleRef>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Ck3HeldTitles {
+    pub titles: Vec<Ck3HeldTitle>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3RealmSizePoint {
+    pub date: String,
+    pub counties: usize,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Ck3RealmSizeResponse {
+    pub data: Vec<Ck3RealmSizePoint>,
+}
+
+#[derive(Tsify, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3CharacterGroupStats {
+    pub count: usize,
+    pub female: usize,
+    pub male: usize,
+    pub average_age: Option<f64>,
+    pub average_skills: Option<Ck3Skills>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ck3DeathReason {
+    pub reason: String,
+    pub count: usize,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Ck3CharacterStats {
+    pub living: Ck3CharacterGroupStats,
+    pub dead: Ck3CharacterGroupStats,
+    pub death_reasons: Vec<Ck3DeathReason>,
+}
@@ -3,7 +3,8 @@ use crate::{
         CountriesCasualties, CountriesExpenses, CountriesIncome, CountryCultures, CountryInfoList,
         CountryLeaders, CountryStateDetailsList, CountryTags, Estates, GreatPowers, IdeaGroups,
         LocalizedTags, MetaRef, OptionalCountryTag, OwnedDevelopmentStatesList, PlayerHistories,
-        ProvinceList, RunningMonarchs, SingleCountryWarCasualtiesList, StaticMap, StringList, Wars,
+        ProvinceList, RunningMonarchs, SingleCountryWarCasualtiesList, StaticMap, StringList,
+        TradeNodes, Wars,
     },
     savefile::{CountryHistory, CountryInstitution, CountryMana, SaveInfo},
 };
@@ -16,9 +17,10 @@ use eu4save::{
 use models::{CountriesManaExpenditure, CountryDevEfficiencies};
 use savefile::{
     AchievementsScore, CountryAdvisors, CountryDetails, CountryReligions, Estate,
-    FileObservationFrequency, HealthData, LocalizedLedger, MapCursorPayload, MapPayload,
-    MapPayloadKind, MapQuickTipPayload, Monitor, ProvinceDetails, ProvinceDevDensity, Reparse,
-    RootTree, SaveFileImpl, TagFilterPayloadRaw, WarInfo,
+    FileObservationFrequency, HealthData, InstitutionForecast, LocalizedLedger, MapCursorPayload,
+    MapPayload, MapPayloadKind, MapQuickTipPayload, Monitor, ProvinceDetails, ProvinceDevDensity,
+    Reparse, RootTree, SaveDiff, SaveFileImpl, TagExpr, TagFilterPayloadRaw, WarInfo,
+    WarScoreEstimate,
 };
 use std::{collections::HashMap, io::Cursor};
 use wasm_bindgen::prelude::*;
@@ -218,6 +220,15 @@ impl SaveFile {
         )
     }
 
+    pub fn get_institution_forecast(
+        &self,
+        tag: &str,
+        institution: usize,
+        planned: JsValue,
+    ) -> Option<InstitutionForecast> {
+        self.0.institution_forecast(tag, institution, planned)
+    }
+
     pub fn get_country_province_religion(&self, tag: &str) -> CountryReligions {
         self.0.get_country_province_religion(tag)
     }
@@ -312,6 +323,14 @@ impl SaveFile {
         self.0.wars(payload).into()
     }
 
+    pub fn get_trade_nodes(&self, payload: TagFilterPayloadRaw) -> TradeNodes {
+        self.0.get_trade_nodes(payload).into()
+    }
+
+    pub fn diff(&self, other: &SaveFile) -> Result<SaveDiff, JsError> {
+        self.0.diff(&other.0).map_err(JsError::from)
+    }
+
     pub fn get_country_casualties(&self, tag: &str) -> SingleCountryWarCasualtiesList {
         self.0.get_country_casualties(tag).into()
     }
@@ -320,6 +339,10 @@ impl SaveFile {
         self.0.get_war(&war_name)
     }
 
+    pub fn get_war_score_estimate(&self, war_name: String) -> Option<WarScoreEstimate> {
+        self.0.get_war_score_estimate(&war_name)
+    }
+
     pub fn monitoring_data(&self) -> Monitor {
         self.0.monitoring_data()
     }
@@ -336,6 +359,16 @@ pub fn parse_meta(data: &[u8]) -> Result<eu4save::models::Meta, JsError> {
     eu4game::shared::parse_meta(data, tokens).map_err(JsError::from)
 }
 
+/// Returns why a tag filter expression is invalid, or nothing if it is valid
+#[wasm_bindgen]
+pub fn validate_tag_filter_expression(expression: &str) -> Option<String> {
+    if expression.trim().is_empty() {
+        return None;
+    }
+
+    expression.parse::<TagExpr>().err().map(|e| e.to_string())
+}
+
 #[wasm_bindgen]
 pub fn parse_save(
     save_data: Vec<u8>,
@@ -10,7 +10,7 @@ use crate::savefile::{
     CountryCasualties, CountryCulture, CountryDevEffiency, CountryInfo, CountryLeader,
     CountryManaExpenditure, CountryStateDetails, Estate, GreatPower, IdeaGroup,
     LocalizedCountryExpense, LocalizedCountryIncome, LocalizedTag, OwnedDevelopmentStates,
-    PlayerHistory, ProvinceItem, RunningMonarch, SingleCountryWarCasualties, War,
+    PlayerHistory, ProvinceItem, RunningMonarch, SingleCountryWarCasualties, TradeNodeInfo, War,
 };
 
 /// Looks like bindgen doesn't include generics in the typescript signature
@@ -59,3 +59,4 @@ wasm_wrapper!(StaticMap, HashMap<&'static str, &'static str>);
 wasm_wrapper!(ProvinceList, Vec<ProvinceItem>);
 wasm_wrapper!(CountryDevEfficiencies, Vec<CountryDevEffiency>);
 wasm_wrapper!(CountriesManaExpenditure, Vec<CountryManaExpenditure>);
+wasm_wrapper!(TradeNodes, Vec<TradeNodeInfo>);
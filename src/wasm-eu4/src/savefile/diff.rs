@@ -0,0 +1,74 @@
+use super::{CountryDiff, DiffValue, IdeaGroupDiff, RulerChange, SaveDiff, SaveFileImpl};
+use eu4game::{Eu4GameError, diff};
+
+impl From<diff::Delta<f32>> for DiffValue {
+    fn from(value: diff::Delta<f32>) -> Self {
+        DiffValue {
+            before: value.before,
+            after: value.after,
+        }
+    }
+}
+
+impl From<diff::Delta<u8>> for DiffValue {
+    fn from(value: diff::Delta<u8>) -> Self {
+        DiffValue {
+            before: f32::from(value.before),
+            after: f32::from(value.after),
+        }
+    }
+}
+
+impl SaveFileImpl {
+    /// Compares this save with another save of the same playthrough.
+    /// Countries are localized with the later of the two saves.
+    pub fn diff(&self, other: &SaveFileImpl) -> Result<SaveDiff, Eu4GameError> {
+        let result = diff::diff_saves(&self.query, &other.query)?;
+        let later = if self.query.save().meta.date >= other.query.save().meta.date {
+            self
+        } else {
+            other
+        };
+
+        let countries = result
+            .countries
+            .into_iter()
+            .map(|x| CountryDiff {
+                country: later.localize_tag(x.tag),
+                development: x.development.into(),
+                provinces_gained: x.provinces_gained,
+                provinces_lost: x.provinces_lost,
+                income: x.income.into(),
+                manpower: x.manpower.into(),
+                adm_tech: x.adm_tech.into(),
+                dip_tech: x.dip_tech.into(),
+                mil_tech: x.mil_tech.into(),
+                ideas: x
+                    .ideas
+                    .into_iter()
+                    .map(|group| IdeaGroupDiff {
+                        name: group.name,
+                        before: group.ideas.before,
+                        after: group.ideas.after,
+                    })
+                    .collect(),
+                wars_started: x.wars_started,
+                wars_ended: x.wars_ended,
+                rulers: x
+                    .rulers
+                    .into_iter()
+                    .map(|ruler| RulerChange {
+                        date: ruler.date,
+                        name: ruler.name,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(SaveDiff {
+            before: result.before,
+            after: result.after,
+            countries,
+        })
+    }
+}
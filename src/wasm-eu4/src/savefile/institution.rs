@@ -1,7 +1,13 @@
-use super::{CountryInstitution, InstitutionCost, SaveFileImpl};
+use super::{
+    CountryInstitution, InstitutionCost, InstitutionForecast, InstitutionForecastPoint,
+    SaveFileImpl,
+};
 use eu4game::ProvinceExt;
-use eu4save::{CountryTag, ProvinceId, models::Province};
-use std::{collections::HashMap, ops};
+use eu4save::{CountryTag, Eu4Date, PdsDate, ProvinceId, models::Province};
+use std::{
+    collections::{HashMap, HashSet},
+    ops,
+};
 use wasm_bindgen::JsValue;
 
 // The minimum development required to be able to exploit a province
@@ -483,6 +489,225 @@ impl SaveFileImpl {
     }
 }
 
+// Share of a country's development that must have an institution before it
+// can be embraced
+const EMBRACE_THRESHOLD: f32 = 0.1;
+
+// How far into the future institution spread is projected
+const FORECAST_MONTHS: i32 = 50 * 12;
+
+// Estimated monthly institution progress from a neighboring province with
+// the institution. The spread rates below are approximations of the game's
+// spread modifiers, not values read from the game defines.
+const NEIGHBOR_SPREAD: f32 = 0.5;
+
+// Estimated monthly institution progress per point of province development
+const DEVELOPMENT_SPREAD: f32 = 0.02;
+
+// Estimated monthly institution progress from a center of trade with the
+// institution in the same trade node
+const TRADE_NODE_SPREAD: f32 = 0.25;
+
+#[derive(Debug, Clone)]
+struct SpreadProvince<'a> {
+    id: ProvinceId,
+    dev: f32,
+    progress: f32,
+    neighbors: Vec<ProvinceId>,
+    node: Option<&'a str>,
+    center_of_trade: bool,
+
+    /// Owned by the forecasted country
+    owned: bool,
+}
+
+impl SpreadProvince<'_> {
+    fn has_institution(&self) -> bool {
+        self.progress >= 100.0
+    }
+}
+
+/// Where an institution can spread from: provinces with the institution and
+/// trade nodes with a center of trade that has it.
+#[derive(Debug, Clone, Default)]
+struct SpreadSources<'a> {
+    provinces: HashSet<ProvinceId>,
+    nodes: HashSet<&'a str>,
+}
+
+impl<'a> SpreadSources<'a> {
+    fn add(&mut self, province: &SpreadProvince<'a>) {
+        self.provinces.insert(province.id);
+        if province.center_of_trade {
+            self.nodes.extend(province.node);
+        }
+    }
+
+    fn monthly_growth(&self, province: &SpreadProvince) -> f32 {
+        let neighbor = if province
+            .neighbors
+            .iter()
+            .any(|x| self.provinces.contains(x))
+        {
+            NEIGHBOR_SPREAD
+        } else {
+            0.0
+        };
+        let node = province
+            .node
+            .filter(|x| self.nodes.contains(x))
+            .map_or(0.0, |_| TRADE_NODE_SPREAD);
+        neighbor + node + province.dev * DEVELOPMENT_SPREAD
+    }
+}
+
+/// Projects the share of the country's development with the institution at
+/// the end of each month. Every given province is simulated, so the
+/// institution can reach the country through foreign provinces that don't
+/// have it yet.
+fn project_spread<'a>(
+    mut provinces: Vec<SpreadProvince<'a>>,
+    mut sources: SpreadSources<'a>,
+) -> Vec<f32> {
+    let total_dev: f32 = provinces.iter().filter(|x| x.owned).map(|x| x.dev).sum();
+    let mut shares = Vec::with_capacity(FORECAST_MONTHS as usize);
+    for _ in 0..FORECAST_MONTHS {
+        for province in provinces.iter_mut().filter(|x| !x.has_institution()) {
+            province.progress += sources.monthly_growth(province);
+        }
+
+        for province in provinces.iter().filter(|x| x.has_institution()) {
+            sources.add(province);
+        }
+
+        let dev: f32 = provinces
+            .iter()
+            .filter(|x| x.owned && x.has_institution())
+            .map(|x| x.dev)
+            .sum();
+        shares.push(dev / total_dev.max(1.0));
+    }
+
+    shares
+}
+
+fn add_months(date: Eu4Date, months: i32) -> Eu4Date {
+    let months = i32::from(date.year()) * 12 + i32::from(date.month()) - 1 + months;
+    Eu4Date::from_ymd((months / 12) as i16, (months % 12 + 1) as u8, 1)
+}
+
+impl SaveFileImpl {
+    /// Estimates when a country will be able to embrace an institution, both
+    /// from natural spread alone and after dev pushing the planned provinces
+    /// until the institution spawns in them.
+    pub fn institution_forecast(
+        &self,
+        tag: &str,
+        institution: usize,
+        planned: JsValue,
+    ) -> Option<InstitutionForecast> {
+        let planned: HashSet<ProvinceId> = serde_wasm_bindgen::from_value(planned).unwrap();
+        let tag = tag.parse::<CountryTag>().ok()?;
+        let country = self.query.country(&tag)?;
+        let save = self.query.save();
+
+        let spawned = save
+            .game
+            .institutions
+            .get(institution)
+            .copied()
+            .unwrap_or(0);
+        let embraced = country.institutions.get(institution).copied().unwrap_or(0);
+        if spawned == 0 || embraced != 0 {
+            return None;
+        }
+
+        let areas = self.game.province_area_lookup();
+        let mut sources = SpreadSources::default();
+        let mut provinces = Vec::new();
+        let mut planned_provinces = Vec::new();
+        for (id, prov) in &save.game.provinces {
+            let mut neighbors: Vec<_> = self.game.neighbors(id).collect();
+            if neighbors.is_empty() {
+                // Game data compiled before adjacencies were recorded has no
+                // neighbors, so spread between provinces of the same area
+                let area = areas.get(id).and_then(|x| self.game.area_provinces(x));
+                neighbors = area.into_iter().flatten().filter(|x| x != id).collect();
+            }
+
+            let province = SpreadProvince {
+                id: *id,
+                dev: prov.base_tax + prov.base_production + prov.base_manpower,
+                progress: prov.institutions.get(institution).copied().unwrap_or(0.0),
+                neighbors,
+                node: prov.trade.as_deref(),
+                center_of_trade: prov.center_of_trade > 0,
+                owned: prov.owner == Some(tag),
+            };
+
+            if province.has_institution() {
+                sources.add(&province);
+            }
+
+            let mut pushed = province.clone();
+            if pushed.owned && planned.contains(id) && !pushed.has_institution() {
+                let final_dev = end_dev(pushed.dev as i32, f64::from(pushed.progress));
+                pushed.dev = pushed.dev.max(final_dev as f32);
+                pushed.progress = 100.0;
+            }
+
+            provinces.push(province);
+            planned_provinces.push(pushed);
+        }
+
+        let total_dev: f32 = provinces.iter().filter(|x| x.owned).map(|x| x.dev).sum();
+        let current_dev: f32 = provinces
+            .iter()
+            .filter(|x| x.owned && x.has_institution())
+            .map(|x| x.dev)
+            .sum();
+
+        let mut planned_sources = sources.clone();
+        for province in planned_provinces.iter().filter(|x| x.has_institution()) {
+            planned_sources.add(province);
+        }
+
+        let natural = project_spread(provinces, sources);
+        let pushed = project_spread(planned_provinces, planned_sources);
+        let date = save.meta.date;
+        let embrace_date = |shares: &[f32]| {
+            shares
+                .iter()
+                .position(|&x| x >= EMBRACE_THRESHOLD)
+                .map(|month| add_months(date, month as i32 + 1))
+        };
+
+        let timeline = natural
+            .iter()
+            .zip(pushed.iter())
+            .enumerate()
+            .filter(|(month, _)| month % 12 == 11)
+            .map(
+                |(month, (&share, &planned_share))| InstitutionForecastPoint {
+                    date: add_months(date, month as i32 + 1),
+                    share,
+                    planned_share,
+                },
+            )
+            .collect();
+
+        Some(InstitutionForecast {
+            institution: institution as i32,
+            total_dev,
+            current_share: current_dev / total_dev.max(1.0),
+            threshold: EMBRACE_THRESHOLD,
+            embrace_date: embrace_date(&natural),
+            planned_embrace_date: embrace_date(&pushed),
+            timeline,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -853,4 +1078,68 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_add_months() {
+        let date = Eu4Date::from_ymd(1444, 11, 11);
+        assert_eq!(add_months(date, 1), Eu4Date::from_ymd(1444, 12, 1));
+        assert_eq!(add_months(date, 2), Eu4Date::from_ymd(1445, 1, 1));
+        assert_eq!(add_months(date, 14), Eu4Date::from_ymd(1446, 1, 1));
+    }
+
+    #[test]
+    fn test_project_spread_along_neighbors() {
+        let province = |id: i32, neighbors: &[i32]| SpreadProvince {
+            id: ProvinceId::new(id),
+            dev: 10.0,
+            progress: 0.0,
+            neighbors: neighbors.iter().map(|&x| ProvinceId::new(x)).collect(),
+            node: None,
+            center_of_trade: false,
+            owned: true,
+        };
+
+        // A foreign province (1) with the institution borders 2, which
+        // borders 3
+        let provinces = vec![province(2, &[1, 3]), province(3, &[2])];
+        let mut sources = SpreadSources::default();
+        sources.provinces.insert(ProvinceId::new(1));
+
+        let shares = project_spread(provinces, sources);
+        let neighbor_month = shares.iter().position(|&x| x >= 0.5).unwrap();
+        let all_month = shares.iter().position(|&x| x >= 1.0).unwrap();
+
+        // Growth is 0.7 a month next to the institution and 0.2 otherwise, so
+        // province 3 would need 500 months without its neighbor
+        assert_eq!(neighbor_month, 142);
+        assert!(all_month < 300);
+        assert_eq!(shares.len(), FORECAST_MONTHS as usize);
+    }
+
+    #[test]
+    fn test_project_spread_through_foreign_provinces() {
+        let province = |id: i32, neighbors: &[i32], owned: bool| SpreadProvince {
+            id: ProvinceId::new(id),
+            dev: 10.0,
+            progress: 0.0,
+            neighbors: neighbors.iter().map(|&x| ProvinceId::new(x)).collect(),
+            node: None,
+            center_of_trade: false,
+            owned,
+        };
+
+        // The institution has to cross the foreign province 2 to reach the
+        // owned province 3
+        let provinces = vec![province(2, &[1, 3], false), province(3, &[2], true)];
+        let mut sources = SpreadSources::default();
+        sources.provinces.insert(ProvinceId::new(1));
+
+        let shares = project_spread(provinces, sources);
+        let month = shares.iter().position(|&x| x >= 1.0).unwrap();
+
+        // Province 2 gets the institution after 143 months, after which 3
+        // grows at 0.7 a month instead of 0.2
+        assert!(month > 142 && month < 300, "{}", month);
+        assert!(shares[..142].iter().all(|&x| x == 0.0));
+    }
 }
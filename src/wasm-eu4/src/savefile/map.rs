@@ -5,7 +5,7 @@ use super::{
 use crate::savefile::Interval;
 use eu4save::{
     CountryTag, Eu4Date, PdsDate, ProvinceId,
-    models::{CountryEvent, Province},
+    models::{CountryEvent, Province, ProvinceEvent, ProvinceEventValue},
     query::ReligionIndex,
 };
 use std::{collections::HashMap, str::FromStr};
@@ -13,6 +13,40 @@ use wasm_bindgen::prelude::*;
 
 pub const WASTELAND: [u8; 4] = [61, 61, 61, 0];
 
+/// Development that can't be placed in time from the save's history
+const UNKNOWN_DEVELOPMENT: [u8; 4] = [200, 200, 200, 255];
+
+/// Cultures and trade nodes don't have a color in the game files, so derive a
+/// stable one from their name
+fn name_color(name: &str) -> [u8; 4] {
+    let hash = name.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    });
+
+    let hue = (hash % 360) as f64;
+    let saturation = 0.45 + ((hash >> 16) % 30) as f64 / 100.0;
+    let value = 0.65 + ((hash >> 32) % 30) as f64 / 100.0;
+
+    let c = value * saturation;
+    let x = c * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let m = value - c;
+    let (r, g, b) = match (hue / 60.0) as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    [
+        ((r + m) * 255.0).round() as u8,
+        ((g + m) * 255.0).round() as u8,
+        ((b + m) * 255.0).round() as u8,
+        255,
+    ]
+}
+
 impl SaveFileImpl {
     pub fn initial_map_position(&self) -> (u16, u16) {
         let save = self.query.save();
@@ -229,6 +263,69 @@ impl SaveFileImpl {
                 })
             }
 
+            MapPayloadKind::Culture => {
+                let culture_in_province_id = 'prov_culture: {
+                    let Some(date) = requested_date else {
+                        break 'prov_culture province.culture.clone()?;
+                    };
+                    let latest_culture = province
+                        .history
+                        .events
+                        .iter()
+                        .take_while(|(cdate, _c)| *cdate <= date)
+                        .filter_map(|(_date, event)| match event {
+                            ProvinceEvent::KV((key, ProvinceEventValue::String(x)))
+                                if key == "culture" =>
+                            {
+                                Some(x)
+                            }
+                            _ => None,
+                        })
+                        .last()
+                        .cloned();
+
+                    latest_culture
+                        .or_else(|| province.history.culture.clone())
+                        .or_else(|| province.culture.clone())?
+                };
+
+                let owner = self.query.country(&stored_owner_tag)?;
+                let primary_culture_id = 'primary_culture: {
+                    let Some(date) = requested_date else {
+                        break 'primary_culture owner.primary_culture.clone();
+                    };
+                    let latest_culture = owner
+                        .history
+                        .events
+                        .iter()
+                        .take_while(|(cdate, _c)| *cdate <= date)
+                        .filter_map(|(_date, event)| match event {
+                            CountryEvent::PrimaryCulture(c) => Some(c),
+                            _ => None,
+                        })
+                        .last()
+                        .cloned();
+
+                    latest_culture
+                        .or_else(|| owner.history.primary_culture.clone())
+                        .or_else(|| owner.primary_culture.clone())
+                };
+
+                let localize_culture = |id: String| LocalizedObj {
+                    name: String::from(self.game.localize(&id).unwrap_or(&id)),
+                    id,
+                };
+
+                Some(MapQuickTipPayload::Culture {
+                    province_id,
+                    province_name: province.name.clone(),
+                    owner: local_owner,
+                    controller: local_controller,
+                    culture_in_province: localize_culture(culture_in_province_id),
+                    primary_culture: primary_culture_id.map(localize_culture),
+                })
+            }
+
             MapPayloadKind::Development => Some(MapQuickTipPayload::Development {
                 province_id,
                 province_name: province.name.clone(),
@@ -298,7 +395,11 @@ impl SaveFileImpl {
     pub fn map_colors(&self, payload: MapPayload) -> Vec<u8> {
         if matches!(
             payload.kind,
-            MapPayloadKind::Political | MapPayloadKind::Religion | MapPayloadKind::Battles
+            MapPayloadKind::Political
+                | MapPayloadKind::Religion
+                | MapPayloadKind::Culture
+                | MapPayloadKind::Development
+                | MapPayloadKind::Battles
         ) {
             let date = payload
                 .date
@@ -437,6 +538,41 @@ impl SaveFileImpl {
                 }
             }
 
+            MapPayloadKind::Culture => {
+                for (&id, prov, include) in provs {
+                    let offset = province_id_to_color_index[usize::from(id.as_u16())] as usize * 4;
+                    let primary_color = &mut primary[offset..offset + 4];
+                    let secondary_color = &mut secondary[offset..offset + 4];
+
+                    primary_color.copy_from_slice(&WASTELAND);
+                    secondary_color.copy_from_slice(&WASTELAND);
+
+                    if let Some(owner_tag) = prov.owner.as_ref() {
+                        let owner = self.query.country(owner_tag).unwrap();
+
+                        primary_color.copy_from_slice(&excluded_color);
+                        secondary_color.copy_from_slice(&excluded_color);
+
+                        if !include {
+                            continue;
+                        }
+
+                        if let Some(prov_culture) = prov.culture.as_ref() {
+                            secondary_color.copy_from_slice(&name_color(prov_culture));
+
+                            if let Some(owner_culture) = owner.primary_culture.as_ref() {
+                                primary_color.copy_from_slice(&name_color(owner_culture));
+                            }
+                        }
+                    } else if let Some(prov) = self.game.get_province(&id)
+                        && prov.is_habitable()
+                    {
+                        primary_color.copy_from_slice(&[94, 94, 94, 128]);
+                        secondary_color.copy_from_slice(&[94, 94, 94, 128]);
+                    }
+                }
+            }
+
             MapPayloadKind::Development => {
                 let min_color = [127., 0., 0.];
                 let diff_color = [0. - 127., 212. - 0., 144. - 0.];
@@ -557,6 +693,54 @@ impl SaveFileImpl {
                 }
             }
 
+            MapPayloadKind::Trade => {
+                let shares = self.trade_power_shares();
+
+                for (&id, prov, include) in provs {
+                    let offset = province_id_to_color_index[usize::from(id.as_u16())] as usize * 4;
+                    let primary_color = &mut primary[offset..offset + 4];
+                    let secondary_color = &mut secondary[offset..offset + 4];
+
+                    primary_color.copy_from_slice(&WASTELAND);
+                    secondary_color.copy_from_slice(&WASTELAND);
+
+                    if let Some(owner_tag) = prov.owner.as_ref() {
+                        primary_color.copy_from_slice(&excluded_color);
+                        secondary_color.copy_from_slice(&excluded_color);
+
+                        if !include {
+                            continue;
+                        }
+
+                        let Some(node) = prov.trade.as_ref() else {
+                            continue;
+                        };
+
+                        // The node color is darkened the less of the node's
+                        // power the owner holds
+                        let node_color = name_color(node);
+                        let share = shares
+                            .get(node.as_str())
+                            .and_then(|x| x.get(owner_tag))
+                            .copied()
+                            .unwrap_or(0.0)
+                            .clamp(0.0, 1.0);
+                        let shade = 0.25 + 0.75 * share;
+
+                        primary_color.copy_from_slice(&node_color);
+                        for (dst, src) in secondary_color[..3].iter_mut().zip(node_color) {
+                            *dst = (f32::from(src) * shade).round() as u8;
+                        }
+                        secondary_color[3] = 255;
+                    } else if let Some(prov) = self.game.get_province(&id)
+                        && prov.is_habitable()
+                    {
+                        primary_color.copy_from_slice(&[94, 94, 94, 128]);
+                        secondary_color.copy_from_slice(&[94, 94, 94, 128]);
+                    }
+                }
+            }
+
             MapPayloadKind::Terrain => {}
         }
 
@@ -569,6 +753,14 @@ impl SaveFileImpl {
                 let mut timelapse = ReligionTimelapse::new(self);
                 timelapse.advance_to(date)
             }
+            MapPayloadKind::Culture => {
+                let mut timelapse = CultureTimelapse::new(self);
+                timelapse.advance_to(date)
+            }
+            MapPayloadKind::Development => {
+                let mut timelapse = DevelopmentTimelapse::new(self);
+                timelapse.advance_to(date)
+            }
             MapPayloadKind::Battles => {
                 let mut timelapse = BattleTimelapse::new(self);
                 let prep_date = date.add_days(-365).max(self.query.save().game.start_date);
@@ -586,6 +778,10 @@ impl SaveFileImpl {
         let timelapse = match payload.kind {
             MapCursorPayloadKind::Political => Timelapse::Political(PoliticalTimelapse::new(self)),
             MapCursorPayloadKind::Religion => Timelapse::Religion(ReligionTimelapse::new(self)),
+            MapCursorPayloadKind::Culture => Timelapse::Culture(CultureTimelapse::new(self)),
+            MapCursorPayloadKind::Development => {
+                Timelapse::Development(DevelopmentTimelapse::new(self))
+            }
             MapCursorPayloadKind::Battles => Timelapse::Battles(BattleTimelapse::new(self)),
         };
 
@@ -960,6 +1156,8 @@ impl OwnerTimelapse {
 enum Timelapse {
     Political(PoliticalTimelapse),
     Religion(ReligionTimelapse),
+    Culture(CultureTimelapse),
+    Development(DevelopmentTimelapse),
     Battles(BattleTimelapse),
 }
 
@@ -968,6 +1166,8 @@ impl std::fmt::Debug for Timelapse {
         match self {
             Timelapse::Political(_) => write!(f, "Timelapse::Political"),
             Timelapse::Religion(_) => write!(f, "Timelapse::Religion"),
+            Timelapse::Culture(_) => write!(f, "Timelapse::Culture"),
+            Timelapse::Development(_) => write!(f, "Timelapse::Development"),
             Timelapse::Battles(_) => write!(f, "Timelapse::Battles"),
         }
     }
@@ -978,6 +1178,8 @@ impl Timelapse {
         match self {
             Timelapse::Political(x) => x.advance_to(date),
             Timelapse::Religion(x) => x.advance_to(date),
+            Timelapse::Culture(x) => x.advance_to(date),
+            Timelapse::Development(x) => x.advance_to(date),
             Timelapse::Battles(x) => x.advance_to(date),
         }
     }
@@ -1358,6 +1560,410 @@ impl ReligionTimelapse {
     }
 }
 
+struct CultureEvent {
+    date: Eu4Date,
+    kind: CultureEventKind,
+}
+
+#[derive(Debug)]
+enum CultureEventKind {
+    ProvCulture {
+        province: ProvinceId,
+        new_culture: usize,
+    },
+    TagCulture {
+        tag: CountryTag,
+        new_culture: usize,
+    },
+}
+
+struct CultureTimelapse {
+    wasm: &'static SaveFileImpl,
+    owners: OwnerTimelapse,
+    country_cultures: HashMap<CountryTag, usize>,
+    culture_colors: Vec<[u8; 4]>,
+
+    current_cultures: Vec<Option<usize>>,
+    event_index: usize,
+    events: Vec<CultureEvent>,
+}
+
+impl CultureTimelapse {
+    pub fn new(wasm: &SaveFileImpl) -> Self {
+        let owners = OwnerTimelapse::new(wasm, ProvinceTracking::OnlyOwner);
+
+        // Cultures are not enumerated in the save, so they are interned as
+        // they are encountered
+        let mut culture_indices: HashMap<String, usize> = HashMap::new();
+        let mut culture_colors = Vec::new();
+        let mut intern = |culture: &str| -> usize {
+            if let Some(index) = culture_indices.get(culture) {
+                return *index;
+            }
+
+            culture_colors.push(name_color(culture));
+            culture_indices.insert(String::from(culture), culture_colors.len() - 1);
+            culture_colors.len() - 1
+        };
+
+        let save = &wasm.query.save().game;
+        let country_cultures = save
+            .countries
+            .iter()
+            .filter_map(|(tag, country)| {
+                country
+                    .history
+                    .primary_culture
+                    .as_ref()
+                    .or(country.primary_culture.as_ref())
+                    .map(|culture| (*tag, intern(culture)))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut current_cultures = vec![None; owners.current_owners.len()];
+        let mut events = Vec::new();
+        for (id, prov) in &save.provinces {
+            if let Some(culture) = prov.history.culture.as_ref()
+                && let Some(current) = current_cultures.get_mut(usize::from(id.as_u16()))
+            {
+                *current = Some(intern(culture));
+            }
+
+            for (date, event) in &prov.history.events {
+                if let ProvinceEvent::KV((key, ProvinceEventValue::String(x))) = event
+                    && key == "culture"
+                {
+                    events.push(CultureEvent {
+                        date: *date,
+                        kind: CultureEventKind::ProvCulture {
+                            province: *id,
+                            new_culture: intern(x),
+                        },
+                    });
+                }
+            }
+        }
+
+        for (tag, country) in &save.countries {
+            for (date, event) in &country.history.events {
+                if let CountryEvent::PrimaryCulture(x) = event {
+                    events.push(CultureEvent {
+                        date: *date,
+                        kind: CultureEventKind::TagCulture {
+                            tag: *tag,
+                            new_culture: intern(x),
+                        },
+                    });
+                }
+            }
+        }
+
+        events.sort_by(|a, b| a.date.cmp(&b.date));
+
+        Self {
+            owners,
+            current_cultures,
+            country_cultures,
+            culture_colors,
+            events,
+            wasm: unsafe { std::mem::transmute::<&SaveFileImpl, &SaveFileImpl>(wasm) },
+            event_index: 0,
+        }
+    }
+
+    fn advance_to(&mut self, date: Eu4Date) -> Vec<u8> {
+        let result_len = self.wasm.province_id_to_color_index.len() * 4;
+        let mut result: Vec<u8> = vec![0; result_len * 3];
+        let resolver = self.wasm.tag_resolver.at(date);
+        let (culture_colors, country_colors) = result.split_at_mut(result_len * 2);
+        let (primary, secondary) = culture_colors.split_at_mut(result_len);
+
+        self.owners.advance_to(date);
+
+        let remaining_events = &self.events[self.event_index..];
+        let pos = remaining_events
+            .iter()
+            .position(|event| event.date > date)
+            .unwrap_or(remaining_events.len());
+        let events = &remaining_events[..pos];
+        self.event_index += pos;
+
+        for event in events {
+            match event.kind {
+                CultureEventKind::ProvCulture {
+                    province,
+                    new_culture,
+                } => {
+                    let ind = usize::from(province.as_u16());
+                    if let Some(current) = self.current_cultures.get_mut(ind) {
+                        *current = Some(new_culture);
+                    }
+                }
+                CultureEventKind::TagCulture { tag, new_culture } => {
+                    self.country_cultures.insert(tag, new_culture);
+                }
+            }
+        }
+
+        for province in self.wasm.game.provinces() {
+            let prov_ind = usize::from(province.id.as_u16());
+            let (primary_color, country_color) = 'color: {
+                if !province.is_habitable() {
+                    break 'color (&WASTELAND, &WASTELAND);
+                }
+
+                let Some((date, owner)) = self.owners.current_owners.get(prov_ind) else {
+                    break 'color (&WASTELAND, &WASTELAND);
+                };
+
+                if owner.is_none() {
+                    break 'color (&[94, 94, 94, 128], &[94, 94, 94, 128]);
+                }
+
+                let tag = resolver
+                    .resolve(*owner, *date)
+                    .map(|x| x.stored)
+                    .unwrap_or(*owner);
+
+                let owner_culture_color = self
+                    .country_cultures
+                    .get(&tag)
+                    .and_then(|culture| self.culture_colors.get(*culture))
+                    .unwrap_or(&[94, 94, 94, 128]);
+
+                let country_color = self
+                    .owners
+                    .country_colors
+                    .get(&tag)
+                    .unwrap_or(&[94, 94, 94, 128]);
+                (owner_culture_color, country_color)
+            };
+
+            let secondary_color = 'color: {
+                // Mirror the religion timelapse and leave unowned provinces
+                // uncolored
+                if primary_color == &[94, 94, 94, 128] {
+                    break 'color primary_color;
+                }
+
+                if !province.is_habitable() {
+                    break 'color &WASTELAND;
+                }
+
+                self.current_cultures
+                    .get(prov_ind)
+                    .copied()
+                    .flatten()
+                    .and_then(|culture| self.culture_colors.get(culture))
+                    .unwrap_or(&[94, 94, 94, 128])
+            };
+
+            let ind = self.wasm.province_id_to_color_index[prov_ind];
+            let offset = usize::from(ind) * 4;
+            primary[offset..offset + 4].copy_from_slice(primary_color);
+            secondary[offset..offset + 4].copy_from_slice(secondary_color);
+            country_colors[offset..offset + 4].copy_from_slice(country_color);
+        }
+
+        result
+    }
+}
+
+struct DevelopmentEvent {
+    date: Eu4Date,
+    province: ProvinceId,
+    kind: DevelopmentEventKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DevelopmentEventKind {
+    BaseTax(f32),
+    BaseProduction(f32),
+    BaseManpower(f32),
+}
+
+impl DevelopmentEventKind {
+    fn apply(self, dev: &mut [f32; 3]) {
+        match self {
+            DevelopmentEventKind::BaseTax(x) => dev[0] = x,
+            DevelopmentEventKind::BaseProduction(x) => dev[1] = x,
+            DevelopmentEventKind::BaseManpower(x) => dev[2] = x,
+        }
+    }
+}
+
+/// Province development over time.
+///
+/// Province history records the dated base tax, production, and manpower
+/// changes that come from history files and events. Development bought with
+/// monarch points is only stored as undated totals per country, and buildings
+/// don't change development, so any difference between the development
+/// reconstructed from history and the development at the save date can't be
+/// placed in time. Before the save date, such provinces are drawn with
+/// [`UNKNOWN_DEVELOPMENT`] stripes over the development known from history
+/// when development was gained, or entirely as unknown when it was lost.
+struct DevelopmentTimelapse {
+    wasm: &'static SaveFileImpl,
+    owners: OwnerTimelapse,
+    current_dev: Vec<[f32; 3]>,
+    unrecorded_dev: Vec<f32>,
+    max_dev: f32,
+    end: Eu4Date,
+    event_index: usize,
+    events: Vec<DevelopmentEvent>,
+}
+
+impl DevelopmentTimelapse {
+    pub fn new(wasm: &SaveFileImpl) -> Self {
+        let owners = OwnerTimelapse::new(wasm, ProvinceTracking::OnlyOwner);
+        let save = wasm.query.save();
+
+        let mut current_dev = vec![[0.0; 3]; owners.current_owners.len()];
+        let mut unrecorded_dev = vec![0.0; owners.current_owners.len()];
+        let mut events = Vec::new();
+        let mut max_dev: f32 = 0.0;
+
+        for (id, prov) in &save.game.provinces {
+            let ind = usize::from(id.as_u16());
+            if ind >= current_dev.len() {
+                continue;
+            }
+
+            let final_dev = prov.base_tax + prov.base_production + prov.base_manpower;
+            if prov.owner.is_some() {
+                max_dev = max_dev.max(final_dev);
+            }
+
+            let history = &prov.history;
+            let initial = [
+                history.base_tax.unwrap_or(prov.base_tax),
+                history.base_production.unwrap_or(prov.base_production),
+                history.base_manpower.unwrap_or(prov.base_manpower),
+            ];
+
+            let mut recorded = initial;
+            for (date, event) in &history.events {
+                let kind = match event {
+                    ProvinceEvent::BaseTax(x) => DevelopmentEventKind::BaseTax(*x),
+                    ProvinceEvent::BaseProduction(x) => DevelopmentEventKind::BaseProduction(*x),
+                    ProvinceEvent::BaseManpower(x) => DevelopmentEventKind::BaseManpower(*x),
+                    _ => continue,
+                };
+
+                kind.apply(&mut recorded);
+                events.push(DevelopmentEvent {
+                    date: *date,
+                    province: *id,
+                    kind,
+                });
+            }
+
+            current_dev[ind] = initial;
+            unrecorded_dev[ind] = final_dev - recorded.iter().sum::<f32>();
+        }
+
+        events.sort_by(|a, b| a.date.cmp(&b.date));
+
+        Self {
+            owners,
+            current_dev,
+            unrecorded_dev,
+            max_dev: max_dev.clamp(10.0, 50.0),
+            end: save.meta.date,
+            events,
+            wasm: unsafe { std::mem::transmute::<&SaveFileImpl, &SaveFileImpl>(wasm) },
+            event_index: 0,
+        }
+    }
+
+    fn advance_to(&mut self, date: Eu4Date) -> Vec<u8> {
+        let result_len = self.wasm.province_id_to_color_index.len() * 4;
+        let mut result: Vec<u8> = vec![0; result_len * 3];
+        let resolver = self.wasm.tag_resolver.at(date);
+        let (dev_colors, country_colors) = result.split_at_mut(result_len * 2);
+        let (primary, secondary) = dev_colors.split_at_mut(result_len);
+
+        self.owners.advance_to(date);
+
+        let remaining_events = &self.events[self.event_index..];
+        let pos = remaining_events
+            .iter()
+            .position(|event| event.date > date)
+            .unwrap_or(remaining_events.len());
+        let events = &remaining_events[..pos];
+        self.event_index += pos;
+
+        for event in events {
+            let ind = usize::from(event.province.as_u16());
+            event.kind.apply(&mut self.current_dev[ind]);
+        }
+
+        let at_save_date = date >= self.end;
+        for province in self.wasm.game.provinces() {
+            let prov_ind = usize::from(province.id.as_u16());
+            let (primary_color, secondary_color, country_color) = 'color: {
+                if !province.is_habitable() {
+                    break 'color (WASTELAND, WASTELAND, &WASTELAND);
+                }
+
+                let Some((owner_date, owner)) = self.owners.current_owners.get(prov_ind) else {
+                    break 'color (WASTELAND, WASTELAND, &WASTELAND);
+                };
+
+                if owner.is_none() {
+                    break 'color ([94, 94, 94, 128], [94, 94, 94, 128], &[94, 94, 94, 128]);
+                }
+
+                let tag = resolver
+                    .resolve(*owner, *owner_date)
+                    .map(|x| x.stored)
+                    .unwrap_or(*owner);
+
+                let country_color = self
+                    .owners
+                    .country_colors
+                    .get(&tag)
+                    .unwrap_or(&[94, 94, 94, 128]);
+
+                let recorded = self.current_dev[prov_ind].iter().sum::<f32>();
+                let unrecorded = self.unrecorded_dev[prov_ind];
+                if at_save_date {
+                    let color = self.dev_color(recorded + unrecorded);
+                    (color, color, country_color)
+                } else if unrecorded.abs() < 0.001 {
+                    let color = self.dev_color(recorded);
+                    (color, color, country_color)
+                } else if unrecorded > 0.0 {
+                    let color = self.dev_color(recorded);
+                    (color, UNKNOWN_DEVELOPMENT, country_color)
+                } else {
+                    (UNKNOWN_DEVELOPMENT, UNKNOWN_DEVELOPMENT, country_color)
+                }
+            };
+
+            let ind = self.wasm.province_id_to_color_index[prov_ind];
+            let offset = usize::from(ind) * 4;
+            primary[offset..offset + 4].copy_from_slice(&primary_color);
+            secondary[offset..offset + 4].copy_from_slice(&secondary_color);
+            country_colors[offset..offset + 4].copy_from_slice(country_color);
+        }
+
+        result
+    }
+
+    fn dev_color(&self, dev: f32) -> [u8; 4] {
+        let min_color = [127., 0., 0.];
+        let diff_color = [0. - 127., 212. - 0., 144. - 0.];
+        let ratio = dev.clamp(0.0, self.max_dev) / self.max_dev;
+        [
+            (min_color[0] + ratio * diff_color[0]).round() as u8,
+            (min_color[1] + ratio * diff_color[1]).round() as u8,
+            (min_color[2] + ratio * diff_color[2]).round() as u8,
+            255,
+        ]
+    }
+}
+
 struct BattleEvent {
     date: Eu4Date,
     province: ProvinceId,
@@ -1,7 +1,7 @@
 use crate::tokens;
 use eu4game::{
     Eu4GameError, SaveGameQuery,
-    achievements::AchievementHunter,
+    achievements::{AchievementEligibility, AchievementHunter},
     game::Game,
     shared::{Eu4Parser, playthrough_id},
 };
@@ -16,10 +16,13 @@ use eu4save::{
 use std::collections::{HashMap, HashSet};
 
 mod country_details;
+mod diff;
 mod institution;
 mod map;
 mod models;
 mod tag_filter;
+mod trade;
+mod war_score;
 
 pub use map::*;
 pub use models::*;
@@ -474,13 +477,27 @@ impl SaveFileImpl {
         };
 
         let score = eu4_start_date().days_until(&self.query.save().meta.date);
+        let eligibility = AchievementEligibility::check(
+            self.encoding,
+            &self.query,
+            &self.game,
+            &self.player_histories,
+        )
+        .gates
+        .into_iter()
+        .map(|x| AchievementGate {
+            check: x.check,
+            passed: x.passed,
+            reason: x.reason,
+        })
+        .collect();
 
         match achieves {
             Some(results) => {
                 let list = eu4game::achievements::achievements();
+                let results = results.achievements();
                 let completed: Vec<_> = results
-                    .achievements()
-                    .into_iter()
+                    .iter()
                     .filter(|x| x.completed())
                     .filter_map(|x| list.iter().find(|y| x.id == y.id))
                     .map(|x| CompletedAchievement {
@@ -489,11 +506,37 @@ impl SaveFileImpl {
                     })
                     .collect();
 
+                let progress = results
+                    .iter()
+                    .filter_map(|x| list.iter().find(|y| x.id == y.id).map(|y| (x, y)))
+                    .map(|(result, achievement)| AchievementProgress {
+                        id: achievement.id,
+                        name: achievement.name.clone(),
+                        completed: result.completed(),
+                        conditions: result
+                            .conditions
+                            .iter()
+                            .map(|cond| AchievementConditionProgress {
+                                completed: cond.is_completed(),
+                                description: String::from(cond.description()),
+                                current: cond.progress().map(|x| x.current),
+                                total: cond.progress().map(|x| x.total),
+                                missing: cond
+                                    .progress()
+                                    .map(|x| x.missing.clone())
+                                    .unwrap_or_default(),
+                            })
+                            .collect(),
+                    })
+                    .collect();
+
                 AchievementsScore {
                     kind: AchievementCompatibility::Compatible,
                     patch,
                     score,
                     achievements: completed,
+                    progress,
+                    eligibility,
                 }
             }
             None => AchievementsScore {
@@ -501,6 +544,8 @@ impl SaveFileImpl {
                 patch,
                 score,
                 achievements: Vec::with_capacity(0),
+                progress: Vec::with_capacity(0),
+                eligibility,
             },
         }
     }
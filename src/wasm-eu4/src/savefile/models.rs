@@ -1,5 +1,6 @@
 #![allow(nonstandard_style)]
 #![allow(clippy::empty_docs)]
+use eu4game::achievements::EligibilityCheck;
 use eu4save::{
     CountryTag, Eu4Date, ProvinceId,
     models::{
@@ -165,6 +166,32 @@ pub struct CompletedAchievement {
     pub name: String,
 }
 
+#[derive(Tsify, Debug, Serialize)]
+pub struct AchievementGate {
+    pub check: EligibilityCheck,
+    pub passed: bool,
+    pub reason: String,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+pub struct AchievementConditionProgress {
+    pub completed: bool,
+    pub description: String,
+    pub current: Option<usize>,
+    pub total: Option<usize>,
+
+    /// Provinces or tags that don't yet satisfy the condition
+    pub missing: Vec<String>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+pub struct AchievementProgress {
+    pub id: i32,
+    pub name: String,
+    pub completed: bool,
+    pub conditions: Vec<AchievementConditionProgress>,
+}
+
 #[derive(Tsify, Debug, Serialize)]
 #[tsify(into_wasm_abi)]
 pub struct AchievementsScore {
@@ -172,6 +199,13 @@ pub struct AchievementsScore {
     pub patch: GameVersion,
     pub score: i32,
     pub achievements: Vec<CompletedAchievement>,
+
+    /// Progress towards every achievement, completed or not
+    pub progress: Vec<AchievementProgress>,
+
+    /// The gates checked to determine if the save is eligible for
+    /// achievements
+    pub eligibility: Vec<AchievementGate>,
 }
 
 #[derive(Tsify, Serialize, Debug)]
@@ -992,10 +1026,176 @@ pub struct InfluenceModifier {
 pub enum MapPayloadKind {
     Political,
     Religion,
+    Culture,
     Development,
     Battles,
     Technology,
     Terrain,
+    Trade,
+}
+
+#[derive(Tsify, Serialize, Debug, Clone, Copy)]
+pub struct DiffValue {
+    pub before: f32,
+    pub after: f32,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+pub struct IdeaGroupDiff {
+    pub name: String,
+    pub before: u8,
+    pub after: u8,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+pub struct RulerChange {
+    pub date: Eu4Date,
+    pub name: String,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CountryDiff {
+    pub country: LocalizedTag,
+    pub development: DiffValue,
+    pub provinces_gained: Vec<ProvinceId>,
+    pub provinces_lost: Vec<ProvinceId>,
+    pub income: DiffValue,
+    pub manpower: DiffValue,
+    pub adm_tech: DiffValue,
+    pub dip_tech: DiffValue,
+    pub mil_tech: DiffValue,
+    pub ideas: Vec<IdeaGroupDiff>,
+    pub wars_started: Vec<String>,
+    pub wars_ended: Vec<String>,
+    pub rulers: Vec<RulerChange>,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[tsify(into_wasm_abi)]
+pub struct SaveDiff {
+    pub before: Eu4Date,
+    pub after: Eu4Date,
+    pub countries: Vec<CountryDiff>,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeNodeCountry {
+    pub country: LocalizedTag,
+    pub power: f32,
+}
+
+#[derive(Tsify, Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum TradeNodeMerchantMode {
+    Collect,
+    Steer,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeNodeMerchant {
+    pub country: LocalizedTag,
+    pub mode: TradeNodeMerchantMode,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeNodeInfo {
+    pub name: String,
+    pub local_value: f32,
+    pub incoming_value: f32,
+    pub outgoing_value: f32,
+    pub retention: f32,
+    pub total_power: f32,
+
+    /// Countries with the most power collecting in the node, in descending
+    /// order of power
+    pub collectors: Vec<TradeNodeCountry>,
+
+    /// Countries with the most power steering trade downstream, in
+    /// descending order of power
+    pub steering: Vec<TradeNodeCountry>,
+    pub merchants: Vec<TradeNodeMerchant>,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WarScoreParticipant {
+    pub country: LocalizedTag,
+    pub battles_won: u32,
+
+    /// Battle war score earned by battles this country led, before the
+    /// side's battle score cap is applied
+    pub battle_score: f32,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WarScoreSide {
+    pub battle_score: f32,
+    pub occupation_score: f32,
+    pub ticking_score: f32,
+    pub total: f32,
+    pub participants: Vec<WarScoreParticipant>,
+}
+
+#[derive(Tsify, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WarGoalStatus {
+    /// The attackers control the war goal and war score ticks in their favor
+    Attackers,
+
+    /// The defenders control the war goal and war score ticks in their favor
+    Defenders,
+
+    /// The war goal is not tied to a controlled province, so its status
+    /// can't be derived from the map
+    Unknown,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WarGoalInfo {
+    pub casus_belli: String,
+    pub province: Option<ProvinceId>,
+    pub controller: Option<LocalizedTag>,
+    pub status: WarGoalStatus,
+
+    /// Whole months the war goal has been held by the side in control
+    pub months: i32,
+}
+
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WarScorePoint {
+    pub date: Eu4Date,
+
+    /// Net war score from battles, occupations, and the war goal, positive
+    /// in favor of the attackers
+    pub score: f32,
+}
+
+/// An estimate of a war's war score. The save doesn't record war score, so
+/// battle score is approximated from battle losses, occupation score from
+/// the share of occupied development, and ticking score from how long the
+/// war goal has been held.
+#[derive(Tsify, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct WarScoreEstimate {
+    pub attackers: WarScoreSide,
+    pub defenders: WarScoreSide,
+    pub war_goal: Option<WarGoalInfo>,
+
+    /// Net war score, positive in favor of the attackers
+    pub net: f32,
+
+    /// Net war score after each war event and each change of control of a
+    /// participant's province. Occupations are weighed by the current
+    /// development of the provinces.
+    pub timeline: Vec<WarScorePoint>,
 }
 
 #[derive(Debug, Tsify, Serialize, Deserialize, Clone)]
@@ -1013,6 +1213,8 @@ pub struct MapPayload {
 pub enum MapCursorPayloadKind {
     Political,
     Religion,
+    Culture,
+    Development,
     Battles,
 }
 
@@ -1055,6 +1257,16 @@ pub enum MapQuickTipPayload {
         state_religion: LocalizedObj,
     },
 
+    #[serde(rename_all = "camelCase")]
+    Culture {
+        owner: LocalizedTag,
+        controller: LocalizedTag,
+        province_id: ProvinceId,
+        province_name: String,
+        culture_in_province: LocalizedObj,
+        primary_culture: Option<LocalizedObj>,
+    },
+
     #[serde(rename_all = "camelCase")]
     Development {
         owner: LocalizedTag,
@@ -1299,3 +1511,34 @@ pub struct CountryInstitution {
     pub institutions_embraced: i32,
     pub dev_push: Vec<InstitutionCost>,
 }
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionForecastPoint {
+    pub date: Eu4Date,
+
+    /// Share of development with the institution from natural spread
+    pub share: f32,
+
+    /// Share of development with the institution after the planned dev pushes
+    pub planned_share: f32,
+}
+
+/// An estimate of how an institution spreads through a country. The spread
+/// rates approximate the game's and are not read from the game defines.
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct InstitutionForecast {
+    pub institution: i32,
+    pub total_dev: f32,
+    pub current_share: f32,
+
+    /// Share of development that must have the institution to embrace it
+    pub threshold: f32,
+    pub embrace_date: Option<Eu4Date>,
+    pub planned_embrace_date: Option<Eu4Date>,
+
+    /// Projected shares at yearly intervals
+    pub timeline: Vec<InstitutionForecastPoint>,
+}
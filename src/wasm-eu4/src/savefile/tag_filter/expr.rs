@@ -0,0 +1,693 @@
+//! A small expression language for filtering countries, for example:
+//!
+//! ```text
+//! religion_group(christian) and not hre and (development >= 200 or great_power_rank <= 8)
+//! ```
+//!
+//! Expressions combine predicates with `and`, `or`, `not`, and parentheses.
+//! `and` binds tighter than `or`.
+//!
+//! | Predicate                  | Matches countries                             |
+//! |----------------------------|-----------------------------------------------|
+//! | `tag(FRA)`                 | with the tag                                  |
+//! | `religion(catholic)`       | with the state religion                       |
+//! | `religion_group(muslim)`   | with a state religion in the group            |
+//! | `culture(english)`         | with the primary culture                      |
+//! | `culture_group(british)`   | with a primary culture in the group           |
+//! | `government(monarchy)`     | with the government type                      |
+//! | `reform(pirate_republic_reform)` | that have enacted the government reform |
+//! | `at_war_with(FRA)`         | on the opposing side of a war with the tag    |
+//! | `subject_of(FRA)`          | that are a direct subject of the tag          |
+//! | `at_war`                   | participating in an active war                |
+//! | `hre`                      | with their capital in the HRE                 |
+//! | `subject`                  | that have an overlord                         |
+//! | `development <op> n`       | compared by total development                 |
+//! | `provinces <op> n`         | compared by number of owned provinces         |
+//! | `great_power_rank <op> n`  | compared by great power rank (1 is the top)   |
+//!
+//! Comparison operators are `<`, `<=`, `>`, `>=`, and `=`.
+use super::SaveFileImpl;
+use eu4save::{
+    CountryTag, ProvinceId,
+    models::{Country, WarEvent},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagExpr {
+    And(Box<TagExpr>, Box<TagExpr>),
+    Or(Box<TagExpr>, Box<TagExpr>),
+    Not(Box<TagExpr>),
+    Predicate(TagPredicate),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagPredicate {
+    Tag(CountryTag),
+    Religion(String),
+    ReligionGroup(String),
+    Culture(String),
+    CultureGroup(String),
+    Government(String),
+    Reform(String),
+    AtWarWith(CountryTag),
+    SubjectOf(CountryTag),
+    AtWar,
+    Hre,
+    Subject,
+    Compare(TagMetric, Comparison, f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TagMetric {
+    Development,
+    Provinces,
+    GreatPowerRank,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Equal,
+}
+
+impl Comparison {
+    fn matches(self, lhs: f32, rhs: f32) -> bool {
+        match self {
+            Comparison::Less => lhs < rhs,
+            Comparison::LessEqual => lhs <= rhs,
+            Comparison::Greater => lhs > rhs,
+            Comparison::GreaterEqual => lhs >= rhs,
+            Comparison::Equal => lhs == rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagExprError {
+    /// Byte offset into the expression where the error was found
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TagExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at position {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for TagExprError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Number(f32),
+    LParen,
+    RParen,
+    Cmp(Comparison),
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token<'_>)>, TagExprError> {
+    let mut tokens = Vec::new();
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let token = match bytes[i] {
+            b' ' | b'\t' | b'\n' | b'\r' => {
+                i += 1;
+                continue;
+            }
+            b'(' => Token::LParen,
+            b')' => Token::RParen,
+            b'=' => Token::Cmp(Comparison::Equal),
+            b'<' if bytes.get(i + 1) == Some(&b'=') => {
+                i += 1;
+                Token::Cmp(Comparison::LessEqual)
+            }
+            b'<' => Token::Cmp(Comparison::Less),
+            b'>' if bytes.get(i + 1) == Some(&b'=') => {
+                i += 1;
+                Token::Cmp(Comparison::GreaterEqual)
+            }
+            b'>' => Token::Cmp(Comparison::Greater),
+            b'0'..=b'9' => {
+                while i + 1 < bytes.len() && matches!(bytes[i + 1], b'0'..=b'9' | b'.') {
+                    i += 1;
+                }
+
+                let text = &input[start..=i];
+                let value = text.parse::<f32>().map_err(|_| TagExprError {
+                    position: start,
+                    message: format!("invalid number: {}", text),
+                })?;
+                Token::Number(value)
+            }
+            x if x.is_ascii_alphanumeric() || x == b'_' => {
+                while i + 1 < bytes.len()
+                    && (bytes[i + 1].is_ascii_alphanumeric() || matches!(bytes[i + 1], b'_' | b'-'))
+                {
+                    i += 1;
+                }
+
+                Token::Ident(&input[start..=i])
+            }
+            _ => {
+                let c = input[start..].chars().next().unwrap_or_default();
+                return Err(TagExprError {
+                    position: start,
+                    message: format!("unexpected character: {}", c),
+                });
+            }
+        };
+
+        tokens.push((start, token));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<(usize, Token<'a>)>,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).map(|(_, token)| *token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.end, |(pos, _)| *pos)
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, TagExprError> {
+        Err(TagExprError {
+            position: self.position(),
+            message: message.into(),
+        })
+    }
+
+    fn expect(&mut self, expected: Token, desc: &str) -> Result<(), TagExprError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(format!("expected {}", desc))
+        }
+    }
+
+    fn or(&mut self) -> Result<TagExpr, TagExprError> {
+        let mut lhs = self.and()?;
+        while self.peek() == Some(Token::Ident("or")) {
+            self.pos += 1;
+            let rhs = self.and()?;
+            lhs = TagExpr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn and(&mut self) -> Result<TagExpr, TagExprError> {
+        let mut lhs = self.unary()?;
+        while self.peek() == Some(Token::Ident("and")) {
+            self.pos += 1;
+            let rhs = self.unary()?;
+            lhs = TagExpr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<TagExpr, TagExprError> {
+        match self.peek() {
+            Some(Token::Ident("not")) => {
+                self.pos += 1;
+                Ok(TagExpr::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let expr = self.or()?;
+                self.expect(Token::RParen, "closing parenthesis")?;
+                Ok(expr)
+            }
+            Some(Token::Ident(name)) => {
+                self.pos += 1;
+                self.predicate(name).map(TagExpr::Predicate)
+            }
+            Some(_) => self.error("expected a predicate"),
+            None => self.error("unexpected end of expression"),
+        }
+    }
+
+    fn argument(&mut self) -> Result<&'a str, TagExprError> {
+        self.expect(Token::LParen, "opening parenthesis")?;
+        let Some(Token::Ident(arg)) = self.peek() else {
+            return self.error("expected an argument");
+        };
+        self.pos += 1;
+        self.expect(Token::RParen, "closing parenthesis")?;
+        Ok(arg)
+    }
+
+    fn tag_argument(&mut self) -> Result<CountryTag, TagExprError> {
+        let position = self.position();
+        let arg = self.argument()?;
+        arg.parse::<CountryTag>().map_err(|_| TagExprError {
+            position,
+            message: format!("invalid country tag: {}", arg),
+        })
+    }
+
+    fn comparison(&mut self, metric: TagMetric) -> Result<TagPredicate, TagExprError> {
+        let Some(Token::Cmp(cmp)) = self.next() else {
+            self.pos -= 1;
+            return self.error("expected a comparison");
+        };
+
+        let Some(Token::Number(value)) = self.next() else {
+            self.pos -= 1;
+            return self.error("expected a number");
+        };
+
+        Ok(TagPredicate::Compare(metric, cmp, value))
+    }
+
+    fn predicate(&mut self, name: &'a str) -> Result<TagPredicate, TagExprError> {
+        let arg = |x: &str| String::from(x);
+        match name {
+            "tag" => self.tag_argument().map(TagPredicate::Tag),
+            "religion" => self.argument().map(arg).map(TagPredicate::Religion),
+            "religion_group" => self.argument().map(arg).map(TagPredicate::ReligionGroup),
+            "culture" => self.argument().map(arg).map(TagPredicate::Culture),
+            "culture_group" => self.argument().map(arg).map(TagPredicate::CultureGroup),
+            "government" => self.argument().map(arg).map(TagPredicate::Government),
+            "reform" => self.argument().map(arg).map(TagPredicate::Reform),
+            "at_war_with" => self.tag_argument().map(TagPredicate::AtWarWith),
+            "subject_of" => self.tag_argument().map(TagPredicate::SubjectOf),
+            "at_war" => Ok(TagPredicate::AtWar),
+            "hre" => Ok(TagPredicate::Hre),
+            "subject" => Ok(TagPredicate::Subject),
+            "development" => self.comparison(TagMetric::Development),
+            "provinces" => self.comparison(TagMetric::Provinces),
+            "great_power_rank" => self.comparison(TagMetric::GreatPowerRank),
+            _ => {
+                self.pos -= 1;
+                self.error(format!("unknown predicate: {}", name))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for TagExpr {
+    type Err = TagExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+            end: s.len(),
+        };
+
+        let expr = parser.or()?;
+        if parser.peek().is_some() {
+            return parser.error("unexpected trailing input");
+        }
+
+        Ok(expr)
+    }
+}
+
+/// The parts of a country that tag expressions are evaluated against
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CountryFacts<'c> {
+    pub(crate) religion: Option<&'c str>,
+    pub(crate) primary_culture: Option<&'c str>,
+    pub(crate) government: Option<&'c str>,
+    pub(crate) reforms: &'c [String],
+    pub(crate) capital: Option<ProvinceId>,
+    pub(crate) development: f32,
+    pub(crate) provinces: i32,
+}
+
+impl<'c> From<&'c Country> for CountryFacts<'c> {
+    fn from(country: &'c Country) -> Self {
+        let government = country.government.as_ref();
+        CountryFacts {
+            religion: country.religion.as_deref(),
+            primary_culture: country.primary_culture.as_deref(),
+            government: government.map(|x| x.government.as_str()),
+            reforms: government.map_or(&[], |x| x.reform_stack.reforms.as_slice()),
+            capital: Some(country.capital),
+            development: country.development,
+            provinces: country.num_of_cities,
+        }
+    }
+}
+
+/// Evaluates tag expressions against the countries of a save. Data shared
+/// across countries, like the great power ranking and war sides, is computed
+/// once up front.
+pub(crate) struct TagExprEvaluator<'a> {
+    religion_groups: HashMap<&'a str, &'a str>,
+    culture_groups: HashMap<&'a str, &'a str>,
+    hre_provinces: HashSet<ProvinceId>,
+    great_power_ranks: HashMap<CountryTag, usize>,
+    overlords: HashMap<CountryTag, CountryTag>,
+    war_sides: Vec<(HashSet<CountryTag>, HashSet<CountryTag>)>,
+}
+
+impl<'a> TagExprEvaluator<'a> {
+    pub(crate) fn new(save: &'a SaveFileImpl) -> Self {
+        let game = &save.query.save().game;
+
+        let religion_groups = game
+            .countries
+            .values()
+            .filter_map(|country| country.religion.as_deref())
+            .filter_map(|religion| save.game.religion(religion))
+            .filter_map(|religion| religion.group.map(|group| (religion.id, group)))
+            .collect();
+
+        let mut culture_groups = HashMap::new();
+        for culture_group in save.game.culture_groups() {
+            for culture in culture_group.list {
+                culture_groups.insert(culture, culture_group.key);
+            }
+        }
+
+        let hre_provinces = game
+            .provinces
+            .iter()
+            .filter(|(_, prov)| prov.hre)
+            .map(|(id, _)| *id)
+            .collect();
+
+        let mut scores: Vec<_> = game
+            .countries
+            .iter()
+            .filter(|(_, country)| country.great_power_score > 0.0)
+            .map(|(tag, country)| (*tag, country.great_power_score))
+            .collect();
+        scores.sort_unstable_by(|(_, a), (_, b)| b.total_cmp(a));
+        let great_power_ranks = scores
+            .into_iter()
+            .enumerate()
+            .map(|(i, (tag, _))| (tag, i + 1))
+            .collect();
+
+        let overlords = game
+            .countries
+            .iter()
+            .flat_map(|(tag, country)| country.subjects.iter().map(move |x| (*x, *tag)))
+            .collect();
+
+        let war_sides = game
+            .active_wars
+            .iter()
+            .map(|war| {
+                let mut attackers = HashSet::new();
+                let mut defenders = HashSet::new();
+                for (_, event) in &war.history.events {
+                    match event {
+                        WarEvent::AddAttacker(x) => {
+                            attackers.insert(*x);
+                        }
+                        WarEvent::AddDefender(x) => {
+                            defenders.insert(*x);
+                        }
+                        WarEvent::RemoveAttacker(x) => {
+                            attackers.remove(x);
+                        }
+                        WarEvent::RemoveDefender(x) => {
+                            defenders.remove(x);
+                        }
+                        WarEvent::Battle(_) => {}
+                    }
+                }
+                (attackers, defenders)
+            })
+            .collect();
+
+        Self {
+            religion_groups,
+            culture_groups,
+            hre_provinces,
+            great_power_ranks,
+            overlords,
+            war_sides,
+        }
+    }
+
+    pub(crate) fn matches(&self, expr: &TagExpr, tag: CountryTag, country: CountryFacts) -> bool {
+        match expr {
+            TagExpr::And(lhs, rhs) => {
+                self.matches(lhs, tag, country) && self.matches(rhs, tag, country)
+            }
+            TagExpr::Or(lhs, rhs) => {
+                self.matches(lhs, tag, country) || self.matches(rhs, tag, country)
+            }
+            TagExpr::Not(x) => !self.matches(x, tag, country),
+            TagExpr::Predicate(x) => self.predicate(x, tag, country),
+        }
+    }
+
+    fn predicate(&self, predicate: &TagPredicate, tag: CountryTag, country: CountryFacts) -> bool {
+        match predicate {
+            TagPredicate::Tag(x) => tag == *x,
+            TagPredicate::Religion(x) => country.religion == Some(x.as_str()),
+            TagPredicate::ReligionGroup(x) => country
+                .religion
+                .and_then(|religion| self.religion_groups.get(religion))
+                .is_some_and(|group| *group == x.as_str()),
+            TagPredicate::Culture(x) => country.primary_culture == Some(x.as_str()),
+            TagPredicate::CultureGroup(x) => country
+                .primary_culture
+                .and_then(|culture| self.culture_groups.get(culture))
+                .is_some_and(|group| *group == x.as_str()),
+            TagPredicate::Government(x) => country.government == Some(x.as_str()),
+            TagPredicate::Reform(x) => country.reforms.iter().any(|r| r == x),
+            TagPredicate::AtWarWith(x) => self.war_sides.iter().any(|(attackers, defenders)| {
+                (attackers.contains(&tag) && defenders.contains(x))
+                    || (defenders.contains(&tag) && attackers.contains(x))
+            }),
+            TagPredicate::SubjectOf(x) => self.overlords.get(&tag) == Some(x),
+            TagPredicate::AtWar => self
+                .war_sides
+                .iter()
+                .any(|(attackers, defenders)| attackers.contains(&tag) || defenders.contains(&tag)),
+            TagPredicate::Hre => country
+                .capital
+                .is_some_and(|capital| self.hre_provinces.contains(&capital)),
+            TagPredicate::Subject => self.overlords.contains_key(&tag),
+            TagPredicate::Compare(metric, cmp, value) => {
+                let lhs = match metric {
+                    TagMetric::Development => country.development,
+                    TagMetric::Provinces => country.provinces as f32,
+                    TagMetric::GreatPowerRank => match self.great_power_ranks.get(&tag) {
+                        Some(rank) => *rank as f32,
+                        None => return false,
+                    },
+                };
+                cmp.matches(lhs, *value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pred(x: TagPredicate) -> TagExpr {
+        TagExpr::Predicate(x)
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        let expr: TagExpr = "hre or at_war and not subject".parse().unwrap();
+        let expected = TagExpr::Or(
+            Box::new(pred(TagPredicate::Hre)),
+            Box::new(TagExpr::And(
+                Box::new(pred(TagPredicate::AtWar)),
+                Box::new(TagExpr::Not(Box::new(pred(TagPredicate::Subject)))),
+            )),
+        );
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_parse_arguments_and_comparisons() {
+        let expr: TagExpr =
+            "(religion_group(christian) or at_war_with(TUR)) and development >= 150.5"
+                .parse()
+                .unwrap();
+        let expected = TagExpr::And(
+            Box::new(TagExpr::Or(
+                Box::new(pred(TagPredicate::ReligionGroup(String::from("christian")))),
+                Box::new(pred(TagPredicate::AtWarWith("TUR".parse().unwrap()))),
+            )),
+            Box::new(pred(TagPredicate::Compare(
+                TagMetric::Development,
+                Comparison::GreaterEqual,
+                150.5,
+            ))),
+        );
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = "hre and".parse::<TagExpr>().unwrap_err();
+        assert_eq!(err.position, 7);
+
+        let err = "wealthy".parse::<TagExpr>().unwrap_err();
+        assert_eq!(err.message, "unknown predicate: wealthy");
+        assert_eq!(err.position, 0);
+
+        let err = "development > rich".parse::<TagExpr>().unwrap_err();
+        assert_eq!(err.message, "expected a number");
+        assert_eq!(err.position, 14);
+
+        let err = "(hre".parse::<TagExpr>().unwrap_err();
+        assert_eq!(err.message, "expected closing parenthesis");
+
+        let err = "hre hre".parse::<TagExpr>().unwrap_err();
+        assert_eq!(err.message, "unexpected trailing input");
+
+        let err = "hre & at_war".parse::<TagExpr>().unwrap_err();
+        assert_eq!(err.position, 4);
+    }
+
+    fn tag(x: &str) -> CountryTag {
+        x.parse().unwrap()
+    }
+
+    fn eval(evaluator: &TagExprEvaluator, expr: &str, country: &str, facts: CountryFacts) -> bool {
+        let expr: TagExpr = expr.parse().unwrap();
+        evaluator.matches(&expr, tag(country), facts)
+    }
+
+    /// FRA and its subject PRO are at war with ENG, BUR is an HRE member
+    fn evaluator() -> TagExprEvaluator<'static> {
+        TagExprEvaluator {
+            religion_groups: HashMap::from([("catholic", "christian"), ("sunni", "muslim")]),
+            culture_groups: HashMap::from([
+                ("cosmopolitan_french", "french"),
+                ("english", "british"),
+            ]),
+            hre_provinces: HashSet::from([ProvinceId::new(192)]),
+            great_power_ranks: HashMap::from([(tag("FRA"), 1), (tag("ENG"), 2), (tag("BUR"), 9)]),
+            overlords: HashMap::from([(tag("PRO"), tag("FRA"))]),
+            war_sides: vec![(
+                HashSet::from([tag("FRA"), tag("PRO")]),
+                HashSet::from([tag("ENG")]),
+            )],
+        }
+    }
+
+    #[test]
+    fn test_eval_religion_group() {
+        let evaluator = evaluator();
+        let catholic = CountryFacts {
+            religion: Some("catholic"),
+            ..Default::default()
+        };
+        assert!(eval(
+            &evaluator,
+            "religion_group(christian)",
+            "FRA",
+            catholic
+        ));
+        assert!(!eval(&evaluator, "religion_group(muslim)", "FRA", catholic));
+
+        let unknown = CountryFacts {
+            religion: Some("tengri_pagan_reformed"),
+            ..Default::default()
+        };
+        assert!(!eval(
+            &evaluator,
+            "religion_group(christian)",
+            "FRA",
+            unknown
+        ));
+        let none = CountryFacts::default();
+        assert!(!eval(&evaluator, "religion_group(christian)", "FRA", none));
+    }
+
+    #[test]
+    fn test_eval_culture_group() {
+        let evaluator = evaluator();
+        let english = CountryFacts {
+            primary_culture: Some("english"),
+            ..Default::default()
+        };
+        assert!(eval(&evaluator, "culture_group(british)", "ENG", english));
+        assert!(!eval(&evaluator, "culture_group(french)", "ENG", english));
+        let none = CountryFacts::default();
+        assert!(!eval(&evaluator, "culture_group(british)", "ENG", none));
+    }
+
+    #[test]
+    fn test_eval_at_war_with() {
+        let evaluator = evaluator();
+        let facts = CountryFacts::default();
+        assert!(eval(&evaluator, "at_war_with(ENG)", "FRA", facts));
+        assert!(eval(&evaluator, "at_war_with(ENG)", "PRO", facts));
+        assert!(eval(&evaluator, "at_war_with(FRA)", "ENG", facts));
+
+        // Allies aren't at war with each other, and bystanders with no one
+        assert!(!eval(&evaluator, "at_war_with(PRO)", "FRA", facts));
+        assert!(!eval(&evaluator, "at_war_with(ENG)", "BUR", facts));
+    }
+
+    #[test]
+    fn test_eval_subject_of() {
+        let evaluator = evaluator();
+        let facts = CountryFacts::default();
+        assert!(eval(&evaluator, "subject_of(FRA)", "PRO", facts));
+        assert!(!eval(&evaluator, "subject_of(ENG)", "PRO", facts));
+        assert!(!eval(&evaluator, "subject_of(FRA)", "FRA", facts));
+    }
+
+    #[test]
+    fn test_eval_hre() {
+        let evaluator = evaluator();
+        let member = CountryFacts {
+            capital: Some(ProvinceId::new(192)),
+            ..Default::default()
+        };
+        let outsider = CountryFacts {
+            capital: Some(ProvinceId::new(183)),
+            ..Default::default()
+        };
+        assert!(eval(&evaluator, "hre", "BUR", member));
+        assert!(!eval(&evaluator, "hre", "FRA", outsider));
+        assert!(!eval(&evaluator, "hre", "FRA", CountryFacts::default()));
+    }
+
+    #[test]
+    fn test_eval_great_power_rank() {
+        let evaluator = evaluator();
+        let facts = CountryFacts::default();
+        assert!(eval(&evaluator, "great_power_rank <= 8", "FRA", facts));
+        assert!(eval(&evaluator, "great_power_rank = 2", "ENG", facts));
+        assert!(!eval(&evaluator, "great_power_rank <= 8", "BUR", facts));
+
+        // Countries without a great power score have no rank
+        assert!(!eval(&evaluator, "great_power_rank > 0", "PRO", facts));
+        assert!(eval(&evaluator, "not great_power_rank > 0", "PRO", facts));
+    }
+}
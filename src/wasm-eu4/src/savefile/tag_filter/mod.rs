@@ -1,9 +1,12 @@
 use super::{LocalizedTag, SaveFileImpl};
 use eu4save::CountryTag;
+use expr::{CountryFacts, TagExprEvaluator};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+mod expr;
 mod models;
+pub use expr::TagExpr;
 pub use models::*;
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -14,6 +17,7 @@ pub struct TagFilterPayload {
     pub include: Vec<CountryTag>,
     pub exclude: Vec<CountryTag>,
     pub include_subjects: bool,
+    pub expression: String,
 }
 
 impl From<TagFilterPayloadRaw> for TagFilterPayload {
@@ -34,6 +38,7 @@ impl From<TagFilterPayloadRaw> for TagFilterPayload {
             include,
             exclude,
             include_subjects: x.include_subjects,
+            expression: x.expression,
         }
     }
 }
@@ -134,6 +139,26 @@ impl SaveFileImpl {
             tags.extend(dead_greats)
         }
 
+        // The expression narrows the countries selected by the other filters,
+        // but explicitly included countries are always kept. An invalid
+        // expression matches nothing rather than silently falling back to the
+        // unfiltered countries.
+        if !payload.expression.trim().is_empty() {
+            let included: HashSet<_> = payload.include.iter().collect();
+            match payload.expression.parse::<TagExpr>() {
+                Ok(expr) => {
+                    let evaluator = TagExprEvaluator::new(self);
+                    tags.retain(|tag| {
+                        included.contains(tag)
+                            || self.query.country(tag).is_some_and(|country| {
+                                evaluator.matches(&expr, *tag, CountryFacts::from(country))
+                            })
+                    });
+                }
+                Err(_) => tags.retain(|tag| included.contains(tag)),
+            }
+        }
+
         for tag in &payload.exclude {
             tags.remove(tag);
         }
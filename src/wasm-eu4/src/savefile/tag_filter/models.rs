@@ -39,4 +39,10 @@ pub struct TagFilterPayloadRaw {
     pub include: Vec<String>,
     pub exclude: Vec<String>,
     pub include_subjects: bool,
+
+    /// A filter expression that narrows the matched countries. An empty
+    /// expression matches every country. Countries in `include` are kept
+    /// even if they don't match, and `exclude` is applied last.
+    #[serde(default)]
+    pub expression: String,
 }
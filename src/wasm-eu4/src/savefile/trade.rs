@@ -0,0 +1,234 @@
+use super::{
+    SaveFileImpl, TagFilterPayload, TagFilterPayloadRaw, TradeNodeCountry, TradeNodeInfo,
+    TradeNodeMerchant, TradeNodeMerchantMode,
+};
+use eu4save::{CountryTag, models::TradeNode};
+use std::collections::HashMap;
+
+/// The number of collecting and steering countries reported per node
+const TOP_COUNTRIES: usize = 5;
+
+/// A country's presence in a trade node as recorded in the save
+#[derive(Debug, Clone, Copy, Default)]
+struct NodePresence {
+    power: f32,
+    steer_power: f32,
+    has_trader: bool,
+    has_capital: bool,
+}
+
+/// The power a country has in a trade node and how it is using it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NodePower {
+    tag: CountryTag,
+    power: f32,
+    collecting: bool,
+    has_merchant: bool,
+}
+
+impl NodePower {
+    fn from_presence(tag: CountryTag, presence: NodePresence) -> Option<Self> {
+        if presence.power <= 0.0 {
+            return None;
+        }
+
+        // A country always collects in its home node. Elsewhere a merchant
+        // collects unless it is steering, and power without a merchant
+        // is steered downstream.
+        let steering = presence.steer_power > 0.0;
+        Some(NodePower {
+            tag,
+            power: presence.power,
+            collecting: presence.has_capital || (presence.has_trader && !steering),
+            has_merchant: presence.has_trader,
+        })
+    }
+}
+
+fn node_powers(node: &TradeNode) -> impl Iterator<Item = NodePower> + '_ {
+    node.countries.iter().filter_map(|(tag, country)| {
+        let presence = NodePresence {
+            power: country.val,
+            steer_power: country.steer_power,
+            has_trader: country.has_trader,
+            has_capital: country.has_capital,
+        };
+        NodePower::from_presence(*tag, presence)
+    })
+}
+
+/// Each country's share of the total power in a node
+fn power_shares(powers: impl Iterator<Item = NodePower>, total: f32) -> HashMap<CountryTag, f32> {
+    powers
+        .map(|x| (x.tag, x.power / total.max(f32::EPSILON)))
+        .collect()
+}
+
+/// The strongest collecting and steering countries in a node
+fn top_powers(mut powers: Vec<NodePower>) -> (Vec<NodePower>, Vec<NodePower>) {
+    powers.sort_unstable_by(|a, b| b.power.total_cmp(&a.power));
+    let collectors = powers
+        .iter()
+        .filter(|x| x.collecting)
+        .take(TOP_COUNTRIES)
+        .copied()
+        .collect();
+    let steering = powers
+        .iter()
+        .filter(|x| !x.collecting)
+        .take(TOP_COUNTRIES)
+        .copied()
+        .collect();
+    (collectors, steering)
+}
+
+/// Share of trade power in each trade node, keyed by node name and country
+pub(crate) type TradePowerShares<'a> = HashMap<&'a str, HashMap<CountryTag, f32>>;
+
+impl SaveFileImpl {
+    pub(crate) fn trade_power_shares(&self) -> TradePowerShares<'_> {
+        self.query
+            .save()
+            .game
+            .trade
+            .nodes
+            .iter()
+            .map(|node| {
+                let shares = power_shares(node_powers(node), node.total);
+                (node.definitions.as_str(), shares)
+            })
+            .collect()
+    }
+
+    pub fn get_trade_nodes(&self, payload: TagFilterPayloadRaw) -> Vec<TradeNodeInfo> {
+        let filter = TagFilterPayload::from(payload);
+        let tags = self.matching_tags(&filter);
+        let country = |x: &NodePower| TradeNodeCountry {
+            country: self.localize_tag(x.tag),
+            power: x.power,
+        };
+
+        self.query
+            .save()
+            .game
+            .trade
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                let powers: Vec<_> = node_powers(node).collect();
+                if !tags.is_empty() && !powers.iter().any(|x| tags.contains(&x.tag)) {
+                    return None;
+                }
+
+                let merchants = powers
+                    .iter()
+                    .filter(|x| x.has_merchant && (tags.is_empty() || tags.contains(&x.tag)))
+                    .map(|x| TradeNodeMerchant {
+                        country: self.localize_tag(x.tag),
+                        mode: if x.collecting {
+                            TradeNodeMerchantMode::Collect
+                        } else {
+                            TradeNodeMerchantMode::Steer
+                        },
+                    })
+                    .collect();
+
+                let (collectors, steering) = top_powers(powers);
+                Some(TradeNodeInfo {
+                    name: node.definitions.clone(),
+                    local_value: node.local_value,
+                    incoming_value: (node.current - node.local_value).max(0.0),
+                    outgoing_value: node.outgoing,
+                    retention: node.retention,
+                    total_power: node.total,
+                    collectors: collectors.iter().map(country).collect(),
+                    steering: steering.iter().map(country).collect(),
+                    merchants,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(x: &str) -> CountryTag {
+        x.parse().unwrap()
+    }
+
+    fn presence(power: f32) -> NodePresence {
+        NodePresence {
+            power,
+            ..NodePresence::default()
+        }
+    }
+
+    #[test]
+    fn test_node_power_mode() {
+        let home = NodePresence {
+            has_capital: true,
+            steer_power: 1.0,
+            ..presence(10.0)
+        };
+        let home = NodePower::from_presence(tag("ENG"), home).unwrap();
+        assert!(home.collecting);
+        assert!(!home.has_merchant);
+
+        let collector = NodePresence {
+            has_trader: true,
+            ..presence(10.0)
+        };
+        let collector = NodePower::from_presence(tag("FRA"), collector).unwrap();
+        assert!(collector.collecting);
+        assert!(collector.has_merchant);
+
+        let steerer = NodePresence {
+            has_trader: true,
+            steer_power: 5.0,
+            ..presence(10.0)
+        };
+        let steerer = NodePower::from_presence(tag("CAS"), steerer).unwrap();
+        assert!(!steerer.collecting);
+        assert!(steerer.has_merchant);
+
+        let passive = NodePower::from_presence(tag("POR"), presence(10.0)).unwrap();
+        assert!(!passive.collecting);
+        assert!(!passive.has_merchant);
+
+        assert_eq!(NodePower::from_presence(tag("ARA"), presence(0.0)), None);
+    }
+
+    #[test]
+    fn test_power_shares() {
+        let powers = [("ENG", 30.0), ("FRA", 10.0)]
+            .into_iter()
+            .filter_map(|(t, power)| NodePower::from_presence(tag(t), presence(power)));
+        let shares = power_shares(powers, 50.0);
+        assert_eq!(shares.get(&tag("ENG")), Some(&0.6));
+        assert_eq!(shares.get(&tag("FRA")), Some(&0.2));
+
+        let powers = NodePower::from_presence(tag("ENG"), presence(1.0)).into_iter();
+        assert!(power_shares(powers, 0.0)[&tag("ENG")].is_finite());
+    }
+
+    #[test]
+    fn test_top_powers() {
+        let powers = (1..=7)
+            .map(|i| {
+                let presence = NodePresence {
+                    has_trader: true,
+                    steer_power: if i % 2 == 0 { 1.0 } else { 0.0 },
+                    ..presence(i as f32)
+                };
+                NodePower::from_presence(tag(&format!("A{i:02}")), presence).unwrap()
+            })
+            .collect();
+
+        let (collectors, steering) = top_powers(powers);
+        let power = |x: &[NodePower]| x.iter().map(|x| x.power).collect::<Vec<_>>();
+        assert_eq!(power(&collectors), vec![7.0, 5.0, 3.0, 1.0]);
+        assert_eq!(power(&steering), vec![6.0, 4.0, 2.0]);
+    }
+}
@@ -0,0 +1,421 @@
+use super::{
+    SaveFileImpl, WarGoalInfo, WarGoalStatus, WarOverview, WarScoreEstimate, WarScoreParticipant,
+    WarScorePoint, WarScoreSide,
+};
+use eu4save::{
+    CountryTag, Eu4Date, PdsDate,
+    models::{BattleSide, Province, ProvinceEvent, WarEvent},
+};
+use std::collections::{HashMap, HashSet};
+
+/// The most war score a side can earn from battles
+const BATTLE_SCORE_CAP: f32 = 40.0;
+
+/// The most war score a side can earn from holding the war goal
+const TICKING_SCORE_CAP: f32 = 25.0;
+
+/// Approximate battle war score for each thousand men lost by the losing
+/// side. The game doesn't record the war score of individual battles, so
+/// this is a rough estimate and not a game define.
+const LAND_SCORE_PER_THOUSAND: f32 = 0.5;
+
+/// Approximate battle war score for each ship lost by the losing side. Like
+/// land battles, this is a rough estimate and not a game define.
+const NAVAL_SCORE_PER_SHIP: f32 = 0.25;
+
+/// Estimated war score earned by the winner of a battle, derived from the
+/// losses of the losing side.
+fn battle_score(is_land: bool, loser_losses: i32) -> f32 {
+    let losses = loser_losses.max(0) as f32;
+    if is_land {
+        losses / 1000.0 * LAND_SCORE_PER_THOUSAND
+    } else {
+        losses * NAVAL_SCORE_PER_SHIP
+    }
+}
+
+fn is_land_battle(attacker: &BattleSide, defender: &BattleSide) -> bool {
+    [attacker, defender]
+        .iter()
+        .any(|side| side.infantry + side.cavalry + side.artillery > 0)
+}
+
+/// The share of the enemy's development that is occupied, as war score
+fn occupation_share(occupied: f32, total: f32) -> f32 {
+    if total > 0.0 {
+        occupied / total * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Whole months elapsed between two dates
+fn months_between(start: Eu4Date, end: Eu4Date) -> i32 {
+    let months = (i32::from(end.year()) * 12 + i32::from(end.month()))
+        - (i32::from(start.year()) * 12 + i32::from(start.month()));
+    let partial = i32::from(end.day() < start.day());
+    (months - partial).max(0)
+}
+
+/// Ticking war score for holding the war goal for the given months
+fn ticking_score(months: i32) -> f32 {
+    (months as f32).min(TICKING_SCORE_CAP)
+}
+
+/// Ticking war score of the attackers and defenders
+fn ticking_scores(status: WarGoalStatus, months: i32) -> (f32, f32) {
+    let ticking = ticking_score(months);
+    match status {
+        WarGoalStatus::Attackers => (ticking, 0.0),
+        WarGoalStatus::Defenders => (0.0, ticking),
+        WarGoalStatus::Unknown => (0.0, 0.0),
+    }
+}
+
+#[derive(Default)]
+struct BattleTally {
+    won: u32,
+    score: f32,
+}
+
+/// The owner and controller of a province throughout its history
+struct ProvinceControl {
+    dev: f32,
+
+    /// Date ordered owner and controller after each change
+    changes: Vec<(Eu4Date, Option<CountryTag>, Option<CountryTag>)>,
+}
+
+impl ProvinceControl {
+    fn new(prov: &Province) -> Self {
+        let mut owner = prov.history.owner;
+        let mut controller = owner;
+        let mut changes = vec![(Eu4Date::from_ymd(1, 1, 1), owner, controller)];
+        for (date, event) in &prov.history.events {
+            match event {
+                ProvinceEvent::Owner(x) => {
+                    owner = Some(*x);
+                    controller = Some(*x);
+                }
+                ProvinceEvent::Controller(x) => controller = Some(x.tag),
+                _ => continue,
+            }
+
+            changes.push((*date, owner, controller));
+        }
+
+        Self {
+            dev: prov.base_tax + prov.base_production + prov.base_manpower,
+            changes,
+        }
+    }
+
+    /// The owner and controller on the given date
+    fn at(&self, date: Eu4Date) -> (Option<CountryTag>, Option<CountryTag>) {
+        let idx = self.changes.partition_point(|(x, _, _)| *x <= date);
+        let (_, owner, controller) = self.changes[idx.saturating_sub(1)];
+        (owner, controller)
+    }
+
+    fn owners(&self) -> impl Iterator<Item = CountryTag> + '_ {
+        self.changes.iter().filter_map(|(_, owner, _)| *owner)
+    }
+}
+
+impl SaveFileImpl {
+    pub fn get_war_score_estimate(&self, name: &str) -> Option<WarScoreEstimate> {
+        let save = self.query.save();
+        let game = &save.game;
+        let active_war = game.active_wars.iter().find(|x| x.name == name);
+        let previous_war = game.previous_wars.iter().find(|x| x.name == name);
+        let war = active_war
+            .map(WarOverview::from)
+            .or_else(|| previous_war.map(WarOverview::from))?;
+
+        let start_date = war.history.events.iter().map(|(date, _)| *date).min();
+        let start_date = start_date.unwrap_or_else(eu4save::eu4_start_date);
+        let end_date = if war.is_active {
+            save.meta.date
+        } else {
+            let last = war.history.events.iter().map(|(date, _)| *date).max();
+            last.unwrap_or(start_date)
+        };
+
+        // Every country that took part in the war at some point
+        let everyone: HashSet<_> = war
+            .history
+            .events
+            .iter()
+            .filter_map(|(_, event)| match event {
+                WarEvent::AddAttacker(x) | WarEvent::AddDefender(x) => Some(*x),
+                _ => None,
+            })
+            .chain(war.participants.iter().map(|x| x.tag))
+            .collect();
+
+        let war_goal = active_war
+            .and_then(|x| x.war_goal.as_ref())
+            .map(|goal| (goal, goal.province.and_then(|id| game.provinces.get(&id))));
+        let goal_control = war_goal.and_then(|(_, prov)| prov.map(ProvinceControl::new));
+
+        let controls: Vec<_> = game
+            .provinces
+            .values()
+            .map(ProvinceControl::new)
+            .filter(|x| x.owners().any(|owner| everyone.contains(&owner)))
+            .collect();
+
+        // The timeline is sampled on every war event and on every change of
+        // control of the participants' provinces
+        let mut dates: Vec<_> = war
+            .history
+            .events
+            .iter()
+            .map(|(date, _)| *date)
+            .chain(controls.iter().flat_map(|x| x.changes.iter().map(|c| c.0)))
+            .chain([start_date, end_date])
+            .filter(|x| *x >= start_date && *x <= end_date)
+            .collect();
+        dates.sort_unstable();
+        dates.dedup();
+
+        let mut attackers = HashSet::new();
+        let mut current = HashSet::new();
+        let mut tallies: HashMap<CountryTag, BattleTally> = HashMap::new();
+        let mut attacker_battle_score = 0.0;
+        let mut defender_battle_score = 0.0;
+        let mut timeline = Vec::with_capacity(dates.len());
+        let mut events = war.history.events.iter().peekable();
+
+        for date in dates {
+            while let Some((_, event)) = events.next_if(|(x, _)| *x <= date) {
+                match event {
+                    WarEvent::AddAttacker(x) => {
+                        attackers.insert(*x);
+                        current.insert(*x);
+                    }
+                    WarEvent::AddDefender(x) => {
+                        current.insert(*x);
+                    }
+                    WarEvent::RemoveAttacker(x) | WarEvent::RemoveDefender(x) => {
+                        current.remove(x);
+                    }
+                    WarEvent::Battle(battle) => {
+                        let (winner, loser) = if battle.attacker_won {
+                            (&battle.attacker, &battle.defender)
+                        } else {
+                            (&battle.defender, &battle.attacker)
+                        };
+
+                        let is_land = is_land_battle(&battle.attacker, &battle.defender);
+                        let score = battle_score(is_land, loser.losses);
+                        let winner = winner.country;
+                        let tally = tallies.entry(winner).or_default();
+                        tally.won += 1;
+                        tally.score += score;
+
+                        if attackers.contains(&winner) {
+                            attacker_battle_score += score;
+                        } else {
+                            defender_battle_score += score;
+                        }
+                    }
+                }
+            }
+
+            let (attacker_occupation, defender_occupation) =
+                occupation_scores(&controls, date, &attackers, &current);
+            let (status, months) = goal_status(goal_control.as_ref(), date, start_date, &attackers);
+            let (attacker_ticking, defender_ticking) = ticking_scores(status, months);
+
+            let attacker_total = (attacker_battle_score.min(BATTLE_SCORE_CAP)
+                + attacker_occupation
+                + attacker_ticking)
+                .min(100.0);
+            let defender_total = (defender_battle_score.min(BATTLE_SCORE_CAP)
+                + defender_occupation
+                + defender_ticking)
+                .min(100.0);
+            timeline.push(WarScorePoint {
+                date,
+                score: (attacker_total - defender_total).clamp(-100.0, 100.0),
+            });
+        }
+
+        let (attacker_occupation, defender_occupation) =
+            occupation_scores(&controls, end_date, &attackers, &current);
+        let (status, months) = goal_status(goal_control.as_ref(), end_date, start_date, &attackers);
+
+        let war_goal = war_goal.map(|(goal, prov)| {
+            let controller = prov.and_then(|x| x.controller);
+            WarGoalInfo {
+                casus_belli: goal.casus_belli.clone(),
+                province: goal.province,
+                controller: controller.map(|x| self.localize_tag(x)),
+                status,
+                months,
+            }
+        });
+
+        let side = |is_attacker: bool, battle: f32, occupation: f32, ticking: f32| {
+            let participants = war
+                .participants
+                .iter()
+                .filter(|x| attackers.contains(&x.tag) == is_attacker)
+                .map(|x| {
+                    let tally = tallies.get(&x.tag);
+                    WarScoreParticipant {
+                        country: self.localize_tag(x.tag),
+                        battles_won: tally.map_or(0, |t| t.won),
+                        battle_score: tally.map_or(0.0, |t| t.score),
+                    }
+                })
+                .collect();
+
+            let battle_score = battle.min(BATTLE_SCORE_CAP);
+            WarScoreSide {
+                battle_score,
+                occupation_score: occupation,
+                ticking_score: ticking,
+                total: (battle_score + occupation + ticking).min(100.0),
+                participants,
+            }
+        };
+
+        let (attacker_ticking, defender_ticking) = ticking_scores(status, months);
+        let attackers = side(
+            true,
+            attacker_battle_score,
+            attacker_occupation,
+            attacker_ticking,
+        );
+        let defenders = side(
+            false,
+            defender_battle_score,
+            defender_occupation,
+            defender_ticking,
+        );
+
+        Some(WarScoreEstimate {
+            net: (attackers.total - defenders.total).clamp(-100.0, 100.0),
+            attackers,
+            defenders,
+            war_goal,
+            timeline,
+        })
+    }
+}
+
+/// War score each side earns on a date from occupying the other side's
+/// provinces, as the share of the enemy's development they control
+fn occupation_scores(
+    controls: &[ProvinceControl],
+    date: Eu4Date,
+    attackers: &HashSet<CountryTag>,
+    current: &HashSet<CountryTag>,
+) -> (f32, f32) {
+    let mut attacker_dev = 0.0;
+    let mut defender_dev = 0.0;
+    let mut attacker_occupied = 0.0;
+    let mut defender_occupied = 0.0;
+
+    for prov in controls {
+        let (owner, controller) = prov.at(date);
+        let Some(owner) = owner.filter(|x| current.contains(x)) else {
+            continue;
+        };
+
+        let controller = controller.filter(|x| current.contains(x));
+        if attackers.contains(&owner) {
+            attacker_dev += prov.dev;
+            if controller.is_some_and(|x| !attackers.contains(&x)) {
+                defender_occupied += prov.dev;
+            }
+        } else {
+            defender_dev += prov.dev;
+            if controller.is_some_and(|x| attackers.contains(&x)) {
+                attacker_occupied += prov.dev;
+            }
+        }
+    }
+
+    (
+        occupation_share(attacker_occupied, defender_dev),
+        occupation_share(defender_occupied, attacker_dev),
+    )
+}
+
+/// Which side holds the war goal on a date and for how many months. War
+/// score ticks from when the controller took the province or the start of
+/// the war, whichever is later.
+fn goal_status(
+    goal: Option<&ProvinceControl>,
+    date: Eu4Date,
+    start_date: Eu4Date,
+    attackers: &HashSet<CountryTag>,
+) -> (WarGoalStatus, i32) {
+    let Some(goal) = goal else {
+        return (WarGoalStatus::Unknown, 0);
+    };
+
+    let (_, Some(controller)) = goal.at(date) else {
+        return (WarGoalStatus::Unknown, 0);
+    };
+
+    let mut controlled_since = None;
+    for (x, _, c) in goal.changes.iter().take_while(|(x, _, _)| *x <= date) {
+        if *c != Some(controller) {
+            controlled_since = None;
+        } else if controlled_since.is_none() {
+            controlled_since = Some(*x);
+        }
+    }
+    let controlled_since = controlled_since.map_or(start_date, |x| x.max(start_date));
+
+    let status = if attackers.contains(&controller) {
+        WarGoalStatus::Attackers
+    } else {
+        WarGoalStatus::Defenders
+    };
+
+    (status, months_between(controlled_since, date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_land_battle_score() {
+        assert_eq!(battle_score(true, 6_000), 3.0);
+        assert_eq!(battle_score(true, 2_000), 1.0);
+        assert_eq!(battle_score(true, -10), 0.0);
+    }
+
+    #[test]
+    fn test_naval_battle_score() {
+        assert_eq!(battle_score(false, 8), 2.0);
+        assert_eq!(battle_score(false, 2), 0.5);
+    }
+
+    #[test]
+    fn test_occupation_share() {
+        assert_eq!(occupation_share(25.0, 100.0), 25.0);
+        assert_eq!(occupation_share(10.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_months_between() {
+        let start = Eu4Date::from_ymd(1444, 11, 11);
+        assert_eq!(months_between(start, Eu4Date::from_ymd(1444, 12, 10)), 0);
+        assert_eq!(months_between(start, Eu4Date::from_ymd(1444, 12, 11)), 1);
+        assert_eq!(months_between(start, Eu4Date::from_ymd(1446, 1, 1)), 13);
+        assert_eq!(months_between(start, start), 0);
+    }
+
+    #[test]
+    fn test_ticking_score_capped() {
+        assert_eq!(ticking_score(6), 6.0);
+        assert_eq!(ticking_score(40), TICKING_SCORE_CAP);
+    }
+}
@@ -13,15 +13,19 @@ use eu5app::game_data::{OptimizedGameBundle, OptimizedLocalizationBundle};
 use eu5app::hover::presentation::DisplayData as HoverDisplayData;
 use eu5app::insights::buildings::presentation::BuildingLevelsInsightData;
 use eu5app::insights::control::presentation::{ControlInsightData, PoliticalWorldScoreboard};
+use eu5app::insights::culture::presentation::CultureInsightData;
 use eu5app::insights::development::presentation::DevelopmentInsightData;
 use eu5app::insights::markets::presentation::{
     MarketInsightData, MarketProductionLocationSummary, ScopedGoodSummary,
 };
+use eu5app::insights::pop_attributes::presentation::PopAttributeInsightData;
 use eu5app::insights::population::presentation::PopulationInsightData;
+use eu5app::insights::prosperity::presentation::ProsperityInsightData;
 use eu5app::insights::religion::presentation::ReligionInsightData;
 use eu5app::insights::rgo::presentation::RgoInsightData;
 use eu5app::insights::state_efficacy::presentation::StateEfficacyInsightData;
 use eu5app::insights::tax::presentation::{UnrealizedTaxBaseInsightData, WealthInsightData};
+use eu5app::insights::wars::presentation::WarsInsightData;
 use eu5app::insights::{UnrealizedTaxBaseScope, WealthScope};
 use eu5app::{CanvasDimensions, MapMode as Eu5MapMode};
 use eu5app::{Eu5LoadedSave, Eu5SaveLoader};
@@ -49,6 +53,14 @@ pub enum MapMode {
     Wealth,
     UnrealizedTaxBase,
     Religion,
+    Culture,
+    Literacy,
+    Satisfaction,
+    Prosperity,
+    Proximity,
+    MarketAccess,
+    MarketAttraction,
+    Occupation,
     StateEfficacy,
 }
 
@@ -88,6 +100,14 @@ impl From<MapMode> for Eu5MapMode {
             MapMode::Wealth => Eu5MapMode::Wealth,
             MapMode::UnrealizedTaxBase => Eu5MapMode::UnrealizedTaxBase,
             MapMode::Religion => Eu5MapMode::Religion,
+            MapMode::Culture => Eu5MapMode::Culture,
+            MapMode::Literacy => Eu5MapMode::Literacy,
+            MapMode::Satisfaction => Eu5MapMode::Satisfaction,
+            MapMode::Prosperity => Eu5MapMode::Prosperity,
+            MapMode::Proximity => Eu5MapMode::Proximity,
+            MapMode::MarketAccess => Eu5MapMode::MarketAccess,
+            MapMode::MarketAttraction => Eu5MapMode::MarketAttraction,
+            MapMode::Occupation => Eu5MapMode::Occupation,
             MapMode::StateEfficacy => Eu5MapMode::StateEfficacy,
         }
     }
@@ -106,6 +126,14 @@ impl From<Eu5MapMode> for MapMode {
             Eu5MapMode::Wealth => MapMode::Wealth,
             Eu5MapMode::UnrealizedTaxBase => MapMode::UnrealizedTaxBase,
             Eu5MapMode::Religion => MapMode::Religion,
+            Eu5MapMode::Culture => MapMode::Culture,
+            Eu5MapMode::Literacy => MapMode::Literacy,
+            Eu5MapMode::Satisfaction => MapMode::Satisfaction,
+            Eu5MapMode::Prosperity => MapMode::Prosperity,
+            Eu5MapMode::Proximity => MapMode::Proximity,
+            Eu5MapMode::MarketAccess => MapMode::MarketAccess,
+            Eu5MapMode::MarketAttraction => MapMode::MarketAttraction,
+            Eu5MapMode::Occupation => MapMode::Occupation,
             Eu5MapMode::StateEfficacy => MapMode::StateEfficacy,
         }
     }
@@ -922,6 +950,44 @@ impl Eu5App {
         self.localized().presenter().calculate_religion_insight()
     }
 
+    /// Culture insight data: culture population shares and per-country cultural
+    /// composition for the current filter.
+    #[wasm_bindgen]
+    pub fn get_culture_insight(&self) -> CultureInsightData {
+        self.localized().presenter().calculate_culture_insight()
+    }
+
+    /// Literacy insight data: pop size weighted literacy by pop type, country
+    /// and least literate locations for the current filter.
+    #[wasm_bindgen]
+    pub fn get_literacy_insight(&self) -> PopAttributeInsightData {
+        self.localized().presenter().calculate_literacy_insight()
+    }
+
+    /// Satisfaction insight data: pop size weighted satisfaction by pop type,
+    /// country and least satisfied locations for the current filter.
+    #[wasm_bindgen]
+    pub fn get_satisfaction_insight(&self) -> PopAttributeInsightData {
+        self.localized()
+            .presenter()
+            .calculate_satisfaction_insight()
+    }
+
+    /// Prosperity insight data: how proximity, control and market access
+    /// relate to prosperity, and the least prosperous locations for the
+    /// current filter.
+    #[wasm_bindgen]
+    pub fn get_prosperity_insight(&self) -> ProsperityInsightData {
+        self.localized().presenter().calculate_prosperity_insight()
+    }
+
+    /// Wars insight data: occupied locations and the wars with battles fought
+    /// in the current filter.
+    #[wasm_bindgen]
+    pub fn get_wars_insight(&self) -> WarsInsightData {
+        self.localized().presenter().calculate_wars_insight()
+    }
+
     /// RGO insight data: scoped raw-material capacity by material and location,
     /// profile deltas against global share, and owner-control breakdown.
     #[wasm_bindgen]
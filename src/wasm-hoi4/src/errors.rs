@@ -0,0 +1,8 @@
+#[derive(thiserror::Error, Debug)]
+pub enum Hoi4AnalysisError {
+    #[error("invalid country tag: {0}")]
+    InvalidTag(String),
+
+    #[error("country not found in save: {0}")]
+    UnknownCountry(String),
+}
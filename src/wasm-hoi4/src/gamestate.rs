@@ -0,0 +1,135 @@
+use hoi4save::{CountryTag, Hoi4Date};
+use jomini::JominiDeserialize;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The HOI4 gamestate needed for analysis.
+///
+/// `hoi4save::models::Hoi4Save` doesn't model armies, production, wars or
+/// factions, so the save is deserialized here in a single pass instead.
+#[derive(Debug, JominiDeserialize)]
+pub struct Hoi4Gamestate {
+    #[jomini(default)]
+    pub player: Option<String>,
+    pub date: Hoi4Date,
+    #[jomini(default)]
+    pub countries: HashMap<CountryTag, Hoi4Country>,
+    #[jomini(duplicated)]
+    pub active_war: Vec<Hoi4War>,
+    #[jomini(duplicated)]
+    pub faction: Vec<Hoi4Faction>,
+}
+
+#[derive(Debug, Default, JominiDeserialize)]
+pub struct Hoi4Country {
+    #[jomini(default)]
+    pub stability: f64,
+    #[jomini(default)]
+    pub war_support: f64,
+    #[jomini(default)]
+    pub variables: HashMap<String, f64>,
+    #[jomini(duplicated)]
+    pub division_template: Vec<DivisionTemplate>,
+    #[jomini(default)]
+    pub units: Units,
+    #[jomini(default)]
+    pub equipment_stockpile: HashMap<String, f64>,
+    #[jomini(default)]
+    pub production: Production,
+}
+
+/// Identifier pairs used throughout HOI4 saves (eg: `id={ id=1 type=52 }`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub struct ObjectId {
+    pub id: u32,
+    #[serde(rename = "type")]
+    pub kind: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DivisionTemplate {
+    pub id: Option<ObjectId>,
+    pub name: String,
+}
+
+#[derive(Debug, Default, JominiDeserialize)]
+pub struct Units {
+    #[jomini(duplicated)]
+    pub division: Vec<Division>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Division {
+    pub name: Option<String>,
+    pub division_template_id: Option<ObjectId>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Production {
+    #[serde(default)]
+    pub military_lines: MilitaryLines,
+}
+
+#[derive(Debug, Default, JominiDeserialize)]
+pub struct MilitaryLines {
+    #[jomini(duplicated)]
+    pub line: Vec<ProductionLine>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProductionLine {
+    pub equipment: Option<EquipmentRef>,
+    #[serde(default)]
+    pub factories: f64,
+    #[serde(default)]
+    pub efficiency: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EquipmentRef {
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Hoi4War {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub attackers: WarSide,
+    #[serde(default)]
+    pub defenders: WarSide,
+}
+
+#[derive(Debug, Default, JominiDeserialize)]
+pub struct WarSide {
+    #[jomini(default)]
+    pub original: Option<CountryTag>,
+    #[jomini(duplicated)]
+    pub participant: Vec<WarParticipant>,
+}
+
+impl WarSide {
+    /// The participating countries with the original belligerent first
+    pub fn countries(&self) -> Vec<CountryTag> {
+        let mut result: Vec<_> = self.original.into_iter().collect();
+        for participant in &self.participant {
+            if !result.contains(&participant.tag) {
+                result.push(participant.tag);
+            }
+        }
+        result
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WarParticipant {
+    pub tag: CountryTag,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Hoi4Faction {
+    pub name: Option<String>,
+    pub leader: Option<CountryTag>,
+    #[serde(default, alias = "member")]
+    pub members: Vec<CountryTag>,
+}
@@ -1,14 +1,22 @@
 use hoi4save::{
-    CountryTag, Encoding, FailedResolveStrategy, Hoi4Error, Hoi4File, MeltOptions, models::Hoi4Save,
+    CountryTag, Encoding, FailedResolveStrategy, Hoi4Error, Hoi4File, Hoi4SliceFileKind,
+    MeltOptions,
 };
 use std::{collections::HashMap, io::Cursor};
 use wasm_bindgen::prelude::*;
 
+mod errors;
+mod gamestate;
 mod log;
 mod tokens;
 pub use tokens::*;
 
-use crate::models::{CountryDetails, Hoi4Metadata};
+use crate::errors::Hoi4AnalysisError;
+use crate::gamestate::{Hoi4Country, Hoi4Gamestate};
+use crate::models::{
+    CountryDetails, CountryDivisions, CountryEquipment, DivisionTemplateSummary, EquipmentStock,
+    FactionOverview, Factions, Hoi4Metadata, ProductionLineSummary, WarOverview, Wars,
+};
 mod models;
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -16,7 +24,7 @@ const COUNTRY_TAG_TYPE: &'static str = r#"export type CountryTag = string;"#;
 
 #[derive(Debug)]
 pub struct SaveFileImpl {
-    save: Hoi4Save,
+    save: Hoi4Gamestate,
     encoding: Encoding,
 }
 
@@ -30,8 +38,28 @@ impl SaveFile {
         self.0.metadata()
     }
 
-    pub fn country_details(&self, tag: String) -> CountryDetails {
-        self.0.country_details(tag)
+    pub fn country_details(&self, tag: String) -> Result<CountryDetails, JsError> {
+        Ok(self.0.country_details(&tag)?)
+    }
+
+    pub fn country_divisions(&self, tag: String) -> Result<CountryDivisions, JsError> {
+        Ok(self.0.country_divisions(&tag)?)
+    }
+
+    pub fn country_equipment(&self, tag: String) -> Result<CountryEquipment, JsError> {
+        Ok(self.0.country_equipment(&tag)?)
+    }
+
+    pub fn wars(&self) -> Wars {
+        Wars {
+            wars: self.0.wars(),
+        }
+    }
+
+    pub fn factions(&self) -> Factions {
+        Factions {
+            factions: self.0.factions(),
+        }
     }
 }
 
@@ -51,9 +79,21 @@ impl SaveFileImpl {
         matches!(self.encoding, Encoding::Binary)
     }
 
-    pub fn country_details(&self, tag: String) -> CountryDetails {
-        let tag = tag.parse::<CountryTag>().unwrap();
-        let (_, country) = self.save.countries.iter().find(|(t, _)| *t == tag).unwrap();
+    fn parse_tag(tag: &str) -> Result<CountryTag, Hoi4AnalysisError> {
+        tag.parse::<CountryTag>()
+            .map_err(|_| Hoi4AnalysisError::InvalidTag(String::from(tag)))
+    }
+
+    fn country(&self, tag: &str) -> Result<&Hoi4Country, Hoi4AnalysisError> {
+        let country_tag = Self::parse_tag(tag)?;
+        self.save
+            .countries
+            .get(&country_tag)
+            .ok_or_else(|| Hoi4AnalysisError::UnknownCountry(String::from(tag)))
+    }
+
+    pub fn country_details(&self, tag: &str) -> Result<CountryDetails, Hoi4AnalysisError> {
+        let country = self.country(tag)?;
 
         let variable_groups = country.variables.iter().filter_map(|(k, v)| {
             k.rsplit_once("^").and_then(|(cat, ind)| {
@@ -80,18 +120,115 @@ impl SaveFileImpl {
             })
             .collect();
 
-        CountryDetails {
+        Ok(CountryDetails {
             stability: country.stability,
             war_support: country.war_support,
             variable_categories,
             variables: country.variables.clone(),
+        })
+    }
+
+    pub fn country_divisions(&self, tag: &str) -> Result<CountryDivisions, Hoi4AnalysisError> {
+        let country = self.country(tag)?;
+
+        let mut counts = HashMap::new();
+        for division in &country.units.division {
+            if let Some(id) = division.division_template_id {
+                *counts.entry(id).or_insert(0usize) += 1;
+            }
         }
+
+        let mut templates: Vec<_> = country
+            .division_template
+            .iter()
+            .map(|template| DivisionTemplateSummary {
+                name: template.name.clone(),
+                divisions: template
+                    .id
+                    .and_then(|id| counts.get(&id).copied())
+                    .unwrap_or(0),
+            })
+            .collect();
+
+        templates.sort_unstable_by(|a, b| {
+            b.divisions
+                .cmp(&a.divisions)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Ok(CountryDivisions {
+            total: country.units.division.len(),
+            templates,
+        })
+    }
+
+    pub fn country_equipment(&self, tag: &str) -> Result<CountryEquipment, Hoi4AnalysisError> {
+        let country = self.country(tag)?;
+
+        let mut stockpile: Vec<_> = country
+            .equipment_stockpile
+            .iter()
+            .map(|(equipment, amount)| EquipmentStock {
+                equipment: equipment.clone(),
+                amount: *amount,
+            })
+            .collect();
+        stockpile.sort_unstable_by(|a, b| b.amount.total_cmp(&a.amount));
+
+        let mut production_lines: Vec<_> = country
+            .production
+            .military_lines
+            .line
+            .iter()
+            .map(|line| ProductionLineSummary {
+                equipment: line.equipment.as_ref().and_then(|x| x.kind.clone()),
+                factories: line.factories,
+                efficiency: line.efficiency,
+            })
+            .collect();
+        production_lines.sort_unstable_by(|a, b| b.factories.total_cmp(&a.factories));
+
+        Ok(CountryEquipment {
+            stockpile,
+            production_lines,
+        })
+    }
+
+    pub fn wars(&self) -> Vec<WarOverview> {
+        self.save
+            .active_war
+            .iter()
+            .map(|war| WarOverview {
+                name: war.name.clone(),
+                attackers: war.attackers.countries(),
+                defenders: war.defenders.countries(),
+            })
+            .collect()
+    }
+
+    pub fn factions(&self) -> Vec<FactionOverview> {
+        self.save
+            .faction
+            .iter()
+            .map(|faction| {
+                let mut members = faction.members.clone();
+                members.sort_unstable();
+                FactionOverview {
+                    name: faction.name.clone(),
+                    leader: faction.leader,
+                    members,
+                }
+            })
+            .collect()
     }
 }
 
 fn _parse_save(data: &[u8]) -> Result<SaveFile, Hoi4Error> {
     let file = Hoi4File::from_slice(data)?;
-    let save = file.parse_save(tokens::get_tokens())?;
+    let save: Hoi4Gamestate = match file.kind() {
+        Hoi4SliceFileKind::Text(x) => x.deserializer().deserialize()?,
+        Hoi4SliceFileKind::Binary(x) => x.deserializer(tokens::get_tokens()).deserialize()?,
+    };
     Ok(SaveFile(SaveFileImpl {
         save,
         encoding: file.encoding(),
@@ -118,3 +255,180 @@ pub fn melt(data: &[u8]) -> Result<js_sys::Uint8Array, JsError> {
         .map(|x| js_sys::Uint8Array::from(x.as_slice()))
         .map_err(JsError::from)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAVE: &str = r#"HOI4txt
+player="GER"
+date="1939.9.1.12"
+countries={
+    GER={
+        stability=0.65
+        war_support=0.8
+        variables={
+            factories^0=12
+            factories^1=15
+            factories^num=2
+            score=3
+        }
+        division_template={
+            id={ id=1 type=52 }
+            name="Infantry Division"
+        }
+        division_template={
+            id={ id=2 type=52 }
+            name="Panzer Division"
+        }
+        division_template={
+            id={ id=3 type=52 }
+            name="Cavalry Division"
+        }
+        units={
+            division={ name="1. Infanterie" division_template_id={ id=1 type=52 } }
+            division={ name="2. Infanterie" division_template_id={ id=1 type=52 } }
+            division={ name="1. Panzer" division_template_id={ id=2 type=52 } }
+            division={ name="Unknown" }
+        }
+        equipment_stockpile={
+            artillery_equipment_1=800
+            infantry_equipment_1=5000
+        }
+        production={
+            military_lines={
+                line={ equipment={ type=artillery_equipment_1 } factories=3 efficiency=0.4 }
+                line={ equipment={ type=infantry_equipment_1 } factories=10 efficiency=0.9 }
+            }
+        }
+    }
+    ITA={ }
+    POL={ }
+}
+active_war={
+    name="German-Polish War"
+    attackers={
+        original="GER"
+        participant={ tag="GER" }
+        participant={ tag="ITA" }
+    }
+    defenders={
+        original="POL"
+        participant={ tag="POL" }
+    }
+}
+faction={
+    name="Axis"
+    leader="GER"
+    members={ "ITA" "GER" }
+}
+"#;
+
+    fn save() -> SaveFileImpl {
+        _parse_save(SAVE.as_bytes()).unwrap().0
+    }
+
+    fn tags(tags: &[&str]) -> Vec<CountryTag> {
+        tags.iter().map(|x| x.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = save().metadata();
+        assert_eq!(metadata.player.as_deref(), Some("GER"));
+        assert!(!metadata.is_meltable);
+
+        assert_eq!(metadata.countries, tags(&["GER", "ITA", "POL"]));
+    }
+
+    #[test]
+    fn test_country_details_groups_variables() {
+        let details = save().country_details("GER").unwrap();
+        assert_eq!(details.stability, 0.65);
+        assert_eq!(details.war_support, 0.8);
+        assert_eq!(details.variable_categories.len(), 1);
+        assert_eq!(details.variable_categories["factories"], vec![12.0, 15.0]);
+        assert_eq!(details.variables["score"], 3.0);
+    }
+
+    #[test]
+    fn test_country_divisions() {
+        let divisions = save().country_divisions("GER").unwrap();
+        assert_eq!(divisions.total, 4);
+
+        let templates: Vec<_> = divisions
+            .templates
+            .iter()
+            .map(|x| (x.name.as_str(), x.divisions))
+            .collect();
+        assert_eq!(
+            templates,
+            vec![
+                ("Infantry Division", 2),
+                ("Panzer Division", 1),
+                ("Cavalry Division", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_country_equipment() {
+        let equipment = save().country_equipment("GER").unwrap();
+
+        let stockpile: Vec<_> = equipment
+            .stockpile
+            .iter()
+            .map(|x| (x.equipment.as_str(), x.amount))
+            .collect();
+        assert_eq!(
+            stockpile,
+            vec![
+                ("infantry_equipment_1", 5000.0),
+                ("artillery_equipment_1", 800.0),
+            ]
+        );
+
+        let lines: Vec<_> = equipment
+            .production_lines
+            .iter()
+            .map(|x| (x.equipment.as_deref(), x.factories))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (Some("infantry_equipment_1"), 10.0),
+                (Some("artillery_equipment_1"), 3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_country() {
+        assert!(matches!(
+            save().country_divisions("ENG"),
+            Err(Hoi4AnalysisError::UnknownCountry(_))
+        ));
+    }
+
+    #[test]
+    fn test_wars() {
+        let wars = save().wars();
+        assert_eq!(wars.len(), 1);
+
+        let war = &wars[0];
+        assert_eq!(war.name.as_deref(), Some("German-Polish War"));
+        assert_eq!(war.attackers, tags(&["GER", "ITA"]));
+        assert_eq!(war.defenders, tags(&["POL"]));
+    }
+
+    #[test]
+    fn test_factions() {
+        let factions = save().factions();
+        assert_eq!(factions.len(), 1);
+
+        let faction = &factions[0];
+        assert_eq!(faction.name.as_deref(), Some("Axis"));
+        assert_eq!(faction.leader, tags(&["GER"]).pop());
+        assert_eq!(faction.members, tags(&["GER", "ITA"]));
+    }
+}
@@ -24,3 +24,71 @@ pub struct CountryDetails {
     pub variable_categories: HashMap<String, Vec<f64>>,
     pub variables: HashMap<String, f64>,
 }
+
+#[derive(Tsify, Debug, Serialize)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct CountryDivisions {
+    pub total: usize,
+    pub templates: Vec<DivisionTemplateSummary>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionTemplateSummary {
+    pub name: String,
+    pub divisions: usize,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct CountryEquipment {
+    pub stockpile: Vec<EquipmentStock>,
+    pub production_lines: Vec<ProductionLineSummary>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EquipmentStock {
+    pub equipment: String,
+    pub amount: f64,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductionLineSummary {
+    pub equipment: Option<String>,
+    pub factories: f64,
+    pub efficiency: f64,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct Wars {
+    pub wars: Vec<WarOverview>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarOverview {
+    pub name: Option<String>,
+    pub attackers: Vec<CountryTag>,
+    pub defenders: Vec<CountryTag>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct Factions {
+    pub factions: Vec<FactionOverview>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FactionOverview {
+    pub name: Option<String>,
+    pub leader: Option<CountryTag>,
+    pub members: Vec<CountryTag>,
+}
@@ -0,0 +1,155 @@
+use imperator_save::ImperatorDate;
+use pdx_serde::maybe_map;
+use serde::{Deserialize, de};
+use std::collections::HashMap;
+
+/// The subset of the Imperator gamestate needed for country, province, and
+/// family insights.
+#[derive(Debug, Deserialize)]
+pub struct ImperatorGamestate {
+    pub version: String,
+    pub date: ImperatorDate,
+    #[serde(default)]
+    pub played_country: Vec<PlayedCountry>,
+    #[serde(default)]
+    pub country: CountryManager,
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub provinces: HashMap<u32, Option<Province>>,
+    #[serde(default)]
+    pub population: PopulationManager,
+    #[serde(default)]
+    pub character: CharacterManager,
+    #[serde(default)]
+    pub family: FamilyManager,
+}
+
+impl ImperatorGamestate {
+    pub fn countries(&self) -> impl Iterator<Item = (u32, &Country)> {
+        self.country
+            .country_database
+            .iter()
+            .filter_map(|(id, country)| country.as_ref().map(|c| (*id, c)))
+    }
+
+    pub fn country_by_tag(&self, tag: &str) -> Option<(u32, &Country)> {
+        self.countries().find(|(_, country)| country.tag == tag)
+    }
+
+    pub fn provinces(&self) -> impl Iterator<Item = (u32, &Province)> {
+        self.provinces
+            .iter()
+            .filter_map(|(id, province)| province.as_ref().map(|p| (*id, p)))
+    }
+
+    pub fn pop(&self, id: u32) -> Option<&Pop> {
+        self.population.pop.get(&id).and_then(|x| x.as_ref())
+    }
+
+    pub fn characters(&self) -> impl Iterator<Item = (u32, &Character)> {
+        self.character
+            .character_database
+            .iter()
+            .filter_map(|(id, character)| character.as_ref().map(|c| (*id, c)))
+    }
+
+    pub fn families(&self) -> impl Iterator<Item = (u32, &Family)> {
+        self.family
+            .families
+            .iter()
+            .filter_map(|(id, family)| family.as_ref().map(|f| (*id, f)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayedCountry {
+    pub name: Option<String>,
+    pub country: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CountryManager {
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub country_database: HashMap<u32, Option<Country>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Country {
+    pub tag: String,
+    pub country_name: Option<LocalizedName>,
+    #[serde(default)]
+    pub currency_data: CurrencyData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LocalizedName {
+    pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CurrencyData {
+    #[serde(default)]
+    pub gold: f64,
+    #[serde(default)]
+    pub manpower: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Province {
+    pub province_name: Option<LocalizedName>,
+    pub owner: Option<u32>,
+    pub culture: Option<String>,
+    pub religion: Option<String>,
+    #[serde(default)]
+    pub pop: Vec<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PopulationManager {
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub pop: HashMap<u32, Option<Pop>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Pop {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub culture: Option<String>,
+    pub religion: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CharacterManager {
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub character_database: HashMap<u32, Option<Character>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Character {
+    pub first_name_loc: Option<LocalizedName>,
+    pub family: Option<u32>,
+    pub country: Option<u32>,
+    pub age: Option<u32>,
+    #[serde(default)]
+    pub female: bool,
+    pub death_date: Option<de::IgnoredAny>,
+}
+
+impl Character {
+    pub fn is_alive(&self) -> bool {
+        self.death_date.is_none()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FamilyManager {
+    #[serde(default, deserialize_with = "maybe_map")]
+    pub families: HashMap<u32, Option<Family>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Family {
+    pub key: String,
+    pub owner: Option<u32>,
+    #[serde(default)]
+    pub prestige: f64,
+}
@@ -1,12 +1,20 @@
+use crate::gamestate::{Character, Country, ImperatorGamestate};
+use crate::models::{
+    ImperatorCharacter, ImperatorCharacters, ImperatorCountries, ImperatorCountryRanking,
+    ImperatorCountryRef, ImperatorFamilies, ImperatorFamily, ImperatorPopShare,
+    ImperatorProvinceDetails,
+};
 use imperator_save::{
     DeserializeImperator, FailedResolveStrategy, ImperatorDate, ImperatorError, ImperatorFile,
     ImperatorMelt, MeltOptions, SaveHeader,
-    models::{Metadata, Save},
 };
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::io::Cursor;
 use wasm_bindgen::prelude::*;
 
+mod gamestate;
+mod models;
 mod tokens;
 pub use tokens::*;
 
@@ -20,7 +28,7 @@ pub struct ImperatorMetadata {
 
 #[derive(Debug)]
 pub struct SaveFileImpl {
-    header: Metadata,
+    save: ImperatorGamestate,
     encoding: SaveHeader,
 }
 
@@ -38,13 +46,35 @@ impl SaveFile {
     pub fn metadata(&self) -> JsValue {
         to_json_value(&self.0.metadata())
     }
+
+    pub fn get_countries(&self) -> ImperatorCountries {
+        ImperatorCountries {
+            countries: self.0.get_countries(),
+        }
+    }
+
+    pub fn get_province_details(&self, province_id: u32) -> Option<ImperatorProvinceDetails> {
+        self.0.get_province_details(province_id)
+    }
+
+    pub fn get_country_characters(&self, tag: &str) -> Option<ImperatorCharacters> {
+        self.0
+            .get_country_characters(tag)
+            .map(|characters| ImperatorCharacters { characters })
+    }
+
+    pub fn get_country_families(&self, tag: &str) -> Option<ImperatorFamilies> {
+        self.0
+            .get_country_families(tag)
+            .map(|families| ImperatorFamilies { families })
+    }
 }
 
 impl SaveFileImpl {
     pub fn metadata(&self) -> ImperatorMetadata {
         ImperatorMetadata {
-            date: self.header.date,
-            version: self.header.version.clone(),
+            date: self.save.date,
+            version: self.save.version.clone(),
             is_meltable: self.is_meltable(),
         }
     }
@@ -52,13 +82,169 @@ impl SaveFileImpl {
     fn is_meltable(&self) -> bool {
         self.encoding.kind().is_binary()
     }
+
+    fn country_ref(&self, id: u32, country: &Country) -> ImperatorCountryRef {
+        ImperatorCountryRef {
+            id,
+            tag: country.tag.clone(),
+            name: country
+                .country_name
+                .as_ref()
+                .map(|x| x.name.clone())
+                .unwrap_or_else(|| country.tag.clone()),
+        }
+    }
+
+    /// Countries ranked by the number of territories they own with ties
+    /// broken by population.
+    pub fn get_countries(&self) -> Vec<ImperatorCountryRanking> {
+        let players: HashSet<u32> = self.save.played_country.iter().map(|x| x.country).collect();
+
+        let mut owned: HashMap<u32, (usize, usize)> = HashMap::new();
+        for (_, province) in self.save.provinces() {
+            if let Some(owner) = province.owner {
+                let entry = owned.entry(owner).or_default();
+                entry.0 += 1;
+                entry.1 += province.pop.len();
+            }
+        }
+
+        let mut result: Vec<_> = self
+            .save
+            .countries()
+            .filter_map(|(id, country)| {
+                let (territories, population) = owned.get(&id).copied()?;
+                Some(ImperatorCountryRanking {
+                    country: self.country_ref(id, country),
+                    is_player: players.contains(&id),
+                    territories,
+                    population,
+                    treasury: country.currency_data.gold,
+                    manpower: country.currency_data.manpower,
+                })
+            })
+            .collect();
+
+        result.sort_unstable_by(|a, b| {
+            b.territories
+                .cmp(&a.territories)
+                .then(b.population.cmp(&a.population))
+                .then_with(|| a.country.tag.cmp(&b.country.tag))
+        });
+        result
+    }
+
+    pub fn get_province_details(&self, province_id: u32) -> Option<ImperatorProvinceDetails> {
+        let province = self.save.provinces.get(&province_id)?.as_ref()?;
+        let owner = province.owner.and_then(|id| {
+            self.save
+                .country
+                .country_database
+                .get(&id)
+                .and_then(|x| x.as_ref())
+                .map(|country| self.country_ref(id, country))
+        });
+
+        let mut pop_types = HashMap::new();
+        let mut cultures = HashMap::new();
+        let mut religions = HashMap::new();
+        let pops = province.pop.iter().filter_map(|id| self.save.pop(*id));
+        for pop in pops {
+            *pop_types.entry(pop.kind.as_str()).or_default() += 1;
+            if let Some(culture) = pop.culture.as_deref() {
+                *cultures.entry(culture).or_default() += 1;
+            }
+            if let Some(religion) = pop.religion.as_deref() {
+                *religions.entry(religion).or_default() += 1;
+            }
+        }
+
+        Some(ImperatorProvinceDetails {
+            id: province_id,
+            name: province.province_name.as_ref().map(|x| x.name.clone()),
+            owner,
+            culture: province.culture.clone(),
+            religion: province.religion.clone(),
+            population: province.pop.len(),
+            pop_types: pop_shares(pop_types),
+            cultures: pop_shares(cultures),
+            religions: pop_shares(religions),
+        })
+    }
+
+    pub fn get_country_characters(&self, tag: &str) -> Option<Vec<ImperatorCharacter>> {
+        let (country_id, _) = self.save.country_by_tag(tag)?;
+        let mut result: Vec<_> = self
+            .save
+            .characters()
+            .filter(|(_, character)| character.country == Some(country_id))
+            .map(|(id, character)| imperator_character(id, character))
+            .collect();
+        result.sort_unstable_by_key(|x| (!x.is_alive, x.id));
+        Some(result)
+    }
+
+    pub fn get_country_families(&self, tag: &str) -> Option<Vec<ImperatorFamily>> {
+        let (country_id, _) = self.save.country_by_tag(tag)?;
+        let mut families: HashMap<u32, ImperatorFamily> = self
+            .save
+            .families()
+            .filter(|(_, family)| family.owner == Some(country_id))
+            .map(|(id, family)| {
+                let result = ImperatorFamily {
+                    id,
+                    key: family.key.clone(),
+                    prestige: family.prestige,
+                    members: Vec::new(),
+                };
+                (id, result)
+            })
+            .collect();
+
+        for (id, character) in self.save.characters() {
+            let family = character.family.and_then(|x| families.get_mut(&x));
+            if let Some(family) = family {
+                family.members.push(imperator_character(id, character));
+            }
+        }
+
+        let mut result: Vec<_> = families.into_values().collect();
+        for family in result.iter_mut() {
+            family.members.sort_unstable_by_key(|x| (!x.is_alive, x.id));
+        }
+        result.sort_unstable_by(|a, b| b.prestige.total_cmp(&a.prestige));
+        Some(result)
+    }
+}
+
+fn imperator_character(id: u32, character: &Character) -> ImperatorCharacter {
+    ImperatorCharacter {
+        id,
+        name: character.first_name_loc.as_ref().map(|x| x.name.clone()),
+        age: character.age,
+        female: character.female,
+        is_alive: character.is_alive(),
+        family: character.family,
+    }
+}
+
+fn pop_shares(counts: HashMap<&str, usize>) -> Vec<ImperatorPopShare> {
+    let mut result: Vec<_> = counts
+        .into_iter()
+        .map(|(key, count)| ImperatorPopShare {
+            key: String::from(key),
+            count,
+        })
+        .collect();
+    result.sort_unstable_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+    result
 }
 
 fn _parse_save(data: &[u8]) -> Result<SaveFile, ImperatorError> {
     let file = ImperatorFile::from_slice(data)?;
-    let save: Save = (&file).deserialize(tokens::get_tokens())?;
+    let save: ImperatorGamestate = (&file).deserialize(tokens::get_tokens())?;
     Ok(SaveFile(SaveFileImpl {
-        header: save.meta,
+        save,
         encoding: file.header().clone(),
     }))
 }
@@ -83,3 +269,120 @@ pub fn melt(data: &[u8]) -> Result<js_sys::Uint8Array, JsError> {
         .map(|x| js_sys::Uint8Array::from(x.as_slice()))
         .map_err(JsError::from)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAVE: &str = r#"SAV0100000000000000000000
+version="2.0.4"
+date=450.10.1
+played_country={
+    name="Player"
+    country=1
+}
+country={
+    country_database={
+        1={ tag="ROM" country_name={ name="ROM" } currency_data={ gold=120.5 manpower=8.25 } }
+        2={ tag="CAR" }
+        3={ tag="EPI" }
+        4=none
+    }
+}
+provinces={
+    10={ province_name={ name="PROV10" } owner=1 culture="roman" religion="roman_pantheon" pop={ 100 101 102 } }
+    11={ owner=1 pop={ 103 } }
+    12={ owner=2 pop={ 104 105 106 107 108 } }
+    13=none
+}
+population={
+    pop={
+        100={ type="citizen" culture="roman" religion="roman_pantheon" }
+        101={ type="freemen" culture="roman" religion="roman_pantheon" }
+        102={ type="slaves" culture="greek" religion="hellenic" }
+        103={ type="freemen" culture="roman" }
+        104=none
+    }
+}
+character={
+    character_database={
+        50={ first_name_loc={ name="Marcus" } family=70 country=1 age=40 }
+        51={ first_name_loc={ name="Julia" } family=70 country=1 age=35 female=yes }
+        52={ family=70 country=1 age=62 death_date=440.1.1 }
+        53={ family=71 country=2 age=30 }
+        54=none
+    }
+}
+family={
+    families={
+        70={ key="Cornelii" owner=1 prestige=250 }
+        71={ key="Barcid" owner=2 prestige=400 }
+        72={ key="Fabii" owner=1 prestige=300 }
+    }
+}
+"#;
+
+    fn save() -> SaveFileImpl {
+        _parse_save(SAVE.as_bytes()).unwrap().0
+    }
+
+    #[test]
+    fn test_country_rankings() {
+        let countries = save().get_countries();
+        let tags: Vec<_> = countries.iter().map(|x| x.country.tag.as_str()).collect();
+
+        // Countries without territory are left out
+        assert_eq!(tags, vec!["ROM", "CAR"]);
+
+        let rome = &countries[0];
+        assert!(rome.is_player);
+        assert_eq!(rome.territories, 2);
+        assert_eq!(rome.population, 4);
+        assert_eq!(rome.treasury, 120.5);
+        assert_eq!(rome.manpower, 8.25);
+        assert!(!countries[1].is_player);
+        assert_eq!(countries[1].country.name, "CAR");
+    }
+
+    #[test]
+    fn test_province_details() {
+        let save = save();
+        let province = save.get_province_details(10).unwrap();
+        assert_eq!(province.name.as_deref(), Some("PROV10"));
+        assert_eq!(province.owner.unwrap().tag, "ROM");
+        assert_eq!(province.population, 3);
+
+        let cultures: Vec<_> = province
+            .cultures
+            .iter()
+            .map(|x| (x.key.as_str(), x.count))
+            .collect();
+        assert_eq!(cultures, vec![("roman", 2), ("greek", 1)]);
+        assert_eq!(province.pop_types.len(), 3);
+
+        assert!(save.get_province_details(13).is_none());
+        assert!(save.get_province_details(99).is_none());
+    }
+
+    #[test]
+    fn test_country_characters() {
+        let save = save();
+        let characters = save.get_country_characters("ROM").unwrap();
+        let ids: Vec<_> = characters.iter().map(|x| x.id).collect();
+        assert_eq!(ids, vec![50, 51, 52]);
+        assert!(characters[1].female);
+        assert!(!characters[2].is_alive);
+        assert!(save.get_country_characters("XXX").is_none());
+    }
+
+    #[test]
+    fn test_country_families() {
+        let families = save().get_country_families("ROM").unwrap();
+        let keys: Vec<_> = families.iter().map(|x| x.key.as_str()).collect();
+        assert_eq!(keys, vec!["Fabii", "Cornelii"]);
+
+        let members: Vec<_> = families[1].members.iter().map(|x| x.id).collect();
+        assert_eq!(members, vec![50, 51, 52]);
+        assert!(families[0].members.is_empty());
+    }
+}
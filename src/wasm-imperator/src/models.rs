@@ -0,0 +1,87 @@
+#![allow(nonstandard_style)]
+#![allow(clippy::empty_docs)]
+
+use serde::Serialize;
+use tsify::Tsify;
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImperatorCountryRef {
+    pub id: u32,
+    pub tag: String,
+    pub name: String,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImperatorCountryRanking {
+    pub country: ImperatorCountryRef,
+    pub is_player: bool,
+    pub territories: usize,
+    pub population: usize,
+    pub treasury: f64,
+    pub manpower: f64,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct ImperatorCountries {
+    pub countries: Vec<ImperatorCountryRanking>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImperatorPopShare {
+    pub key: String,
+    pub count: usize,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct ImperatorProvinceDetails {
+    pub id: u32,
+    pub name: Option<String>,
+    pub owner: Option<ImperatorCountryRef>,
+    pub culture: Option<String>,
+    pub religion: Option<String>,
+    pub population: usize,
+    pub pop_types: Vec<ImperatorPopShare>,
+    pub cultures: Vec<ImperatorPopShare>,
+    pub religions: Vec<ImperatorPopShare>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImperatorCharacter {
+    pub id: u32,
+    pub name: Option<String>,
+    pub age: Option<u32>,
+    pub female: bool,
+    pub is_alive: bool,
+    pub family: Option<u32>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct ImperatorCharacters {
+    pub characters: Vec<ImperatorCharacter>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImperatorFamily {
+    pub id: u32,
+    pub key: String,
+    pub prestige: f64,
+    pub members: Vec<ImperatorCharacter>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct ImperatorFamilies {
+    pub families: Vec<ImperatorFamily>,
+}
@@ -1,6 +1,8 @@
 use jomini::common::{Date, PdsDate};
 use models::{
-    Vic3CountryGraphData, Vic3CountryGraphDataResponse, Vic3GoodPrice, Vic3GraphData, Vic3Metadata,
+    Vic3CountryGraphData, Vic3CountryGraphDataResponse, Vic3DiplomacyResponse,
+    Vic3DiplomaticPactData, Vic3GoodPrice, Vic3GraphData, Vic3InterestGroupData,
+    Vic3InterestGroupsResponse, Vic3Metadata, Vic3StateData, Vic3StatesResponse,
 };
 use std::io::Cursor;
 use vic3save::markets::{Vic3GoodEstimationError, goods_price_based_on_buildings};
@@ -55,6 +57,24 @@ impl SaveFile {
         let prices = self.0.get_country_goods_prices(tag)?;
         Ok(Vic3MarketResponse { prices })
     }
+
+    pub fn get_country_states(&self, tag: &str) -> Vic3StatesResponse {
+        Vic3StatesResponse {
+            states: self.0.get_country_states(tag),
+        }
+    }
+
+    pub fn get_country_interest_groups(&self, tag: &str) -> Vic3InterestGroupsResponse {
+        Vic3InterestGroupsResponse {
+            interest_groups: self.0.get_country_interest_groups(tag),
+        }
+    }
+
+    pub fn get_country_diplomacy(&self, tag: &str) -> Vic3DiplomacyResponse {
+        Vic3DiplomacyResponse {
+            pacts: self.0.get_country_diplomacy(tag),
+        }
+    }
 }
 
 impl SaveFileImpl {
@@ -103,6 +123,64 @@ impl SaveFileImpl {
         Ok(goods_prices_vec)
     }
 
+    pub fn get_country_states(&self, tag: &str) -> Vec<Vic3StateData> {
+        let Some(country_id) = self.save.get_country_id(tag) else {
+            return Vec::new();
+        };
+
+        self.save
+            .country_state_stats(country_id)
+            .into_iter()
+            .map(|state| Vic3StateData {
+                id: state.state,
+                region: state.region,
+                population: state.population,
+                workforce: state.workforce,
+                employed: state.employed,
+                employment_rate: state.employment_rate(),
+                standard_of_living: state.standard_of_living,
+            })
+            .collect()
+    }
+
+    pub fn get_country_interest_groups(&self, tag: &str) -> Vec<Vic3InterestGroupData> {
+        let Some(country_id) = self.save.get_country_id(tag) else {
+            return Vec::new();
+        };
+
+        self.save
+            .country_interest_groups(country_id)
+            .into_iter()
+            .map(|ig| Vic3InterestGroupData {
+                definition: ig.definition.clone(),
+                clout: ig.clout,
+                in_government: ig.in_government,
+            })
+            .collect()
+    }
+
+    pub fn get_country_diplomacy(&self, tag: &str) -> Vec<Vic3DiplomaticPactData> {
+        let Some(country_id) = self.save.get_country_id(tag) else {
+            return Vec::new();
+        };
+
+        self.save
+            .country_diplomatic_pacts(country_id)
+            .into_iter()
+            .map(|pact| Vic3DiplomaticPactData {
+                action: pact.action.clone(),
+                counterpart: self
+                    .save
+                    .country_manager
+                    .database
+                    .get(&pact.counterpart(country_id))
+                    .and_then(|x| x.as_ref())
+                    .map(|x| x.definition.clone()),
+                initiator: pact.first_country == country_id,
+            })
+            .collect()
+    }
+
     pub fn get_countries_stats(&self) -> Vec<Vic3CountryGraphData> {
         self.save
             .country_manager
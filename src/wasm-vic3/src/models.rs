@@ -61,3 +61,54 @@ pub struct Vic3CountryGraphData {
     pub tag: String,
     pub stats: Vec<Vic3GraphData>,
 }
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Vic3StatesResponse {
+    pub states: Vec<Vic3StateData>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vic3StateData {
+    pub id: u32,
+    pub region: Option<u32>,
+    pub population: i64,
+    pub workforce: i64,
+    pub employed: i64,
+    pub employment_rate: f64,
+    pub standard_of_living: f64,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Vic3InterestGroupsResponse {
+    pub interest_groups: Vec<Vic3InterestGroupData>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vic3InterestGroupData {
+    pub definition: String,
+    pub clout: f64,
+    pub in_government: bool,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi)]
+pub struct Vic3DiplomacyResponse {
+    pub pacts: Vec<Vic3DiplomaticPactData>,
+}
+
+#[derive(Tsify, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vic3DiplomaticPactData {
+    pub action: String,
+    pub counterpart: Option<String>,
+
+    /// True when the country is the party that initiated the pact
+    pub initiator: bool,
+}